@@ -1,5 +1,12 @@
+pub mod account;
 pub mod asset;
+pub mod currency;
+pub mod network_settings;
 pub mod pair;
 pub mod pair_group;
+pub mod portfolio_snapshot;
+pub mod rate_snapshot;
+pub mod settings;
 pub mod tag;
+pub mod transaction;
 pub mod watchlist;
@@ -0,0 +1,25 @@
+use std::hash::Hash;
+
+// NOTE: an account, e.g. a hardware wallet or an exchange, owns the assets it holds
+#[derive(Clone, Debug)]
+pub struct Account {
+    pub id: String,
+    pub name: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl PartialEq for Account {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.name == other.name
+            && self.created_at == other.created_at
+            && self.updated_at == other.updated_at;
+    }
+}
+
+impl Hash for Account {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
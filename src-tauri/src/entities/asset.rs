@@ -1,11 +1,15 @@
 use std::hash::Hash;
 
+use rust_decimal::Decimal;
+
+// NOTE: an asset without an `account_id` does not belong to any account
 #[derive(Clone, Debug)]
 pub struct Asset {
     pub id: String,
     pub coin: String,
-    pub quantity: f64,
-    pub usd_value: f64,
+    pub quantity: Decimal,
+    pub usd_value: Decimal,
+    pub account_id: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -16,6 +20,7 @@ impl PartialEq for Asset {
             && self.coin == other.coin
             && self.quantity == other.quantity
             && self.usd_value == other.usd_value
+            && self.account_id == other.account_id
             && self.created_at == other.created_at
             && self.updated_at == other.updated_at;
     }
@@ -0,0 +1,40 @@
+use std::hash::Hash;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CurrencyKind {
+    Fiat,
+    Crypto,
+}
+
+#[derive(Clone, Debug)]
+pub struct Currency {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub symbol: String,
+    pub kind: CurrencyKind,
+    pub decimals: u32,
+    pub icon_id: Option<String>,
+    pub provider_id: Option<String>,
+    pub ambiguous_with: Vec<String>,
+}
+
+impl PartialEq for Currency {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.code == other.code
+            && self.name == other.name
+            && self.symbol == other.symbol
+            && self.kind == other.kind
+            && self.decimals == other.decimals
+            && self.icon_id == other.icon_id
+            && self.provider_id == other.provider_id
+            && self.ambiguous_with == other.ambiguous_with;
+    }
+}
+
+impl Hash for Currency {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
@@ -0,0 +1,37 @@
+/*
+    NOTE: the proxy URL scheme selects the proxy kind, `http://`, `https://`,
+    `socks5://` and `socks5h://` (DNS resolved by the proxy) are supported
+*/
+#[derive(Clone, Debug)]
+pub struct ProxySettings {
+    pub url: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub no_proxy: Vec<String>,
+}
+
+impl PartialEq for ProxySettings {
+    fn eq(&self, other: &Self) -> bool {
+        return self.url == other.url
+            && self.username == other.username
+            && self.password == other.password
+            && self.no_proxy == other.no_proxy;
+    }
+}
+
+/*
+    NOTE: `extra_ca_certificates` are paths to PEM files (bundles are allowed),
+    trusted in addition to the system roots
+*/
+#[derive(Clone, Debug, Default)]
+pub struct NetworkSettings {
+    pub proxy: Option<ProxySettings>,
+    pub extra_ca_certificates: Vec<String>,
+}
+
+impl PartialEq for NetworkSettings {
+    fn eq(&self, other: &Self) -> bool {
+        return self.proxy == other.proxy
+            && self.extra_ca_certificates == other.extra_ca_certificates;
+    }
+}
@@ -1,11 +1,14 @@
 use std::hash::Hash;
 
+use rust_decimal::Decimal;
+
 #[derive(Clone, Debug)]
 pub struct Pair {
     pub id: String,
-    pub value: f64,
+    pub value: Decimal,
     pub base: String,
     pub comparison: String,
+    pub position: u32,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -16,6 +19,7 @@ impl PartialEq for Pair {
             && self.base == other.base
             && self.value == other.value
             && self.comparison == other.comparison
+            && self.position == other.position
             && self.created_at == other.created_at
             && self.updated_at == other.updated_at;
     }
@@ -1,12 +1,15 @@
 use std::hash::Hash;
 
+use rust_decimal::Decimal;
+
 use super::pair::Pair;
 
 #[derive(Clone, Debug)]
 pub struct PairGroup {
     pub id: String,
     pub is_pinned: bool,
-    pub multiplier: f64,
+    pub multiplier: Decimal,
+    pub position: u32,
     pub pairs: Vec<Pair>,
     pub created_at: String,
     pub updated_at: String,
@@ -18,6 +21,7 @@ impl PartialEq for PairGroup {
             && self.pairs == other.pairs
             && self.is_pinned == other.is_pinned
             && self.multiplier == other.multiplier
+            && self.position == other.position
             && self.created_at == other.created_at
             && self.updated_at == other.updated_at;
     }
@@ -0,0 +1,43 @@
+use std::hash::Hash;
+
+use rust_decimal::Decimal;
+
+#[derive(Clone, Debug)]
+pub struct TagValue {
+    pub tag_id: String,
+    pub usd_value: Decimal,
+}
+
+impl PartialEq for TagValue {
+    fn eq(&self, other: &Self) -> bool {
+        return self.tag_id == other.tag_id && self.usd_value == other.usd_value;
+    }
+}
+
+/*
+    NOTE: values are kept in `USD`, like the asset values, and `bucket` identifies
+    the time bucket the snapshot belongs to, so only the last snapshot of each
+    bucket is kept
+*/
+#[derive(Clone, Debug)]
+pub struct PortfolioSnapshot {
+    pub bucket: String,
+    pub total_usd_value: Decimal,
+    pub tag_values: Vec<TagValue>,
+    pub created_at: String,
+}
+
+impl PartialEq for PortfolioSnapshot {
+    fn eq(&self, other: &Self) -> bool {
+        return self.bucket == other.bucket
+            && self.total_usd_value == other.total_usd_value
+            && self.tag_values == other.tag_values
+            && self.created_at == other.created_at;
+    }
+}
+
+impl Hash for PortfolioSnapshot {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.bucket.hash(state);
+    }
+}
@@ -0,0 +1,17 @@
+use super::pair::Pair;
+
+/*
+    NOTE: the pairs are the coin market `USD` pairs as they were when the snapshot
+    was taken, so values in any currency can be recomputed from them later
+*/
+#[derive(Clone, Debug)]
+pub struct RateSnapshot {
+    pub pairs: Vec<Pair>,
+    pub created_at: String,
+}
+
+impl PartialEq for RateSnapshot {
+    fn eq(&self, other: &Self) -> bool {
+        return self.pairs == other.pairs && self.created_at == other.created_at;
+    }
+}
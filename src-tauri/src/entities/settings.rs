@@ -0,0 +1,41 @@
+use super::network_settings::NetworkSettings;
+
+// NOTE: which acquisitions a sale or a withdrawal is matched against
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CostBasisMethod {
+    Fifo,
+    Lifo,
+    AverageCost,
+}
+
+/*
+    NOTE: `refresh_interval` is in seconds, `data_location` overrides the
+    directory where everything but the settings themselves is stored, and
+    `default_watchlist_id` is the watchlist shown when none is requested
+*/
+#[derive(Clone, Debug)]
+pub struct Settings {
+    pub fiat_rates_url: String,
+    pub crypto_rates_url: String,
+    pub reference_currency: String,
+    pub refresh_interval: u64,
+    pub data_location: Option<String>,
+    pub network: NetworkSettings,
+    pub cost_basis_method: CostBasisMethod,
+    pub default_watchlist_id: Option<String>,
+    pub updated_at: String,
+}
+
+impl PartialEq for Settings {
+    fn eq(&self, other: &Self) -> bool {
+        return self.fiat_rates_url == other.fiat_rates_url
+            && self.crypto_rates_url == other.crypto_rates_url
+            && self.reference_currency == other.reference_currency
+            && self.refresh_interval == other.refresh_interval
+            && self.data_location == other.data_location
+            && self.network == other.network
+            && self.cost_basis_method == other.cost_basis_method
+            && self.default_watchlist_id == other.default_watchlist_id
+            && self.updated_at == other.updated_at;
+    }
+}
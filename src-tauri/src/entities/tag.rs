@@ -1,12 +1,32 @@
 use std::hash::Hash;
 
+use rust_decimal::Decimal;
+
 use super::asset::Asset;
 
+// NOTE: `weight` is the share of the tag value the coin should have, from 0 to 1
+#[derive(Clone, Debug)]
+pub struct TargetWeight {
+    pub coin: String,
+    pub weight: Decimal,
+}
+
+impl PartialEq for TargetWeight {
+    fn eq(&self, other: &Self) -> bool {
+        return self.coin == other.coin && self.weight == other.weight;
+    }
+}
+
+// NOTE: a tag without a `parent_id` is a root tag
 #[derive(Clone, Debug)]
 pub struct Tag {
     pub id: String,
     pub name: String,
+    pub color: Option<String>,
+    pub description: String,
+    pub parent_id: Option<String>,
     pub assets: Vec<Asset>,
+    pub targets: Vec<TargetWeight>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -15,7 +35,11 @@ impl PartialEq for Tag {
     fn eq(&self, other: &Self) -> bool {
         return self.id == other.id
             && self.name == other.name
+            && self.color == other.color
+            && self.description == other.description
+            && self.parent_id == other.parent_id
             && self.assets == other.assets
+            && self.targets == other.targets
             && self.created_at == other.created_at
             && self.updated_at == other.updated_at;
     }
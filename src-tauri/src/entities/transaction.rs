@@ -0,0 +1,56 @@
+use std::hash::Hash;
+
+use rust_decimal::Decimal;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TransactionKind {
+    Buy,
+    Sell,
+    Deposit,
+    Withdraw,
+    Transfer,
+    Fee,
+}
+
+/*
+    NOTE: `quantity` is always positive and expressed in the asset coin, the kind
+    tells whether it adds to or removes from the asset. A transfer moves the
+    quantity from `asset_id` to `counterpart_asset_id`, and `price` is the price of
+    one unit in `price_currency`.
+*/
+#[derive(Clone, Debug)]
+pub struct Transaction {
+    pub id: String,
+    pub asset_id: String,
+    pub kind: TransactionKind,
+    pub quantity: Decimal,
+    pub price: Option<Decimal>,
+    pub price_currency: Option<String>,
+    pub counterpart_asset_id: Option<String>,
+    pub executed_at: String,
+    pub notes: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl PartialEq for Transaction {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.asset_id == other.asset_id
+            && self.kind == other.kind
+            && self.quantity == other.quantity
+            && self.price == other.price
+            && self.price_currency == other.price_currency
+            && self.counterpart_asset_id == other.counterpart_asset_id
+            && self.executed_at == other.executed_at
+            && self.notes == other.notes
+            && self.created_at == other.created_at
+            && self.updated_at == other.updated_at;
+    }
+}
+
+impl Hash for Transaction {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
@@ -2,10 +2,17 @@ use std::hash::Hash;
 
 use super::pair::Pair;
 
+/*
+    NOTE: `pairs` are quoted against the reference currency and follow it when it
+    changes, while `cross_pairs` keep the base they were added with, e.g. ETH/BTC
+*/
 #[derive(Clone, Debug)]
 pub struct Watchlist {
     pub id: String,
+    pub name: String,
+    pub position: u32,
     pub pairs: Vec<Pair>,
+    pub cross_pairs: Vec<Pair>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -13,7 +20,10 @@ pub struct Watchlist {
 impl PartialEq for Watchlist {
     fn eq(&self, other: &Self) -> bool {
         return self.id == other.id
+            && self.name == other.name
+            && self.position == other.position
             && self.pairs == other.pairs
+            && self.cross_pairs == other.cross_pairs
             && self.created_at == other.created_at
             && self.updated_at == other.updated_at;
     }
@@ -1,6 +1,16 @@
 use serde::Serialize;
 
-#[derive(Clone, Debug, Serialize)]
+// NOTE: `fields` lists the request fields that failed validation, if any
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct Error {
     pub message: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<FieldError>,
+}
+
+// NOTE: `field` is the path of the request field, e.g. `assets[1].quantity`
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
 }
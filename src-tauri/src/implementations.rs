@@ -1,2 +1,2 @@
-pub mod utilities;
 pub mod data_access;
+pub mod utilities;
@@ -1,6 +1,11 @@
+pub mod file_system_account;
 pub mod file_system_asset;
 pub mod file_system_data_access;
 pub mod file_system_pair;
 pub mod file_system_pair_group;
+pub mod file_system_portfolio_snapshot;
+pub mod file_system_rate_snapshot;
+pub mod file_system_settings;
 pub mod file_system_tag;
+pub mod file_system_transaction;
 pub mod file_system_watchlist;
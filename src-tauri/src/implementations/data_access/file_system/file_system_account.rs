@@ -0,0 +1,26 @@
+use std::hash::Hash;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileSystemAccount {
+    pub id: String,
+    pub name: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl PartialEq for FileSystemAccount {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.name == other.name
+            && self.created_at == other.created_at
+            && self.updated_at == other.updated_at;
+    }
+}
+
+impl Hash for FileSystemAccount {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
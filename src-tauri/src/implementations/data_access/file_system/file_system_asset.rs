@@ -1,13 +1,16 @@
 use std::hash::Hash;
 
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct FileSystemAsset {
     pub id: String,
     pub coin: String,
-    pub quantity: f64,
-    pub usd_value: f64,
+    pub quantity: Decimal,
+    pub usd_value: Decimal,
+    #[serde(default)]
+    pub account_id: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -18,6 +21,7 @@ impl PartialEq for FileSystemAsset {
             && self.coin == other.coin
             && self.quantity == other.quantity
             && self.usd_value == other.usd_value
+            && self.account_id == other.account_id
             && self.created_at == other.created_at
             && self.updated_at == other.updated_at;
     }
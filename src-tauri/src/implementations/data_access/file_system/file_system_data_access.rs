@@ -4,27 +4,73 @@ use std::{
     path::{Path, PathBuf},
 };
 
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    entities::{asset::Asset, pair::Pair, pair_group::PairGroup, tag::Tag, watchlist::Watchlist},
+    entities::{
+        account::Account,
+        asset::Asset,
+        network_settings::{NetworkSettings, ProxySettings},
+        pair::Pair,
+        pair_group::PairGroup,
+        portfolio_snapshot::{PortfolioSnapshot, TagValue},
+        rate_snapshot::RateSnapshot,
+        settings::Settings,
+        tag::{Tag, TargetWeight},
+        transaction::Transaction,
+        watchlist::Watchlist,
+    },
     implementations::data_access::file_system::file_system_pair::FileSystemPair,
     interactors::{
-        delete_asset::DeleteAssetDataAccess, delete_pair_group::DeletePairGroupDataAccess,
-        delete_tag::DeleteTagDataAccess, delete_watchlist_pair::DeleteWatchlistPairDataAccess,
-        save_pair_group::SavePairGroupDataAccess, save_tag::SaveTagDataAccess,
+        add_transaction::AddTransactionDataAccess,
+        adjust_asset_quantities::AdjustAssetQuantitiesDataAccess,
+        delete_account::DeleteAccountDataAccess, delete_asset::DeleteAssetDataAccess,
+        delete_assets::DeleteAssetsDataAccess, delete_pair_group::DeletePairGroupDataAccess,
+        delete_tag::DeleteTagDataAccess, delete_transaction::DeleteTransactionDataAccess,
+        delete_watchlist::DeleteWatchlistDataAccess,
+        delete_watchlist_pair::DeleteWatchlistPairDataAccess,
+        export_tax_lots::ExportTaxLotsDataAccess, merge_assets::MergeAssetsDataAccess,
+        move_assets::MoveAssetsDataAccess,
+        reorder_pair_group_pairs::ReorderPairGroupPairsDataAccess,
+        reorder_pair_groups::ReorderPairGroupsDataAccess,
+        reorder_watchlist_pairs::ReorderWatchlistPairsDataAccess,
+        reorder_watchlists::ReorderWatchlistsDataAccess, retag_assets::RetagAssetsDataAccess,
+        save_account::SaveAccountDataAccess, save_pair_group::SavePairGroupDataAccess,
+        save_tag::SaveTagDataAccess, save_watchlist::SaveWatchlistDataAccess,
         store_portfolios::StorePortfoliosDataAccess,
         store_watchlist_coins::StoreWatchlistCoinsDataAccess,
+        suggest_rebalance::SuggestRebalanceDataAccess,
         update_pair_group::UpdatePairGroupDataAccess, update_portfolio::UpdatePortfolioDataAccess,
-        view_pair_groups::ViewPairGroupsDataAccess, view_portfolios::ViewPortfoliosDataAccess,
-        view_watchlist::ViewWatchlistDataAccess,
+        update_settings::UpdateSettingsDataAccess, update_tag::UpdateTagDataAccess,
+        update_tag_targets::UpdateTagTargetsDataAccess,
+        update_transaction::UpdateTransactionDataAccess,
+        update_watchlist::UpdateWatchlistDataAccess, view_pair_groups::ViewPairGroupsDataAccess,
+        view_portfolio_history::ViewPortfolioHistoryDataAccess,
+        view_portfolios::ViewPortfoliosDataAccess, view_settings::ViewSettingsDataAccess,
+        view_transactions::ViewTransactionsDataAccess, view_watchlist::ViewWatchlistDataAccess,
+    },
+    utilities::{
+        cost_basis::{get_cost_basis_method_name, parse_cost_basis_method},
+        default_settings::{create_default_settings, DEFAULT_COST_BASIS_METHOD},
+        ledger::{get_transaction_kind_name, parse_transaction_kind},
+        watchlist_selection::sort_watchlists,
     },
     Error,
 };
 
 use super::{
-    file_system_asset::FileSystemAsset, file_system_pair_group::FileSystemPairGroup,
-    file_system_tag::FileSystemTag, file_system_watchlist::FileSystemWatchlist,
+    file_system_account::FileSystemAccount,
+    file_system_asset::FileSystemAsset,
+    file_system_pair_group::FileSystemPairGroup,
+    file_system_portfolio_snapshot::{FileSystemPortfolioSnapshot, FileSystemTagValue},
+    file_system_rate_snapshot::FileSystemRateSnapshot,
+    file_system_settings::{
+        FileSystemNetworkSettings, FileSystemProxySettings, FileSystemSettings,
+    },
+    file_system_tag::{FileSystemTag, FileSystemTargetWeight},
+    file_system_transaction::FileSystemTransaction,
+    file_system_watchlist::FileSystemWatchlist,
 };
 
 const TAGS_DIR_NAME: &str = "tag";
@@ -32,6 +78,15 @@ const PAIRS_DIR_NAME: &str = "pairs";
 const ASSETS_DIR_NAME: &str = "assets";
 const WATCHLISTS_DIR_NAME: &str = "watchlists";
 const PAIR_GROUPS_DIR_NAME: &str = "pair_groups";
+const RATE_SNAPSHOTS_DIR_NAME: &str = "rate_snapshots";
+const LATEST_RATE_SNAPSHOT_FILE_NAME: &str = "latest";
+const HOURLY_RATE_SNAPSHOTS_DIR_NAME: &str = "hourly_rate_snapshots";
+const HOURLY_RATE_SNAPSHOT_RETENTION_HOURS: i64 = 48;
+const SETTINGS_DIR_NAME: &str = "settings";
+const SETTINGS_FILE_NAME: &str = "settings";
+const TRANSACTIONS_DIR_NAME: &str = "transactions";
+const PORTFOLIO_SNAPSHOTS_DIR_NAME: &str = "portfolio_snapshots";
+const ACCOUNTS_DIR_NAME: &str = "accounts";
 
 pub struct FileSystemDataAccess {
     pub root: PathBuf,
@@ -49,6 +104,17 @@ impl ViewPairGroupsDataAccess for FileSystemDataAccess {
     async fn update_pair_group(&mut self, pair_group: &PairGroup) -> Result<(), Error> {
         return update_pair_group(&self, pair_group).await;
     }
+
+    async fn fetch_daily_rate_snapshots_since(
+        &mut self,
+        date_time: &DateTime<Utc>,
+    ) -> Result<Vec<RateSnapshot>, Error> {
+        return fetch_daily_rate_snapshots_since(&self, date_time).await;
+    }
+
+    async fn fetch_hourly_rate_snapshots(&mut self) -> Result<Vec<RateSnapshot>, Error> {
+        return fetch_hourly_rate_snapshots(&self).await;
+    }
 }
 
 async fn update_pair(data_access: &FileSystemDataAccess, pair: &Pair) -> Result<(), Error> {
@@ -57,6 +123,7 @@ async fn update_pair(data_access: &FileSystemDataAccess, pair: &Pair) -> Result<
     if !path.exists() {
         return Err(Error {
             message: String::from("Pair to update does not exist!"),
+            ..Default::default()
         });
     }
     write_pair(&data_access.root, pair)?;
@@ -79,6 +146,7 @@ fn write_pair(root: &Path, pair: &Pair) -> Result<(), Error> {
             base: pair.base.clone(),
             value: pair.value.clone(),
             comparison: pair.comparison.clone(),
+            position: pair.position,
             created_at: pair.created_at.clone(),
             updated_at: pair.updated_at.clone(),
         },
@@ -92,11 +160,13 @@ where
 {
     let object_contents = serde_json::to_string(object).map_err(|e| Error {
         message: e.to_string(),
+        ..Default::default()
     })?;
     File::create(path)
         .and_then(|mut file| file.write_all(object_contents.as_bytes()))
         .map_err(|e| Error {
             message: e.to_string(),
+            ..Default::default()
         })?;
     return Ok(());
 }
@@ -119,10 +189,12 @@ fn get_dir_entries(root: &Path, name: &str) -> Result<Vec<DirEntry>, Error> {
     let dir = ensure_dir(root, name)?;
     let dir_entry_results = read_dir(&dir).map_err(|e| Error {
         message: e.to_string(),
+        ..Default::default()
     })?;
     for dir_entry_result in dir_entry_results {
         let dir_entry = dir_entry_result.map_err(|e| Error {
             message: e.to_string(),
+            ..Default::default()
         })?;
         dir_entries.push(dir_entry);
     }
@@ -138,6 +210,7 @@ fn read_pair_group(root: &Path, id: &str) -> Result<PairGroup, Error> {
         pairs: vec![],
         is_pinned: fs_pair_group.is_pinned,
         multiplier: fs_pair_group.multiplier,
+        position: fs_pair_group.position,
         created_at: fs_pair_group.created_at.clone(),
         updated_at: fs_pair_group.updated_at.clone(),
     };
@@ -154,13 +227,16 @@ where
 {
     let mut file = File::open(path).map_err(|e| Error {
         message: e.to_string(),
+        ..Default::default()
     })?;
     let mut contents = String::new();
     file.read_to_string(&mut contents).map_err(|e| Error {
         message: e.to_string(),
+        ..Default::default()
     })?;
     let object = serde_json::from_str::<T>(&contents).map_err(|e| Error {
         message: e.to_string(),
+        ..Default::default()
     })?;
     return Ok(object);
 }
@@ -174,6 +250,7 @@ fn read_pair(root: &Path, id: &str) -> Result<Pair, Error> {
         base: fs_pair.base.clone(),
         value: fs_pair.value.clone(),
         comparison: fs_pair.comparison.clone(),
+        position: fs_pair.position,
         created_at: fs_pair.created_at.clone(),
         updated_at: fs_pair.updated_at.clone(),
     });
@@ -188,6 +265,7 @@ async fn update_pair_group(
     if !path.exists() {
         return Err(Error {
             message: String::from("Pair group to update does not exist!"),
+            ..Default::default()
         });
     }
     write_pair_group(&data_access.root, pair_group)?;
@@ -203,6 +281,7 @@ fn write_pair_group(root: &Path, pair_group: &PairGroup) -> Result<(), Error> {
             id: pair_group.id.clone(),
             is_pinned: pair_group.is_pinned,
             multiplier: pair_group.multiplier,
+            position: pair_group.position,
             pairs: pair_group.pairs.iter().map(|p| p.id.clone()).collect(),
             created_at: pair_group.created_at.clone(),
             updated_at: pair_group.updated_at.clone(),
@@ -212,6 +291,10 @@ fn write_pair_group(root: &Path, pair_group: &PairGroup) -> Result<(), Error> {
 }
 
 impl SavePairGroupDataAccess for FileSystemDataAccess {
+    async fn fetch_pair_groups(&mut self) -> Result<Vec<PairGroup>, Error> {
+        return fetch_pair_groups(&self).await;
+    }
+
     async fn save_pair(&mut self, pair: &Pair) -> Result<(), Error> {
         return save_pair(&self, pair).await;
     }
@@ -227,6 +310,7 @@ async fn save_pair(data_access: &FileSystemDataAccess, pair: &Pair) -> Result<()
     if path.exists() {
         return Err(Error {
             message: String::from("Pair to save already exists!"),
+            ..Default::default()
         });
     }
     write_pair(&data_access.root, pair)?;
@@ -242,6 +326,7 @@ async fn save_pair_group(
     if path.exists() {
         return Err(Error {
             message: String::from("Pair group to save already exists!"),
+            ..Default::default()
         });
     }
     write_pair_group(&data_access.root, pair_group)?;
@@ -325,6 +410,7 @@ async fn delete_pair(data_access: &FileSystemDataAccess, id: &str) -> Result<(),
     if !path.exists() {
         return Err(Error {
             message: String::from("Pair to delete does not exist!"),
+            ..Default::default()
         });
     }
     remove_pair(&data_access.root, id)?;
@@ -341,6 +427,7 @@ fn remove_pair(root: &Path, id: &str) -> Result<(), Error> {
 fn remove_object_file(path: &Path) -> Result<(), Error> {
     remove_file(path).map_err(|e| Error {
         message: e.to_string(),
+        ..Default::default()
     })?;
     return Ok(());
 }
@@ -351,6 +438,7 @@ async fn delete_pair_group(data_access: &FileSystemDataAccess, id: &str) -> Resu
     if !path.exists() {
         return Err(Error {
             message: String::from("Pair group to delete does not exist!"),
+            ..Default::default()
         });
     }
     remove_pair_group(&data_access.root, id)?;
@@ -366,6 +454,10 @@ fn remove_pair_group(root: &Path, id: &str) -> Result<(), Error> {
 }
 
 impl ViewPortfoliosDataAccess for FileSystemDataAccess {
+    async fn fetch_accounts(&mut self) -> Result<Vec<Account>, Error> {
+        return fetch_accounts(&self).await;
+    }
+
     async fn fetch_tags(&mut self) -> Result<Vec<Tag>, Error> {
         return fetch_tags(&self).await;
     }
@@ -377,6 +469,272 @@ impl ViewPortfoliosDataAccess for FileSystemDataAccess {
     async fn update_asset(&mut self, asset: &Asset) -> Result<(), Error> {
         return update_asset(&self, asset).await;
     }
+
+    async fn find_rate_snapshot(&mut self) -> Result<Option<RateSnapshot>, Error> {
+        return find_rate_snapshot(&self).await;
+    }
+
+    async fn update_rate_snapshot(&mut self, rate_snapshot: &RateSnapshot) -> Result<(), Error> {
+        return update_rate_snapshot(&self, rate_snapshot).await;
+    }
+
+    async fn update_daily_rate_snapshot(
+        &mut self,
+        rate_snapshot: &RateSnapshot,
+    ) -> Result<(), Error> {
+        return update_daily_rate_snapshot(&self, rate_snapshot).await;
+    }
+
+    async fn update_hourly_rate_snapshot(
+        &mut self,
+        rate_snapshot: &RateSnapshot,
+    ) -> Result<(), Error> {
+        return update_hourly_rate_snapshot(&self, rate_snapshot).await;
+    }
+
+    async fn fetch_daily_rate_snapshots_since(
+        &mut self,
+        date_time: &DateTime<Utc>,
+    ) -> Result<Vec<RateSnapshot>, Error> {
+        return fetch_daily_rate_snapshots_since(&self, date_time).await;
+    }
+
+    async fn fetch_daily_rate_snapshots_on(
+        &mut self,
+        dates: &Vec<NaiveDate>,
+    ) -> Result<Vec<RateSnapshot>, Error> {
+        return fetch_daily_rate_snapshots_on(&self, dates).await;
+    }
+
+    async fn fetch_hourly_rate_snapshots(&mut self) -> Result<Vec<RateSnapshot>, Error> {
+        return fetch_hourly_rate_snapshots(&self).await;
+    }
+
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error> {
+        return fetch_transactions(&self).await;
+    }
+
+    async fn update_portfolio_snapshot(
+        &mut self,
+        portfolio_snapshot: &PortfolioSnapshot,
+    ) -> Result<(), Error> {
+        return update_portfolio_snapshot(&self, portfolio_snapshot).await;
+    }
+}
+
+async fn find_rate_snapshot(
+    data_access: &FileSystemDataAccess,
+) -> Result<Option<RateSnapshot>, Error> {
+    let dir = ensure_dir(&data_access.root, RATE_SNAPSHOTS_DIR_NAME)?;
+    let path = dir.join(LATEST_RATE_SNAPSHOT_FILE_NAME);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let rate_snapshot = read_rate_snapshot(
+        &data_access.root,
+        RATE_SNAPSHOTS_DIR_NAME,
+        LATEST_RATE_SNAPSHOT_FILE_NAME,
+    )?;
+    return Ok(Some(rate_snapshot));
+}
+
+fn read_rate_snapshot(root: &Path, dir_name: &str, name: &str) -> Result<RateSnapshot, Error> {
+    let dir = ensure_dir(root, dir_name)?;
+    let path = dir.join(name);
+    let fs_rate_snapshot = create_object_from_file::<FileSystemRateSnapshot>(&path)?;
+    return Ok(RateSnapshot {
+        pairs: fs_rate_snapshot
+            .pairs
+            .iter()
+            .map(|p| Pair {
+                id: p.id.clone(),
+                base: p.base.clone(),
+                value: p.value,
+                comparison: p.comparison.clone(),
+                position: p.position,
+                created_at: p.created_at.clone(),
+                updated_at: p.updated_at.clone(),
+            })
+            .collect(),
+        created_at: fs_rate_snapshot.created_at.clone(),
+    });
+}
+
+async fn update_rate_snapshot(
+    data_access: &FileSystemDataAccess,
+    rate_snapshot: &RateSnapshot,
+) -> Result<(), Error> {
+    write_rate_snapshot(
+        &data_access.root,
+        RATE_SNAPSHOTS_DIR_NAME,
+        LATEST_RATE_SNAPSHOT_FILE_NAME,
+        rate_snapshot,
+    )?;
+    return Ok(());
+}
+
+// NOTE: daily snapshots are named after their UTC date, so the last one of the day wins
+async fn update_daily_rate_snapshot(
+    data_access: &FileSystemDataAccess,
+    rate_snapshot: &RateSnapshot,
+) -> Result<(), Error> {
+    let created_at =
+        DateTime::parse_from_rfc3339(&rate_snapshot.created_at).map_err(|e| Error {
+            message: e.to_string(),
+            ..Default::default()
+        })?;
+    let name = created_at.with_timezone(&Utc).date_naive().to_string();
+    write_rate_snapshot(
+        &data_access.root,
+        RATE_SNAPSHOTS_DIR_NAME,
+        &name,
+        rate_snapshot,
+    )?;
+    return Ok(());
+}
+
+/*
+    NOTE: hourly snapshots are named after their UTC hour, e.g. `2024-01-01T13`, so
+    the last one of the hour wins. Only the recent ones are kept, the daily snapshots
+    cover the longer periods.
+*/
+async fn update_hourly_rate_snapshot(
+    data_access: &FileSystemDataAccess,
+    rate_snapshot: &RateSnapshot,
+) -> Result<(), Error> {
+    let created_at = DateTime::parse_from_rfc3339(&rate_snapshot.created_at)
+        .map_err(|e| Error {
+            message: e.to_string(),
+            ..Default::default()
+        })?
+        .with_timezone(&Utc);
+    let name = get_hourly_rate_snapshot_name(&created_at);
+    write_rate_snapshot(
+        &data_access.root,
+        HOURLY_RATE_SNAPSHOTS_DIR_NAME,
+        &name,
+        rate_snapshot,
+    )?;
+    let oldest_name = get_hourly_rate_snapshot_name(
+        &(created_at - Duration::hours(HOURLY_RATE_SNAPSHOT_RETENTION_HOURS)),
+    );
+    let entries = get_dir_entries(&data_access.root, HOURLY_RATE_SNAPSHOTS_DIR_NAME)?;
+    for entry in entries {
+        let file_name = entry.file_name();
+        if let Some(name) = file_name.to_str() {
+            if name < oldest_name.as_str() {
+                remove_object_file(&entry.path())?;
+            }
+        }
+    }
+    return Ok(());
+}
+
+fn get_hourly_rate_snapshot_name(date_time: &DateTime<Utc>) -> String {
+    return date_time.format("%Y-%m-%dT%H").to_string();
+}
+
+fn write_rate_snapshot(
+    root: &Path,
+    dir_name: &str,
+    name: &str,
+    rate_snapshot: &RateSnapshot,
+) -> Result<(), Error> {
+    let dir = ensure_dir(root, dir_name)?;
+    let path = dir.join(name);
+    write_object_file(
+        &path,
+        &FileSystemRateSnapshot {
+            pairs: rate_snapshot
+                .pairs
+                .iter()
+                .map(|p| FileSystemPair {
+                    id: p.id.clone(),
+                    base: p.base.clone(),
+                    value: p.value,
+                    comparison: p.comparison.clone(),
+                    position: p.position,
+                    created_at: p.created_at.clone(),
+                    updated_at: p.updated_at.clone(),
+                })
+                .collect(),
+            created_at: rate_snapshot.created_at.clone(),
+        },
+    )?;
+    return Ok(());
+}
+
+async fn fetch_daily_rate_snapshots(
+    data_access: &FileSystemDataAccess,
+) -> Result<Vec<RateSnapshot>, Error> {
+    let mut rate_snapshots: Vec<RateSnapshot> = vec![];
+    let entries = get_dir_entries(&data_access.root, RATE_SNAPSHOTS_DIR_NAME)?;
+    for entry in entries {
+        let file_name = entry.file_name();
+        if let Some(name) = file_name.to_str() {
+            if name != LATEST_RATE_SNAPSHOT_FILE_NAME {
+                let rate_snapshot =
+                    read_rate_snapshot(&data_access.root, RATE_SNAPSHOTS_DIR_NAME, name)?;
+                rate_snapshots.push(rate_snapshot);
+            }
+        }
+    }
+    return Ok(rate_snapshots);
+}
+
+// NOTE: daily snapshots are named after their UTC date, so older ones are skipped unread
+async fn fetch_daily_rate_snapshots_since(
+    data_access: &FileSystemDataAccess,
+    date_time: &DateTime<Utc>,
+) -> Result<Vec<RateSnapshot>, Error> {
+    let oldest_name = date_time.date_naive().to_string();
+    let mut rate_snapshots: Vec<RateSnapshot> = vec![];
+    let entries = get_dir_entries(&data_access.root, RATE_SNAPSHOTS_DIR_NAME)?;
+    for entry in entries {
+        let file_name = entry.file_name();
+        if let Some(name) = file_name.to_str() {
+            if name != LATEST_RATE_SNAPSHOT_FILE_NAME && name >= oldest_name.as_str() {
+                let rate_snapshot =
+                    read_rate_snapshot(&data_access.root, RATE_SNAPSHOTS_DIR_NAME, name)?;
+                rate_snapshots.push(rate_snapshot);
+            }
+        }
+    }
+    return Ok(rate_snapshots);
+}
+
+// NOTE: dates without a daily snapshot are skipped
+async fn fetch_daily_rate_snapshots_on(
+    data_access: &FileSystemDataAccess,
+    dates: &Vec<NaiveDate>,
+) -> Result<Vec<RateSnapshot>, Error> {
+    let mut rate_snapshots: Vec<RateSnapshot> = vec![];
+    let dir = ensure_dir(&data_access.root, RATE_SNAPSHOTS_DIR_NAME)?;
+    for date in dates {
+        let name = date.to_string();
+        if dir.join(&name).exists() {
+            let rate_snapshot =
+                read_rate_snapshot(&data_access.root, RATE_SNAPSHOTS_DIR_NAME, &name)?;
+            rate_snapshots.push(rate_snapshot);
+        }
+    }
+    return Ok(rate_snapshots);
+}
+
+async fn fetch_hourly_rate_snapshots(
+    data_access: &FileSystemDataAccess,
+) -> Result<Vec<RateSnapshot>, Error> {
+    let mut rate_snapshots: Vec<RateSnapshot> = vec![];
+    let entries = get_dir_entries(&data_access.root, HOURLY_RATE_SNAPSHOTS_DIR_NAME)?;
+    for entry in entries {
+        let file_name = entry.file_name();
+        if let Some(name) = file_name.to_str() {
+            let rate_snapshot =
+                read_rate_snapshot(&data_access.root, HOURLY_RATE_SNAPSHOTS_DIR_NAME, name)?;
+            rate_snapshots.push(rate_snapshot);
+        }
+    }
+    return Ok(rate_snapshots);
 }
 
 async fn fetch_tags(data_access: &FileSystemDataAccess) -> Result<Vec<Tag>, Error> {
@@ -400,6 +758,17 @@ fn read_tag(root: &Path, id: &str) -> Result<Tag, Error> {
         id: fs_tag.id.clone(),
         assets: vec![],
         name: fs_tag.name.clone(),
+        color: fs_tag.color.clone(),
+        description: fs_tag.description.clone(),
+        parent_id: fs_tag.parent_id.clone(),
+        targets: fs_tag
+            .targets
+            .iter()
+            .map(|t| TargetWeight {
+                coin: t.coin.clone(),
+                weight: t.weight,
+            })
+            .collect(),
         created_at: fs_tag.created_at.clone(),
         updated_at: fs_tag.updated_at.clone(),
     };
@@ -419,6 +788,7 @@ fn read_asset(root: &Path, id: &str) -> Result<Asset, Error> {
         coin: fs_asset.coin.clone(),
         quantity: fs_asset.quantity.clone(),
         usd_value: fs_asset.usd_value.clone(),
+        account_id: fs_asset.account_id.clone(),
         created_at: fs_asset.created_at.clone(),
         updated_at: fs_asset.updated_at.clone(),
     });
@@ -438,6 +808,10 @@ async fn fetch_assets(data_access: &FileSystemDataAccess) -> Result<Vec<Asset>,
 }
 
 impl StorePortfoliosDataAccess for FileSystemDataAccess {
+    async fn find_account(&mut self, id: &str) -> Result<Option<Account>, Error> {
+        return find_account(&self, id).await;
+    }
+
     async fn find_tag(&mut self, id: &str) -> Result<Option<Tag>, Error> {
         return find_tag(&self, id).await;
     }
@@ -449,6 +823,10 @@ impl StorePortfoliosDataAccess for FileSystemDataAccess {
     async fn save_asset(&mut self, asset: &Asset) -> Result<(), Error> {
         return save_asset(&self, asset).await;
     }
+
+    async fn save_transaction(&mut self, transaction: &Transaction) -> Result<(), Error> {
+        return save_transaction(&self, transaction).await;
+    }
 }
 
 async fn find_tag(data_access: &FileSystemDataAccess, id: &str) -> Result<Option<Tag>, Error> {
@@ -471,6 +849,7 @@ async fn update_tag(data_access: &FileSystemDataAccess, tag: &Tag) -> Result<(),
     if !path.exists() {
         return Err(Error {
             message: String::from("Tag to update does not exist!"),
+            ..Default::default()
         });
     }
     write_tag(&data_access.root, tag)?;
@@ -485,7 +864,18 @@ fn write_tag(root: &Path, tag: &Tag) -> Result<(), Error> {
         &FileSystemTag {
             id: tag.id.clone(),
             name: tag.name.clone(),
+            color: tag.color.clone(),
+            description: tag.description.clone(),
+            parent_id: tag.parent_id.clone(),
             assets: tag.assets.iter().map(|a| a.id.clone()).collect(),
+            targets: tag
+                .targets
+                .iter()
+                .map(|t| FileSystemTargetWeight {
+                    coin: t.coin.clone(),
+                    weight: t.weight,
+                })
+                .collect(),
             created_at: tag.created_at.clone(),
             updated_at: tag.updated_at.clone(),
         },
@@ -503,6 +893,7 @@ fn write_asset(root: &Path, asset: &Asset) -> Result<(), Error> {
             coin: asset.coin.clone(),
             quantity: asset.quantity.clone(),
             usd_value: asset.usd_value.clone(),
+            account_id: asset.account_id.clone(),
             created_at: asset.created_at.clone(),
             updated_at: asset.updated_at.clone(),
         },
@@ -516,6 +907,7 @@ async fn save_asset(data_access: &FileSystemDataAccess, asset: &Asset) -> Result
     if path.exists() {
         return Err(Error {
             message: String::from("Asset to save already exists!"),
+            ..Default::default()
         });
     }
     write_asset(&data_access.root, asset)?;
@@ -523,6 +915,10 @@ async fn save_asset(data_access: &FileSystemDataAccess, asset: &Asset) -> Result
 }
 
 impl SaveTagDataAccess for FileSystemDataAccess {
+    async fn fetch_tags(&mut self) -> Result<Vec<Tag>, Error> {
+        return fetch_tags(&self).await;
+    }
+
     async fn save_tag(&mut self, tag: &Tag) -> Result<(), Error> {
         return save_tag(&self, tag).await;
     }
@@ -534,6 +930,7 @@ async fn save_tag(data_access: &FileSystemDataAccess, tag: &Tag) -> Result<(), E
     if path.exists() {
         return Err(Error {
             message: String::from("Tag to save already exists!"),
+            ..Default::default()
         });
     }
     write_tag(&data_access.root, tag)?;
@@ -541,9 +938,33 @@ async fn save_tag(data_access: &FileSystemDataAccess, tag: &Tag) -> Result<(), E
 }
 
 impl DeleteTagDataAccess for FileSystemDataAccess {
+    async fn fetch_tags(&mut self) -> Result<Vec<Tag>, Error> {
+        return fetch_tags(&self).await;
+    }
+
+    async fn update_tag(&mut self, tag: &Tag) -> Result<(), Error> {
+        return update_tag(&self, tag).await;
+    }
+
     async fn delete_tag(&mut self, id: &str) -> Result<(), Error> {
         return delete_tag(&self, id).await;
     }
+
+    async fn delete_asset(&mut self, id: &str) -> Result<(), Error> {
+        return delete_asset(&self, id).await;
+    }
+
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error> {
+        return fetch_transactions(&self).await;
+    }
+
+    async fn update_transaction(&mut self, transaction: &Transaction) -> Result<(), Error> {
+        return update_transaction(&self, transaction).await;
+    }
+
+    async fn delete_transaction(&mut self, id: &str) -> Result<(), Error> {
+        return delete_transaction(&self, id).await;
+    }
 }
 
 async fn delete_tag(data_access: &FileSystemDataAccess, id: &str) -> Result<(), Error> {
@@ -552,6 +973,7 @@ async fn delete_tag(data_access: &FileSystemDataAccess, id: &str) -> Result<(),
     if !path.exists() {
         return Err(Error {
             message: String::from("Tag to delete does not exist!"),
+            ..Default::default()
         });
     }
     remove_tag(&data_access.root, id)?;
@@ -585,6 +1007,14 @@ impl UpdatePortfolioDataAccess for FileSystemDataAccess {
     async fn update_asset(&mut self, asset: &Asset) -> Result<(), Error> {
         return update_asset(&self, asset).await;
     }
+
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error> {
+        return fetch_transactions(&self).await;
+    }
+
+    async fn save_transaction(&mut self, transaction: &Transaction) -> Result<(), Error> {
+        return save_transaction(&self, transaction).await;
+    }
 }
 
 async fn retrieve_tags_by_asset(
@@ -625,6 +1055,7 @@ async fn update_asset(data_access: &FileSystemDataAccess, asset: &Asset) -> Resu
     if !path.exists() {
         return Err(Error {
             message: String::from("Asset to update does not exist!"),
+            ..Default::default()
         });
     }
     write_asset(&data_access.root, asset)?;
@@ -643,6 +1074,18 @@ impl DeleteAssetDataAccess for FileSystemDataAccess {
     async fn delete_asset(&mut self, id: &str) -> Result<(), Error> {
         return delete_asset(&self, id).await;
     }
+
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error> {
+        return fetch_transactions(&self).await;
+    }
+
+    async fn update_transaction(&mut self, transaction: &Transaction) -> Result<(), Error> {
+        return update_transaction(&self, transaction).await;
+    }
+
+    async fn delete_transaction(&mut self, id: &str) -> Result<(), Error> {
+        return delete_transaction(&self, id).await;
+    }
 }
 
 async fn delete_asset(data_access: &FileSystemDataAccess, id: &str) -> Result<(), Error> {
@@ -651,6 +1094,7 @@ async fn delete_asset(data_access: &FileSystemDataAccess, id: &str) -> Result<()
     if !path.exists() {
         return Err(Error {
             message: String::from("Asset to delete does not exist!"),
+            ..Default::default()
         });
     }
     remove_asset(&data_access.root, id)?;
@@ -669,8 +1113,8 @@ impl ViewWatchlistDataAccess for FileSystemDataAccess {
         return update_pair(&self, pair).await;
     }
 
-    async fn find_watchlist(&mut self) -> Result<Option<Watchlist>, Error> {
-        return find_watchlist(&self).await;
+    async fn fetch_watchlists(&mut self) -> Result<Vec<Watchlist>, Error> {
+        return fetch_watchlists(&self).await;
     }
 
     async fn save_watchlist(&mut self, watchlist: &Watchlist) -> Result<(), Error> {
@@ -680,19 +1124,60 @@ impl ViewWatchlistDataAccess for FileSystemDataAccess {
     async fn update_watchlist(&mut self, watchlist: &Watchlist) -> Result<(), Error> {
         return update_watchlist(&self, watchlist).await;
     }
+
+    async fn update_daily_rate_snapshot(
+        &mut self,
+        rate_snapshot: &RateSnapshot,
+    ) -> Result<(), Error> {
+        return update_daily_rate_snapshot(&self, rate_snapshot).await;
+    }
+
+    async fn update_hourly_rate_snapshot(
+        &mut self,
+        rate_snapshot: &RateSnapshot,
+    ) -> Result<(), Error> {
+        return update_hourly_rate_snapshot(&self, rate_snapshot).await;
+    }
+
+    async fn fetch_daily_rate_snapshots_since(
+        &mut self,
+        date_time: &DateTime<Utc>,
+    ) -> Result<Vec<RateSnapshot>, Error> {
+        return fetch_daily_rate_snapshots_since(&self, date_time).await;
+    }
+
+    async fn fetch_hourly_rate_snapshots(&mut self) -> Result<Vec<RateSnapshot>, Error> {
+        return fetch_hourly_rate_snapshots(&self).await;
+    }
 }
 
-// NOTE: currently the business logic states that only one watchlist should exist
-async fn find_watchlist(data_access: &FileSystemDataAccess) -> Result<Option<Watchlist>, Error> {
+async fn fetch_watchlists(data_access: &FileSystemDataAccess) -> Result<Vec<Watchlist>, Error> {
+    let mut watchlists: Vec<Watchlist> = vec![];
     let entries = get_dir_entries(&data_access.root, WATCHLISTS_DIR_NAME)?;
-    if entries.len() == 0 {
-        return Ok(None);
+    for entry in entries {
+        let file_name = entry.file_name();
+        if let Some(id) = file_name.to_str() {
+            let watchlist = read_watchlist(&data_access.root, id)?;
+            watchlists.push(watchlist);
+        }
     }
-    let first_entry = &entries[0];
-    let file_name = first_entry.file_name();
-    if let Some(id) = file_name.to_str() {
-        let watchlist = read_watchlist(&data_access.root, id)?;
-        return Ok(Some(watchlist));
+    sort_watchlists(&mut watchlists);
+    return Ok(watchlists);
+}
+
+async fn find_watchlist(
+    data_access: &FileSystemDataAccess,
+    id: &str,
+) -> Result<Option<Watchlist>, Error> {
+    let entries = get_dir_entries(&data_access.root, WATCHLISTS_DIR_NAME)?;
+    for entry in entries {
+        let file_name = entry.file_name();
+        if let Some(comparison_id) = file_name.to_str() {
+            if comparison_id == id {
+                let watchlist = read_watchlist(&data_access.root, id)?;
+                return Ok(Some(watchlist));
+            }
+        }
     }
     return Ok(None);
 }
@@ -703,7 +1188,10 @@ fn read_watchlist(root: &Path, id: &str) -> Result<Watchlist, Error> {
     let fs_watchlist = create_object_from_file::<FileSystemWatchlist>(&path)?;
     let mut watchlist = Watchlist {
         id: fs_watchlist.id.clone(),
+        name: fs_watchlist.name.clone(),
+        position: fs_watchlist.position,
         pairs: vec![],
+        cross_pairs: vec![],
         created_at: fs_watchlist.created_at.clone(),
         updated_at: fs_watchlist.updated_at.clone(),
     };
@@ -711,6 +1199,10 @@ fn read_watchlist(root: &Path, id: &str) -> Result<Watchlist, Error> {
         let pair = read_pair(root, &pair_id)?;
         watchlist.pairs.push(pair);
     }
+    for pair_id in &fs_watchlist.cross_pairs {
+        let pair = read_pair(root, pair_id)?;
+        watchlist.cross_pairs.push(pair);
+    }
     return Ok(watchlist);
 }
 
@@ -722,7 +1214,8 @@ async fn save_watchlist(
     let path = dir.join(&watchlist.id);
     if path.exists() {
         return Err(Error {
-            message: String::from("Pair group to save already exists!"),
+            message: String::from("Watchlist to save already exists!"),
+            ..Default::default()
         });
     }
     write_watchlist(&data_access.root, watchlist)?;
@@ -737,7 +1230,8 @@ async fn update_watchlist(
     let path = dir.join(&watchlist.id);
     if !path.exists() {
         return Err(Error {
-            message: String::from("Pair group to update does not exist!"),
+            message: String::from("Watchlist to update does not exist!"),
+            ..Default::default()
         });
     }
     write_watchlist(&data_access.root, watchlist)?;
@@ -751,7 +1245,10 @@ fn write_watchlist(root: &Path, watchlist: &Watchlist) -> Result<(), Error> {
         &path,
         &FileSystemWatchlist {
             id: watchlist.id.clone(),
+            name: watchlist.name.clone(),
+            position: watchlist.position,
             pairs: watchlist.pairs.iter().map(|p| p.id.clone()).collect(),
+            cross_pairs: watchlist.cross_pairs.iter().map(|p| p.id.clone()).collect(),
             created_at: watchlist.created_at.clone(),
             updated_at: watchlist.updated_at.clone(),
         },
@@ -764,8 +1261,22 @@ impl StoreWatchlistCoinsDataAccess for FileSystemDataAccess {
         return save_pair(&self, pair).await;
     }
 
-    async fn get_watchlist(&mut self) -> Result<Watchlist, Error> {
-        return get_watchlist(&self).await;
+    async fn fetch_watchlists(&mut self) -> Result<Vec<Watchlist>, Error> {
+        return fetch_watchlists(&self).await;
+    }
+
+    async fn update_watchlist(&mut self, watchlist: &Watchlist) -> Result<(), Error> {
+        return update_watchlist(&self, watchlist).await;
+    }
+}
+
+impl DeleteWatchlistPairDataAccess for FileSystemDataAccess {
+    async fn delete_pair(&mut self, id: &str) -> Result<(), Error> {
+        return delete_pair(&self, id).await;
+    }
+
+    async fn fetch_watchlists(&mut self) -> Result<Vec<Watchlist>, Error> {
+        return fetch_watchlists(&self).await;
     }
 
     async fn update_watchlist(&mut self, watchlist: &Watchlist) -> Result<(), Error> {
@@ -773,23 +1284,719 @@ impl StoreWatchlistCoinsDataAccess for FileSystemDataAccess {
     }
 }
 
-async fn get_watchlist(data_access: &FileSystemDataAccess) -> Result<Watchlist, Error> {
-    if let Some(watchlist) = find_watchlist(data_access).await? {
-        return Ok(watchlist);
-    } else {
+impl ViewSettingsDataAccess for FileSystemDataAccess {
+    async fn fetch_settings(&mut self) -> Result<Settings, Error> {
+        return fetch_settings(&self).await;
+    }
+}
+
+// NOTE: the settings file is only written once the user changes something
+async fn fetch_settings(data_access: &FileSystemDataAccess) -> Result<Settings, Error> {
+    let dir = ensure_dir(&data_access.root, SETTINGS_DIR_NAME)?;
+    let path = dir.join(SETTINGS_FILE_NAME);
+    if !path.exists() {
+        return Ok(create_default_settings());
+    }
+    let fs_settings = create_object_from_file::<FileSystemSettings>(&path)?;
+    return Ok(Settings {
+        fiat_rates_url: fs_settings.fiat_rates_url,
+        crypto_rates_url: fs_settings.crypto_rates_url,
+        reference_currency: fs_settings.reference_currency,
+        refresh_interval: fs_settings.refresh_interval,
+        data_location: fs_settings.data_location,
+        network: NetworkSettings {
+            proxy: fs_settings.network.proxy.map(|p| ProxySettings {
+                url: p.url,
+                username: p.username,
+                password: p.password,
+                no_proxy: p.no_proxy,
+            }),
+            extra_ca_certificates: fs_settings.network.extra_ca_certificates,
+        },
+        cost_basis_method: fs_settings
+            .cost_basis_method
+            .and_then(|m| parse_cost_basis_method(&m).ok())
+            .unwrap_or(DEFAULT_COST_BASIS_METHOD),
+        default_watchlist_id: fs_settings.default_watchlist_id,
+        updated_at: fs_settings.updated_at,
+    });
+}
+
+impl UpdateSettingsDataAccess for FileSystemDataAccess {
+    async fn fetch_settings(&mut self) -> Result<Settings, Error> {
+        return fetch_settings(&self).await;
+    }
+
+    async fn update_settings(&mut self, settings: &Settings) -> Result<(), Error> {
+        return update_settings(&self, settings).await;
+    }
+
+    async fn find_watchlist(
+        &mut self,
+        data_location: &Option<String>,
+        id: &str,
+    ) -> Result<Option<Watchlist>, Error> {
+        let data_access = FileSystemDataAccess {
+            root: data_location
+                .as_ref()
+                .map(|l| l.into())
+                .unwrap_or(self.root.clone()),
+        };
+        return find_watchlist(&data_access, id).await;
+    }
+}
+
+async fn update_settings(
+    data_access: &FileSystemDataAccess,
+    settings: &Settings,
+) -> Result<(), Error> {
+    write_settings(&data_access.root, settings)?;
+    return Ok(());
+}
+
+fn write_settings(root: &Path, settings: &Settings) -> Result<(), Error> {
+    let dir = ensure_dir(root, SETTINGS_DIR_NAME)?;
+    let path = dir.join(SETTINGS_FILE_NAME);
+    write_object_file(
+        &path,
+        &FileSystemSettings {
+            fiat_rates_url: settings.fiat_rates_url.clone(),
+            crypto_rates_url: settings.crypto_rates_url.clone(),
+            reference_currency: settings.reference_currency.clone(),
+            refresh_interval: settings.refresh_interval,
+            data_location: settings.data_location.clone(),
+            network: FileSystemNetworkSettings {
+                proxy: settings
+                    .network
+                    .proxy
+                    .as_ref()
+                    .map(|p| FileSystemProxySettings {
+                        url: p.url.clone(),
+                        username: p.username.clone(),
+                        password: p.password.clone(),
+                        no_proxy: p.no_proxy.clone(),
+                    }),
+                extra_ca_certificates: settings.network.extra_ca_certificates.clone(),
+            },
+            cost_basis_method: Some(get_cost_basis_method_name(&settings.cost_basis_method)),
+            default_watchlist_id: settings.default_watchlist_id.clone(),
+            updated_at: settings.updated_at.clone(),
+        },
+    )?;
+    return Ok(());
+}
+
+impl AddTransactionDataAccess for FileSystemDataAccess {
+    async fn find_asset(&mut self, id: &str) -> Result<Option<Asset>, Error> {
+        return find_asset(&self, id).await;
+    }
+
+    async fn update_asset(&mut self, asset: &Asset) -> Result<(), Error> {
+        return update_asset(&self, asset).await;
+    }
+
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error> {
+        return fetch_transactions(&self).await;
+    }
+
+    async fn save_transaction(&mut self, transaction: &Transaction) -> Result<(), Error> {
+        return save_transaction(&self, transaction).await;
+    }
+}
+
+async fn fetch_transactions(data_access: &FileSystemDataAccess) -> Result<Vec<Transaction>, Error> {
+    let mut transactions: Vec<Transaction> = vec![];
+    let entries = get_dir_entries(&data_access.root, TRANSACTIONS_DIR_NAME)?;
+    for entry in entries {
+        let file_name = entry.file_name();
+        if let Some(id) = file_name.to_str() {
+            let transaction = read_transaction(&data_access.root, id)?;
+            transactions.push(transaction);
+        }
+    }
+    return Ok(transactions);
+}
+
+fn read_transaction(root: &Path, id: &str) -> Result<Transaction, Error> {
+    let dir = ensure_dir(root, TRANSACTIONS_DIR_NAME)?;
+    let path = dir.join(id);
+    let fs_transaction = create_object_from_file::<FileSystemTransaction>(&path)?;
+    return Ok(Transaction {
+        id: fs_transaction.id.clone(),
+        asset_id: fs_transaction.asset_id.clone(),
+        kind: parse_transaction_kind(&fs_transaction.kind)?,
+        quantity: fs_transaction.quantity,
+        price: fs_transaction.price,
+        price_currency: fs_transaction.price_currency.clone(),
+        counterpart_asset_id: fs_transaction.counterpart_asset_id.clone(),
+        executed_at: fs_transaction.executed_at.clone(),
+        notes: fs_transaction.notes.clone(),
+        created_at: fs_transaction.created_at.clone(),
+        updated_at: fs_transaction.updated_at.clone(),
+    });
+}
+
+fn write_transaction(root: &Path, transaction: &Transaction) -> Result<(), Error> {
+    let dir = ensure_dir(root, TRANSACTIONS_DIR_NAME)?;
+    let path = dir.join(&transaction.id);
+    write_object_file(
+        &path,
+        &FileSystemTransaction {
+            id: transaction.id.clone(),
+            asset_id: transaction.asset_id.clone(),
+            kind: get_transaction_kind_name(&transaction.kind),
+            quantity: transaction.quantity,
+            price: transaction.price,
+            price_currency: transaction.price_currency.clone(),
+            counterpart_asset_id: transaction.counterpart_asset_id.clone(),
+            executed_at: transaction.executed_at.clone(),
+            notes: transaction.notes.clone(),
+            created_at: transaction.created_at.clone(),
+            updated_at: transaction.updated_at.clone(),
+        },
+    )?;
+    return Ok(());
+}
+
+async fn save_transaction(
+    data_access: &FileSystemDataAccess,
+    transaction: &Transaction,
+) -> Result<(), Error> {
+    let dir = ensure_dir(&data_access.root, TRANSACTIONS_DIR_NAME)?;
+    let path = dir.join(&transaction.id);
+    if path.exists() {
         return Err(Error {
-            message: String::from("Watchlist not found!"),
+            message: String::from("Transaction to save already exists!"),
+            ..Default::default()
         });
-    };
+    }
+    write_transaction(&data_access.root, transaction)?;
+    return Ok(());
 }
 
-impl DeleteWatchlistPairDataAccess for FileSystemDataAccess {
-    async fn delete_pair(&mut self, id: &str) -> Result<(), Error> {
-        return delete_pair(&self, id).await;
+impl UpdateTransactionDataAccess for FileSystemDataAccess {
+    async fn find_asset(&mut self, id: &str) -> Result<Option<Asset>, Error> {
+        return find_asset(&self, id).await;
+    }
+
+    async fn update_asset(&mut self, asset: &Asset) -> Result<(), Error> {
+        return update_asset(&self, asset).await;
+    }
+
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error> {
+        return fetch_transactions(&self).await;
+    }
+
+    async fn find_transaction(&mut self, id: &str) -> Result<Option<Transaction>, Error> {
+        return find_transaction(&self, id).await;
+    }
+
+    async fn save_transaction(&mut self, transaction: &Transaction) -> Result<(), Error> {
+        return save_transaction(&self, transaction).await;
+    }
+
+    async fn update_transaction(&mut self, transaction: &Transaction) -> Result<(), Error> {
+        return update_transaction(&self, transaction).await;
+    }
+}
+
+async fn find_transaction(
+    data_access: &FileSystemDataAccess,
+    id: &str,
+) -> Result<Option<Transaction>, Error> {
+    let dir = ensure_dir(&data_access.root, TRANSACTIONS_DIR_NAME)?;
+    let path = dir.join(id);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let transaction = read_transaction(&data_access.root, id)?;
+    return Ok(Some(transaction));
+}
+
+async fn update_transaction(
+    data_access: &FileSystemDataAccess,
+    transaction: &Transaction,
+) -> Result<(), Error> {
+    let dir = ensure_dir(&data_access.root, TRANSACTIONS_DIR_NAME)?;
+    let path = dir.join(&transaction.id);
+    if !path.exists() {
+        return Err(Error {
+            message: String::from("Transaction to update does not exist!"),
+            ..Default::default()
+        });
+    }
+    write_transaction(&data_access.root, transaction)?;
+    return Ok(());
+}
+
+impl DeleteTransactionDataAccess for FileSystemDataAccess {
+    async fn find_asset(&mut self, id: &str) -> Result<Option<Asset>, Error> {
+        return find_asset(&self, id).await;
+    }
+
+    async fn update_asset(&mut self, asset: &Asset) -> Result<(), Error> {
+        return update_asset(&self, asset).await;
+    }
+
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error> {
+        return fetch_transactions(&self).await;
+    }
+
+    async fn delete_transaction(&mut self, id: &str) -> Result<(), Error> {
+        return delete_transaction(&self, id).await;
+    }
+}
+
+async fn delete_transaction(data_access: &FileSystemDataAccess, id: &str) -> Result<(), Error> {
+    let dir = ensure_dir(&data_access.root, TRANSACTIONS_DIR_NAME)?;
+    let path = dir.join(id);
+    if !path.exists() {
+        return Err(Error {
+            message: String::from("Transaction to delete does not exist!"),
+            ..Default::default()
+        });
+    }
+    remove_object_file(&path)?;
+    return Ok(());
+}
+
+impl ViewTransactionsDataAccess for FileSystemDataAccess {
+    async fn fetch_assets(&mut self) -> Result<Vec<Asset>, Error> {
+        return fetch_assets(&self).await;
+    }
+
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error> {
+        return fetch_transactions(&self).await;
+    }
+
+    async fn save_transaction(&mut self, transaction: &Transaction) -> Result<(), Error> {
+        return save_transaction(&self, transaction).await;
+    }
+}
+
+impl ExportTaxLotsDataAccess for FileSystemDataAccess {
+    async fn fetch_assets(&mut self) -> Result<Vec<Asset>, Error> {
+        return fetch_assets(&self).await;
+    }
+
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error> {
+        return fetch_transactions(&self).await;
+    }
+
+    async fn fetch_daily_rate_snapshots_on(
+        &mut self,
+        dates: &Vec<NaiveDate>,
+    ) -> Result<Vec<RateSnapshot>, Error> {
+        return fetch_daily_rate_snapshots_on(&self, dates).await;
+    }
+}
+
+impl ViewPortfolioHistoryDataAccess for FileSystemDataAccess {
+    async fn fetch_portfolio_snapshots(&mut self) -> Result<Vec<PortfolioSnapshot>, Error> {
+        return fetch_portfolio_snapshots(&self).await;
+    }
+
+    async fn fetch_daily_rate_snapshots(&mut self) -> Result<Vec<RateSnapshot>, Error> {
+        return fetch_daily_rate_snapshots(&self).await;
+    }
+}
+
+async fn fetch_portfolio_snapshots(
+    data_access: &FileSystemDataAccess,
+) -> Result<Vec<PortfolioSnapshot>, Error> {
+    let mut portfolio_snapshots: Vec<PortfolioSnapshot> = vec![];
+    let entries = get_dir_entries(&data_access.root, PORTFOLIO_SNAPSHOTS_DIR_NAME)?;
+    for entry in entries {
+        let file_name = entry.file_name();
+        if let Some(bucket) = file_name.to_str() {
+            let portfolio_snapshot = read_portfolio_snapshot(&data_access.root, bucket)?;
+            portfolio_snapshots.push(portfolio_snapshot);
+        }
+    }
+    return Ok(portfolio_snapshots);
+}
+
+fn read_portfolio_snapshot(root: &Path, bucket: &str) -> Result<PortfolioSnapshot, Error> {
+    let dir = ensure_dir(root, PORTFOLIO_SNAPSHOTS_DIR_NAME)?;
+    let path = dir.join(bucket);
+    let fs_portfolio_snapshot = create_object_from_file::<FileSystemPortfolioSnapshot>(&path)?;
+    return Ok(PortfolioSnapshot {
+        bucket: fs_portfolio_snapshot.bucket.clone(),
+        total_usd_value: fs_portfolio_snapshot.total_usd_value,
+        tag_values: fs_portfolio_snapshot
+            .tag_values
+            .iter()
+            .map(|tv| TagValue {
+                tag_id: tv.tag_id.clone(),
+                usd_value: tv.usd_value,
+            })
+            .collect(),
+        created_at: fs_portfolio_snapshot.created_at.clone(),
+    });
+}
+
+// NOTE: a snapshot of an already recorded bucket replaces the previous one
+async fn update_portfolio_snapshot(
+    data_access: &FileSystemDataAccess,
+    portfolio_snapshot: &PortfolioSnapshot,
+) -> Result<(), Error> {
+    let dir = ensure_dir(&data_access.root, PORTFOLIO_SNAPSHOTS_DIR_NAME)?;
+    let path = dir.join(&portfolio_snapshot.bucket);
+    write_object_file(
+        &path,
+        &FileSystemPortfolioSnapshot {
+            bucket: portfolio_snapshot.bucket.clone(),
+            total_usd_value: portfolio_snapshot.total_usd_value,
+            tag_values: portfolio_snapshot
+                .tag_values
+                .iter()
+                .map(|tv| FileSystemTagValue {
+                    tag_id: tv.tag_id.clone(),
+                    usd_value: tv.usd_value,
+                })
+                .collect(),
+            created_at: portfolio_snapshot.created_at.clone(),
+        },
+    )?;
+    return Ok(());
+}
+
+impl UpdateTagTargetsDataAccess for FileSystemDataAccess {
+    async fn find_tag(&mut self, id: &str) -> Result<Option<Tag>, Error> {
+        return find_tag(&self, id).await;
+    }
+
+    async fn update_tag(&mut self, tag: &Tag) -> Result<(), Error> {
+        return update_tag(&self, tag).await;
+    }
+}
+
+impl SuggestRebalanceDataAccess for FileSystemDataAccess {
+    async fn find_tag(&mut self, id: &str) -> Result<Option<Tag>, Error> {
+        return find_tag(&self, id).await;
+    }
+
+    async fn find_rate_snapshot(&mut self) -> Result<Option<RateSnapshot>, Error> {
+        return find_rate_snapshot(&self).await;
+    }
+}
+
+async fn fetch_accounts(data_access: &FileSystemDataAccess) -> Result<Vec<Account>, Error> {
+    let mut accounts: Vec<Account> = vec![];
+    let entries = get_dir_entries(&data_access.root, ACCOUNTS_DIR_NAME)?;
+    for entry in entries {
+        let file_name = entry.file_name();
+        if let Some(id) = file_name.to_str() {
+            let account = read_account(&data_access.root, id)?;
+            accounts.push(account);
+        }
+    }
+    accounts.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    return Ok(accounts);
+}
+
+async fn find_account(
+    data_access: &FileSystemDataAccess,
+    id: &str,
+) -> Result<Option<Account>, Error> {
+    let entries = get_dir_entries(&data_access.root, ACCOUNTS_DIR_NAME)?;
+    for entry in entries {
+        let file_name = entry.file_name();
+        if let Some(comparison_id) = file_name.to_str() {
+            if comparison_id == id {
+                let account = read_account(&data_access.root, id)?;
+                return Ok(Some(account));
+            }
+        }
+    }
+    return Ok(None);
+}
+
+fn read_account(root: &Path, id: &str) -> Result<Account, Error> {
+    let dir = ensure_dir(root, ACCOUNTS_DIR_NAME)?;
+    let path = dir.join(id);
+    let fs_account = create_object_from_file::<FileSystemAccount>(&path)?;
+    return Ok(Account {
+        id: fs_account.id.clone(),
+        name: fs_account.name.clone(),
+        created_at: fs_account.created_at.clone(),
+        updated_at: fs_account.updated_at.clone(),
+    });
+}
+
+fn write_account(root: &Path, account: &Account) -> Result<(), Error> {
+    let dir = ensure_dir(root, ACCOUNTS_DIR_NAME)?;
+    let path = dir.join(&account.id);
+    write_object_file(
+        &path,
+        &FileSystemAccount {
+            id: account.id.clone(),
+            name: account.name.clone(),
+            created_at: account.created_at.clone(),
+            updated_at: account.updated_at.clone(),
+        },
+    )?;
+    return Ok(());
+}
+
+impl SaveAccountDataAccess for FileSystemDataAccess {
+    async fn save_account(&mut self, account: &Account) -> Result<(), Error> {
+        return save_account(&self, account).await;
+    }
+}
+
+async fn save_account(data_access: &FileSystemDataAccess, account: &Account) -> Result<(), Error> {
+    let dir = ensure_dir(&data_access.root, ACCOUNTS_DIR_NAME)?;
+    let path = dir.join(&account.id);
+    if path.exists() {
+        return Err(Error {
+            message: String::from("Account to save already exists!"),
+            ..Default::default()
+        });
+    }
+    write_account(&data_access.root, account)?;
+    return Ok(());
+}
+
+impl DeleteAccountDataAccess for FileSystemDataAccess {
+    async fn fetch_assets(&mut self) -> Result<Vec<Asset>, Error> {
+        return fetch_assets(&self).await;
+    }
+
+    async fn update_asset(&mut self, asset: &Asset) -> Result<(), Error> {
+        return update_asset(&self, asset).await;
+    }
+
+    async fn delete_account(&mut self, id: &str) -> Result<(), Error> {
+        return delete_account(&self, id).await;
+    }
+}
+
+async fn delete_account(data_access: &FileSystemDataAccess, id: &str) -> Result<(), Error> {
+    let dir = ensure_dir(&data_access.root, ACCOUNTS_DIR_NAME)?;
+    let path = dir.join(id);
+    if !path.exists() {
+        return Err(Error {
+            message: String::from("Account to delete does not exist!"),
+            ..Default::default()
+        });
+    }
+    remove_object_file(&path)?;
+    return Ok(());
+}
+
+impl MoveAssetsDataAccess for FileSystemDataAccess {
+    async fn find_account(&mut self, id: &str) -> Result<Option<Account>, Error> {
+        return find_account(&self, id).await;
+    }
+
+    async fn find_asset(&mut self, id: &str) -> Result<Option<Asset>, Error> {
+        return find_asset(&self, id).await;
+    }
+
+    async fn update_asset(&mut self, asset: &Asset) -> Result<(), Error> {
+        return update_asset(&self, asset).await;
+    }
+}
+
+impl UpdateTagDataAccess for FileSystemDataAccess {
+    async fn fetch_tags(&mut self) -> Result<Vec<Tag>, Error> {
+        return fetch_tags(&self).await;
+    }
+
+    async fn update_tag(&mut self, tag: &Tag) -> Result<(), Error> {
+        return update_tag(&self, tag).await;
+    }
+}
+
+impl RetagAssetsDataAccess for FileSystemDataAccess {
+    async fn fetch_tags(&mut self) -> Result<Vec<Tag>, Error> {
+        return fetch_tags(&self).await;
+    }
+
+    async fn fetch_assets(&mut self) -> Result<Vec<Asset>, Error> {
+        return fetch_assets(&self).await;
+    }
+
+    async fn update_tag(&mut self, tag: &Tag) -> Result<(), Error> {
+        return update_tag(&self, tag).await;
+    }
+}
+
+impl DeleteAssetsDataAccess for FileSystemDataAccess {
+    async fn fetch_tags(&mut self) -> Result<Vec<Tag>, Error> {
+        return fetch_tags(&self).await;
+    }
+
+    async fn fetch_assets(&mut self) -> Result<Vec<Asset>, Error> {
+        return fetch_assets(&self).await;
+    }
+
+    async fn update_tag(&mut self, tag: &Tag) -> Result<(), Error> {
+        return update_tag(&self, tag).await;
+    }
+
+    async fn delete_asset(&mut self, id: &str) -> Result<(), Error> {
+        return delete_asset(&self, id).await;
+    }
+
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error> {
+        return fetch_transactions(&self).await;
+    }
+
+    async fn update_transaction(&mut self, transaction: &Transaction) -> Result<(), Error> {
+        return update_transaction(&self, transaction).await;
+    }
+
+    async fn delete_transaction(&mut self, id: &str) -> Result<(), Error> {
+        return delete_transaction(&self, id).await;
+    }
+}
+
+impl AdjustAssetQuantitiesDataAccess for FileSystemDataAccess {
+    async fn fetch_assets(&mut self) -> Result<Vec<Asset>, Error> {
+        return fetch_assets(&self).await;
+    }
+
+    async fn update_asset(&mut self, asset: &Asset) -> Result<(), Error> {
+        return update_asset(&self, asset).await;
+    }
+
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error> {
+        return fetch_transactions(&self).await;
+    }
+
+    async fn save_transaction(&mut self, transaction: &Transaction) -> Result<(), Error> {
+        return save_transaction(&self, transaction).await;
+    }
+}
+
+impl MergeAssetsDataAccess for FileSystemDataAccess {
+    async fn fetch_tags(&mut self) -> Result<Vec<Tag>, Error> {
+        return fetch_tags(&self).await;
+    }
+
+    async fn fetch_assets(&mut self) -> Result<Vec<Asset>, Error> {
+        return fetch_assets(&self).await;
+    }
+
+    async fn update_tag(&mut self, tag: &Tag) -> Result<(), Error> {
+        return update_tag(&self, tag).await;
+    }
+
+    async fn update_asset(&mut self, asset: &Asset) -> Result<(), Error> {
+        return update_asset(&self, asset).await;
+    }
+
+    async fn delete_asset(&mut self, id: &str) -> Result<(), Error> {
+        return delete_asset(&self, id).await;
+    }
+
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error> {
+        return fetch_transactions(&self).await;
+    }
+
+    async fn save_transaction(&mut self, transaction: &Transaction) -> Result<(), Error> {
+        return save_transaction(&self, transaction).await;
+    }
+
+    async fn update_transaction(&mut self, transaction: &Transaction) -> Result<(), Error> {
+        return update_transaction(&self, transaction).await;
+    }
+
+    async fn delete_transaction(&mut self, id: &str) -> Result<(), Error> {
+        return delete_transaction(&self, id).await;
+    }
+}
+
+impl SaveWatchlistDataAccess for FileSystemDataAccess {
+    async fn fetch_watchlists(&mut self) -> Result<Vec<Watchlist>, Error> {
+        return fetch_watchlists(&self).await;
+    }
+
+    async fn save_watchlist(&mut self, watchlist: &Watchlist) -> Result<(), Error> {
+        return save_watchlist(&self, watchlist).await;
+    }
+}
+
+impl UpdateWatchlistDataAccess for FileSystemDataAccess {
+    async fn fetch_watchlists(&mut self) -> Result<Vec<Watchlist>, Error> {
+        return fetch_watchlists(&self).await;
+    }
+
+    async fn update_watchlist(&mut self, watchlist: &Watchlist) -> Result<(), Error> {
+        return update_watchlist(&self, watchlist).await;
+    }
+}
+
+impl ReorderWatchlistsDataAccess for FileSystemDataAccess {
+    async fn fetch_watchlists(&mut self) -> Result<Vec<Watchlist>, Error> {
+        return fetch_watchlists(&self).await;
+    }
+
+    async fn update_watchlist(&mut self, watchlist: &Watchlist) -> Result<(), Error> {
+        return update_watchlist(&self, watchlist).await;
+    }
+}
+
+impl DeleteWatchlistDataAccess for FileSystemDataAccess {
+    async fn find_watchlist(&mut self, id: &str) -> Result<Option<Watchlist>, Error> {
+        return find_watchlist(&self, id).await;
+    }
+
+    async fn delete_pair(&mut self, id: &str) -> Result<(), Error> {
+        return delete_pair(&self, id).await;
+    }
+
+    async fn delete_watchlist(&mut self, id: &str) -> Result<(), Error> {
+        return delete_watchlist(&self, id).await;
+    }
+}
+
+async fn delete_watchlist(data_access: &FileSystemDataAccess, id: &str) -> Result<(), Error> {
+    let dir = ensure_dir(&data_access.root, WATCHLISTS_DIR_NAME)?;
+    let path = dir.join(id);
+    if !path.exists() {
+        return Err(Error {
+            message: String::from("Watchlist to delete does not exist!"),
+            ..Default::default()
+        });
+    }
+    remove_object_file(&path)?;
+    return Ok(());
+}
+
+impl ReorderPairGroupsDataAccess for FileSystemDataAccess {
+    async fn fetch_pair_groups(&mut self) -> Result<Vec<PairGroup>, Error> {
+        return fetch_pair_groups(&self).await;
+    }
+
+    async fn update_pair_group(&mut self, pair_group: &PairGroup) -> Result<(), Error> {
+        return update_pair_group(&self, pair_group).await;
+    }
+}
+
+impl ReorderPairGroupPairsDataAccess for FileSystemDataAccess {
+    async fn update_pair(&mut self, pair: &Pair) -> Result<(), Error> {
+        return update_pair(&self, pair).await;
+    }
+
+    async fn find_pair_group(&mut self, id: &str) -> Result<Option<PairGroup>, Error> {
+        return find_pair_group(&self, id).await;
+    }
+
+    async fn update_pair_group(&mut self, pair_group: &PairGroup) -> Result<(), Error> {
+        return update_pair_group(&self, pair_group).await;
+    }
+}
+
+impl ReorderWatchlistPairsDataAccess for FileSystemDataAccess {
+    async fn update_pair(&mut self, pair: &Pair) -> Result<(), Error> {
+        return update_pair(&self, pair).await;
     }
 
-    async fn get_watchlist(&mut self) -> Result<Watchlist, Error> {
-        return get_watchlist(&self).await;
+    async fn fetch_watchlists(&mut self) -> Result<Vec<Watchlist>, Error> {
+        return fetch_watchlists(&self).await;
     }
 
     async fn update_watchlist(&mut self, watchlist: &Watchlist) -> Result<(), Error> {
@@ -808,6 +2015,7 @@ mod tests {
 
     use super::*;
     use chrono::Utc;
+    use rust_decimal::Decimal;
     use tempfile::tempdir;
 
     #[tokio::test]
@@ -824,25 +2032,28 @@ mod tests {
         let example_pairs: Vec<Pair> = vec![
             Pair {
                 id: "p1".to_string(),
-                value: 1.0,
+                value: Decimal::from(1),
                 base: "USD".to_string(),
                 comparison: "BTC".to_string(),
+                position: 0,
                 created_at: Utc::now().to_rfc3339(),
                 updated_at: Utc::now().to_rfc3339(),
             },
             Pair {
                 id: "p2".to_string(),
-                value: 2.0,
+                value: Decimal::from(2),
                 base: "USD".to_string(),
                 comparison: "ETH".to_string(),
+                position: 0,
                 created_at: Utc::now().to_rfc3339(),
                 updated_at: Utc::now().to_rfc3339(),
             },
             Pair {
                 id: "p3".to_string(),
-                value: 3.0,
+                value: Decimal::from(3),
                 base: "USD".to_string(),
                 comparison: "BRL".to_string(),
+                position: 0,
                 created_at: Utc::now().to_rfc3339(),
                 updated_at: Utc::now().to_rfc3339(),
             },
@@ -852,7 +2063,8 @@ mod tests {
             PairGroup {
                 id: "pg1".to_string(),
                 is_pinned: true,
-                multiplier: 1.0,
+                multiplier: Decimal::ONE,
+                position: 0,
                 pairs: vec![example_pairs[0].clone(), example_pairs[1].clone()],
                 created_at: Utc::now().to_rfc3339(),
                 updated_at: Utc::now().to_rfc3339(),
@@ -860,7 +2072,8 @@ mod tests {
             PairGroup {
                 id: "pg2".to_string(),
                 is_pinned: false,
-                multiplier: 1.0,
+                multiplier: Decimal::ONE,
+                position: 0,
                 pairs: vec![example_pairs[2].clone()],
                 created_at: Utc::now().to_rfc3339(),
                 updated_at: Utc::now().to_rfc3339(),
@@ -896,25 +2109,28 @@ mod tests {
         let example_pairs: Vec<Pair> = vec![
             Pair {
                 id: "p1".to_string(),
-                value: 1.0,
+                value: Decimal::from(1),
                 base: "USD".to_string(),
                 comparison: "BTC".to_string(),
+                position: 0,
                 created_at: Utc::now().to_rfc3339(),
                 updated_at: Utc::now().to_rfc3339(),
             },
             Pair {
                 id: "p2".to_string(),
-                value: 2.0,
+                value: Decimal::from(2),
                 base: "USD".to_string(),
                 comparison: "ETH".to_string(),
+                position: 0,
                 created_at: Utc::now().to_rfc3339(),
                 updated_at: Utc::now().to_rfc3339(),
             },
             Pair {
                 id: "p3".to_string(),
-                value: 3.0,
+                value: Decimal::from(3),
                 base: "USD".to_string(),
                 comparison: "BRL".to_string(),
+                position: 0,
                 created_at: Utc::now().to_rfc3339(),
                 updated_at: Utc::now().to_rfc3339(),
             },
@@ -923,7 +2139,8 @@ mod tests {
         let original_pair_group = PairGroup {
             id: "pg1".to_string(),
             is_pinned: false,
-            multiplier: 1.0,
+            multiplier: Decimal::ONE,
+            position: 0,
             pairs: vec![example_pairs[0].clone(), example_pairs[1].clone()],
             created_at: Utc::now().to_rfc3339(),
             updated_at: Utc::now().to_rfc3339(),
@@ -934,7 +2151,8 @@ mod tests {
         let updated_pair_group = PairGroup {
             id: "pg1".to_string(),
             is_pinned: true,
-            multiplier: 1.0,
+            multiplier: Decimal::ONE,
+            position: 0,
             pairs: vec![
                 example_pairs[0].clone(),
                 example_pairs[1].clone(),
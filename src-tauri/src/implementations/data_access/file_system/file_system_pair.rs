@@ -1,13 +1,16 @@
 use std::hash::Hash;
 
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct FileSystemPair {
     pub id: String,
-    pub value: f64,
+    pub value: Decimal,
     pub base: String,
     pub comparison: String,
+    #[serde(default)]
+    pub position: u32,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -18,6 +21,7 @@ impl PartialEq for FileSystemPair {
             && self.base == other.base
             && self.value == other.value
             && self.comparison == other.comparison
+            && self.position == other.position
             && self.created_at == other.created_at
             && self.updated_at == other.updated_at;
     }
@@ -0,0 +1,17 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileSystemTagValue {
+    pub tag_id: String,
+    pub usd_value: Decimal,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileSystemPortfolioSnapshot {
+    pub bucket: String,
+    pub total_usd_value: Decimal,
+    #[serde(default)]
+    pub tag_values: Vec<FileSystemTagValue>,
+    pub created_at: String,
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use super::file_system_pair::FileSystemPair;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileSystemRateSnapshot {
+    pub pairs: Vec<FileSystemPair>,
+    pub created_at: String,
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileSystemProxySettings {
+    pub url: String,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+    #[serde(default)]
+    pub no_proxy: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct FileSystemNetworkSettings {
+    #[serde(default)]
+    pub proxy: Option<FileSystemProxySettings>,
+    #[serde(default)]
+    pub extra_ca_certificates: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileSystemSettings {
+    pub fiat_rates_url: String,
+    pub crypto_rates_url: String,
+    pub reference_currency: String,
+    pub refresh_interval: u64,
+    #[serde(default)]
+    pub data_location: Option<String>,
+    #[serde(default)]
+    pub network: FileSystemNetworkSettings,
+    #[serde(default)]
+    pub cost_basis_method: Option<String>,
+    #[serde(default)]
+    pub default_watchlist_id: Option<String>,
+    pub updated_at: String,
+}
@@ -1,12 +1,33 @@
 use std::hash::Hash;
 
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileSystemTargetWeight {
+    pub coin: String,
+    pub weight: Decimal,
+}
+
+impl PartialEq for FileSystemTargetWeight {
+    fn eq(&self, other: &Self) -> bool {
+        return self.coin == other.coin && self.weight == other.weight;
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct FileSystemTag {
     pub id: String,
     pub name: String,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub parent_id: Option<String>,
     pub assets: Vec<String>,
+    #[serde(default)]
+    pub targets: Vec<FileSystemTargetWeight>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -15,7 +36,11 @@ impl PartialEq for FileSystemTag {
     fn eq(&self, other: &Self) -> bool {
         return self.id == other.id
             && self.name == other.name
+            && self.color == other.color
+            && self.description == other.description
+            && self.parent_id == other.parent_id
             && self.assets == other.assets
+            && self.targets == other.targets
             && self.created_at == other.created_at
             && self.updated_at == other.updated_at;
     }
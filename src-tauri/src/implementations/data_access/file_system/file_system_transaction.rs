@@ -0,0 +1,21 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileSystemTransaction {
+    pub id: String,
+    pub asset_id: String,
+    pub kind: String,
+    pub quantity: Decimal,
+    #[serde(default)]
+    pub price: Option<Decimal>,
+    #[serde(default)]
+    pub price_currency: Option<String>,
+    #[serde(default)]
+    pub counterpart_asset_id: Option<String>,
+    pub executed_at: String,
+    #[serde(default)]
+    pub notes: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
@@ -5,7 +5,13 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct FileSystemWatchlist {
     pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub position: u32,
     pub pairs: Vec<String>,
+    #[serde(default)]
+    pub cross_pairs: Vec<String>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -13,7 +19,10 @@ pub struct FileSystemWatchlist {
 impl PartialEq for FileSystemWatchlist {
     fn eq(&self, other: &Self) -> bool {
         return self.id == other.id
+            && self.name == other.name
+            && self.position == other.position
             && self.pairs == other.pairs
+            && self.cross_pairs == other.cross_pairs
             && self.created_at == other.created_at
             && self.updated_at == other.updated_at;
     }
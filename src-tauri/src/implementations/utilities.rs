@@ -1 +1,3 @@
 pub mod coin_market;
+pub mod http_client;
+pub mod settings_channel;
@@ -1,117 +1,558 @@
-use std::collections::HashMap;
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
 
-use chrono::Utc;
-use serde::Deserialize;
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use serde_json::Value;
 use uuid::Uuid;
 
-use crate::{entities::pair::Pair, utilities::coin_market::CoinMarket, Error};
+use crate::{
+    entities::{
+        currency::{Currency, CurrencyKind},
+        pair::Pair,
+    },
+    utilities::{
+        coin_market::{CoinMarket, FetchReport, MarketFetch, SkippedRecord},
+        currency_catalogue::{
+            create_crypto_currency, create_fiat_currency, find_bundled_crypto_currency,
+        },
+        rounding_policy::round_rate,
+    },
+    Error,
+};
+
+use super::super::http_client::{HttpClient, HttpResponse};
+
+/*
+    NOTE: the market cache is shared between instances (like the HTTP client) so
+    that the feeds are not requested again within the refresh interval, and
+    unchanged feeds, answered with `304 Not Modified`, are not parsed again
+*/
+pub type MarketCache = Arc<Mutex<Option<CachedMarket>>>;
+
+pub struct CachedMarket {
+    pub fiat_version: u64,
+    pub crypto_version: u64,
+    pub market_fetch: MarketFetch,
+    pub fetched_at: DateTime<Utc>,
+}
 
+// NOTE: `refresh_interval` is in seconds, like in the settings
 pub struct GithubCoinMarket {
     pub fiat_rates_url: String,
     pub crypto_rates_url: String,
+    pub refresh_interval: u64,
+    pub http_client: HttpClient,
+    pub market_cache: MarketCache,
 }
 
-#[derive(Deserialize)]
 struct FiatResponse {
-    pub rates: HashMap<String, f64>,
+    pub rates: HashMap<String, Decimal>,
 }
 
-#[derive(Deserialize)]
 struct CryptoObject {
+    pub id: Option<String>,
+    pub name: Option<String>,
     pub symbol: String,
-    pub current_price: f64,
+    pub current_price: Decimal,
+    pub market_cap_rank: Option<u64>,
+}
+
+struct MarketCurrency {
+    pub currency: Currency,
+    pub usd_value: Decimal,
 }
 
 impl CoinMarket for GithubCoinMarket {
-    async fn fetch_usd_pairs(&mut self) -> Result<Vec<Pair>, Error> {
-        let usd_fiat_pairs: Vec<Pair> = fetch_usd_fiat_pairs(&self.fiat_rates_url).await?;
-        let usd_crypto_pairs: Vec<Pair> = fetch_usd_crypto_pairs(&self.crypto_rates_url).await?;
-        let usd_pairs = usd_fiat_pairs.iter().chain(&usd_crypto_pairs);
-        let mut non_repeated_usd_pairs: Vec<Pair> = vec![];
-        for usd_pair in usd_pairs {
-            if !non_repeated_usd_pairs.iter().any(|p| {
-                return p.id != usd_pair.id && p.comparison == usd_pair.comparison;
-            }) {
-                non_repeated_usd_pairs.push(usd_pair.clone());
+    async fn fetch_market(&mut self) -> Result<MarketFetch, Error> {
+        if let Some(cached_market) = self.market_cache.lock().unwrap().as_ref() {
+            if is_cache_fresh(
+                &cached_market.fetched_at,
+                &Utc::now(),
+                self.refresh_interval,
+            ) {
+                return Ok(cached_market.market_fetch.clone());
             }
         }
-        return Ok(non_repeated_usd_pairs);
-    }
-}
-
-async fn fetch_usd_fiat_pairs(url: &str) -> Result<Vec<Pair>, Error> {
-    match reqwest::get(url).await {
-        Ok(resp) => match resp.text().await {
-            Ok(text) => {
-                let mut pairs: Vec<Pair> = vec![];
-                let data: FiatResponse = serde_json::from_str(&text).unwrap();
-                for rate in &data.rates {
-                    let code = rate.0;
-                    let value = rate.1;
-                    pairs.push(Pair {
-                        id: Uuid::new_v4().to_string(),
-                        value: value.clone(),
-                        base: String::from("USD"),
-                        comparison: code.to_string(),
-                        created_at: Utc::now().to_rfc3339(),
-                        updated_at: Utc::now().to_rfc3339(),
-                    });
+        let fiat_response =
+            fetch_feed_text(&self.http_client, &self.fiat_rates_url, "fiat").await?;
+        let crypto_response =
+            fetch_feed_text(&self.http_client, &self.crypto_rates_url, "crypto").await?;
+
+        let mut market_cache = self.market_cache.lock().unwrap();
+        if let Some(cached_market) = market_cache.as_ref() {
+            if cached_market.fiat_version == fiat_response.version
+                && cached_market.crypto_version == crypto_response.version
+            {
+                let mut market_fetch = cached_market.market_fetch.clone();
+                for report in market_fetch.reports.iter_mut() {
+                    let response = if report.source == "fiat" {
+                        &fiat_response
+                    } else {
+                        &crypto_response
+                    };
+                    report.status = get_report_status(response);
+                    report.fetched_at = Utc::now().to_rfc3339();
                 }
-                return Ok(pairs);
-            }
-            Err(_) => {
-                return Err(Error {
-                    message: String::from(
-                        "Could not get the response text from the fiat coin market!",
-                    ),
-                })
+                *market_cache = Some(CachedMarket {
+                    fiat_version: fiat_response.version,
+                    crypto_version: crypto_response.version,
+                    market_fetch: market_fetch.clone(),
+                    fetched_at: Utc::now(),
+                });
+                return Ok(market_fetch);
             }
-        },
-        Err(_) => {
-            return Err(Error {
-                message: String::from("Could not fetch the fiat coin market!"),
-            })
         }
+
+        let mut fiat_report = create_fetch_report(
+            "fiat",
+            &self.fiat_rates_url,
+            get_report_status(&fiat_response),
+        );
+        let fiat_data = parse_fiat_response(&fiat_response.body, &mut fiat_report)?;
+        let mut crypto_report = create_fetch_report(
+            "crypto",
+            &self.crypto_rates_url,
+            get_report_status(&crypto_response),
+        );
+        let crypto_objects = parse_crypto_objects(&crypto_response.body, &mut crypto_report)?;
+        let market_currencies =
+            resolve_market_currencies(&fiat_data, &crypto_objects, &mut crypto_report);
+        let market_fetch = MarketFetch {
+            pairs: market_currencies
+                .iter()
+                .map(|mc| Pair {
+                    id: Uuid::new_v4().to_string(),
+                    value: mc.usd_value,
+                    base: String::from("USD"),
+                    comparison: mc.currency.id.clone(),
+                    position: 0,
+                    created_at: Utc::now().to_rfc3339(),
+                    updated_at: Utc::now().to_rfc3339(),
+                })
+                .collect(),
+            currencies: market_currencies
+                .into_iter()
+                .map(|mc| mc.currency)
+                .collect(),
+            reports: vec![fiat_report, crypto_report],
+        };
+        *market_cache = Some(CachedMarket {
+            fiat_version: fiat_response.version,
+            crypto_version: crypto_response.version,
+            market_fetch: market_fetch.clone(),
+            fetched_at: Utc::now(),
+        });
+        return Ok(market_fetch);
     }
 }
 
-async fn fetch_usd_crypto_pairs(url: &str) -> Result<Vec<Pair>, Error> {
-    match reqwest::get(url).await {
-        Ok(resp) => match resp.text().await {
-            Ok(text) => {
-                let mut pairs: Vec<Pair> = vec![];
-                let data: Vec<CryptoObject> = serde_json::from_str(&text).unwrap();
-                for crypto_object in &data {
-                    let code: &String = &crypto_object.symbol.to_uppercase();
-                    let value = &crypto_object.current_price;
-                    pairs.push(Pair {
-                        id: Uuid::new_v4().to_string(),
-                        value: 1.0 / value,
-                        base: String::from("USD"),
-                        comparison: code.to_string(),
-                        created_at: Utc::now().to_rfc3339(),
-                        updated_at: Utc::now().to_rfc3339(),
+fn is_cache_fresh(fetched_at: &DateTime<Utc>, now: &DateTime<Utc>, refresh_interval: u64) -> bool {
+    return *now - *fetched_at < Duration::seconds(refresh_interval as i64);
+}
+
+/*
+    Both feeds are keyed by ticker symbols, which collide quite often: several
+    tokens share a symbol, and some tokens reuse ISO fiat codes. The precedence
+    policy below is deterministic regardless of the feed ordering:
+
+        - fiat codes always keep the plain ticker as their id;
+        - among cryptos sharing a symbol, the one matching the bundled catalogue
+          wins, then the best market cap rank, then the provider id alphabetically;
+        - the winner keeps the plain ticker as its id (unless a fiat took it) and
+          every other crypto gets `SYMBOL:provider-id`, so it stays selectable.
+
+    Every currency involved in a collision lists the ids it collides with in
+    `ambiguous_with`, so the user can pick which asset they mean.
+*/
+fn resolve_market_currencies(
+    fiat_response: &FiatResponse,
+    crypto_objects: &Vec<CryptoObject>,
+    crypto_report: &mut FetchReport,
+) -> Vec<MarketCurrency> {
+    let mut market_currencies: Vec<MarketCurrency> = vec![];
+    for (code, value) in &fiat_response.rates {
+        market_currencies.push(MarketCurrency {
+            currency: create_fiat_currency(&code.to_uppercase()),
+            usd_value: value.clone(),
+        });
+    }
+
+    let mut crypto_groups: BTreeMap<String, Vec<&CryptoObject>> = BTreeMap::new();
+    for crypto_object in crypto_objects {
+        crypto_groups
+            .entry(crypto_object.symbol.to_uppercase())
+            .or_insert(vec![])
+            .push(crypto_object);
+    }
+
+    for (code, mut crypto_group) in crypto_groups {
+        crypto_group.sort_by(|a, b| compare_crypto_precedence(&code, a, b));
+        let fiat_idx = market_currencies
+            .iter()
+            .position(|mc| mc.currency.kind == CurrencyKind::Fiat && mc.currency.code == code);
+        let mut group_currencies: Vec<MarketCurrency> = vec![];
+        for crypto_object in crypto_group {
+            let maybe_usd_value = Decimal::ONE.checked_div(crypto_object.current_price);
+            if maybe_usd_value.is_none() {
+                continue;
+            }
+            let provider_id = crypto_object.id.as_deref();
+            let mut currency =
+                create_crypto_currency(&code, crypto_object.name.as_deref(), provider_id);
+            if fiat_idx.is_some() || group_currencies.len() > 0 {
+                if provider_id.is_none() {
+                    crypto_report.accepted_records -= 1;
+                    crypto_report.skipped_records.push(SkippedRecord {
+                        key: code.clone(),
+                        reason: String::from("Colliding symbol without a provider id"),
                     });
+                    continue;
                 }
-                return Ok(pairs);
+                currency.id = format!("{}:{}", code, provider_id.unwrap());
             }
-            Err(_) => {
-                return Err(Error {
-                    message: String::from(
-                        "Could not get the response text from the crypto coin market!",
-                    ),
-                })
+            group_currencies.push(MarketCurrency {
+                currency,
+                usd_value: round_rate(maybe_usd_value.unwrap()),
+            });
+        }
+
+        let mut colliding_ids: Vec<String> = group_currencies
+            .iter()
+            .map(|mc| mc.currency.id.clone())
+            .collect();
+        if let Some(idx) = fiat_idx {
+            colliding_ids.push(market_currencies[idx].currency.id.clone());
+        }
+        if colliding_ids.len() > 1 {
+            for market_currency in &mut group_currencies {
+                market_currency.currency.ambiguous_with =
+                    get_other_ids(&colliding_ids, &market_currency.currency.id);
             }
-        },
-        Err(_) => {
-            return Err(Error {
-                message: String::from("Could not fetch the crypto coin market!"),
-            })
+            if let Some(idx) = fiat_idx {
+                let fiat_id = market_currencies[idx].currency.id.clone();
+                market_currencies[idx].currency.ambiguous_with =
+                    get_other_ids(&colliding_ids, &fiat_id);
+            }
+        }
+        market_currencies.append(&mut group_currencies);
+    }
+
+    market_currencies.sort_by(|a, b| a.currency.id.cmp(&b.currency.id));
+    return market_currencies;
+}
+
+fn compare_crypto_precedence(code: &str, a: &CryptoObject, b: &CryptoObject) -> Ordering {
+    let is_a_bundled =
+        find_bundled_crypto_currency(code, a.id.as_deref()).is_some() && a.id.is_some();
+    let is_b_bundled =
+        find_bundled_crypto_currency(code, b.id.as_deref()).is_some() && b.id.is_some();
+    return is_b_bundled
+        .cmp(&is_a_bundled)
+        .then_with(|| match (a.market_cap_rank, b.market_cap_rank) {
+            (Some(a_rank), Some(b_rank)) => a_rank.cmp(&b_rank),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        })
+        .then_with(|| a.id.cmp(&b.id));
+}
+
+fn get_other_ids(ids: &Vec<String>, id: &str) -> Vec<String> {
+    return ids.iter().filter(|i| *i != id).cloned().collect();
+}
+
+async fn fetch_feed_text(
+    http_client: &HttpClient,
+    url: &str,
+    source: &str,
+) -> Result<HttpResponse, Error> {
+    return http_client.get_text(url).await.map_err(|e| Error {
+        message: format!("Could not fetch the {} coin market! {}", source, e.message),
+        ..Default::default()
+    });
+}
+
+fn get_report_status(response: &HttpResponse) -> u16 {
+    if response.is_not_modified {
+        return 304;
+    }
+    return response.status;
+}
+
+fn create_fetch_report(source: &str, url: &str, status: u16) -> FetchReport {
+    return FetchReport {
+        source: source.to_string(),
+        url: url.to_string(),
+        status,
+        fetched_at: Utc::now().to_rfc3339(),
+        accepted_records: 0,
+        skipped_records: vec![],
+    };
+}
+
+fn parse_fiat_response(text: &str, report: &mut FetchReport) -> Result<FiatResponse, Error> {
+    let data: Value = serde_json::from_str(text).map_err(|e| Error {
+        message: format!("The fiat coin market returned malformed JSON: {}", e),
+        ..Default::default()
+    })?;
+    let maybe_rates = data.get("rates").and_then(|r| r.as_object());
+    if maybe_rates.is_none() {
+        return Err(Error {
+            message: String::from("The fiat coin market response does not contain rates!"),
+            ..Default::default()
+        });
+    }
+    let mut rates: HashMap<String, Decimal> = HashMap::new();
+    for (code, value) in maybe_rates.unwrap() {
+        match parse_positive_decimal(value) {
+            Ok(rate) => {
+                rates.insert(code.to_uppercase(), rate);
+                report.accepted_records += 1;
+            }
+            Err(reason) => report.skipped_records.push(SkippedRecord {
+                key: code.clone(),
+                reason,
+            }),
+        }
+    }
+    return Ok(FiatResponse { rates });
+}
+
+fn parse_crypto_objects(text: &str, report: &mut FetchReport) -> Result<Vec<CryptoObject>, Error> {
+    let data: Value = serde_json::from_str(text).map_err(|e| Error {
+        message: format!("The crypto coin market returned malformed JSON: {}", e),
+        ..Default::default()
+    })?;
+    let maybe_records = data.as_array();
+    if maybe_records.is_none() {
+        return Err(Error {
+            message: String::from("The crypto coin market response is not a list!"),
+            ..Default::default()
+        });
+    }
+    let mut crypto_objects: Vec<CryptoObject> = vec![];
+    for (idx, record) in maybe_records.unwrap().iter().enumerate() {
+        let maybe_symbol = record
+            .get("symbol")
+            .and_then(|s| s.as_str())
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty());
+        if maybe_symbol.is_none() {
+            report.skipped_records.push(SkippedRecord {
+                key: format!("#{}", idx),
+                reason: String::from("Missing symbol"),
+            });
+            continue;
+        }
+        let symbol = maybe_symbol.unwrap();
+        let current_price = match record.get("current_price") {
+            Some(value) => parse_positive_decimal(value),
+            None => Err(String::from("Missing price")),
+        };
+        if let Err(reason) = current_price {
+            report.skipped_records.push(SkippedRecord {
+                key: symbol.to_uppercase(),
+                reason,
+            });
+            continue;
         }
+        crypto_objects.push(CryptoObject {
+            id: record
+                .get("id")
+                .and_then(|i| i.as_str())
+                .map(|i| i.to_string()),
+            name: record
+                .get("name")
+                .and_then(|n| n.as_str())
+                .map(|n| n.to_string()),
+            symbol: symbol.to_string(),
+            current_price: current_price.unwrap(),
+            market_cap_rank: record.get("market_cap_rank").and_then(|r| r.as_u64()),
+        });
+        report.accepted_records += 1;
+    }
+    return Ok(crypto_objects);
+}
+
+fn parse_positive_decimal(value: &Value) -> Result<Decimal, String> {
+    let maybe_decimal = match value {
+        Value::Null => return Err(String::from("Missing price")),
+        Value::Number(number) => number.as_f64().and_then(|n| Decimal::try_from(n).ok()),
+        Value::String(text) => Decimal::from_str(text.trim()).ok(),
+        _ => None,
+    };
+    if maybe_decimal.is_none() {
+        return Err(String::from("Non-numeric price"));
+    }
+    let decimal = maybe_decimal.unwrap();
+    if decimal.is_zero() {
+        return Err(String::from("Zero price"));
+    }
+    if decimal.is_sign_negative() {
+        return Err(String::from("Negative price"));
     }
+    return Ok(decimal);
 }
 
 #[cfg(test)]
 mod tests {
-    // TODO: create unit tests
+    use super::*;
+
+    fn create_crypto_object(
+        id: &str,
+        symbol: &str,
+        price: &str,
+        rank: Option<u64>,
+    ) -> CryptoObject {
+        return CryptoObject {
+            id: Some(id.to_string()),
+            name: Some(id.to_string()),
+            symbol: symbol.to_string(),
+            current_price: Decimal::from_str(price).unwrap(),
+            market_cap_rank: rank,
+        };
+    }
+
+    #[test]
+    fn test_resolve_market_currencies() {
+        /*
+            Unit test expectations:
+
+            - Fiat codes keep their plain ticker even when a crypto uses the same symbol.
+            - The bundled crypto wins its symbol regardless of feed order and rank.
+            - Colliding cryptos keep distinct ids and list each other as ambiguous.
+            - The resolution does not depend on the order of the crypto feed.
+        */
+        let fiat_response = FiatResponse {
+            rates: HashMap::from([
+                (String::from("EUR"), Decimal::from_str("0.5").unwrap()),
+                (String::from("USD"), Decimal::ONE),
+            ]),
+        };
+        let mut crypto_objects = vec![
+            create_crypto_object("bitcoin-copycat", "btc", "1", Some(1)),
+            create_crypto_object("bitcoin", "btc", "50000", Some(2)),
+            create_crypto_object("euro-token", "eur", "2", Some(10)),
+        ];
+
+        let mut report = create_fetch_report("crypto", "", 200);
+        let market_currencies =
+            resolve_market_currencies(&fiat_response, &crypto_objects, &mut report);
+        let ids: Vec<String> = market_currencies
+            .iter()
+            .map(|mc| mc.currency.id.clone())
+            .collect();
+        assert_eq!(
+            ids,
+            vec!["BTC", "BTC:bitcoin-copycat", "EUR", "EUR:euro-token", "USD"]
+        );
+
+        let btc = &market_currencies[0];
+        assert_eq!(btc.currency.provider_id, Some(String::from("bitcoin")));
+        assert_eq!(btc.currency.ambiguous_with, vec!["BTC:bitcoin-copycat"]);
+        assert_eq!(btc.usd_value, Decimal::from_str("0.00002").unwrap());
+
+        let eur = &market_currencies[2];
+        assert_eq!(eur.currency.kind, CurrencyKind::Fiat);
+        assert_eq!(eur.currency.ambiguous_with, vec!["EUR:euro-token"]);
+
+        crypto_objects.reverse();
+        let reversed_ids: Vec<String> =
+            resolve_market_currencies(&fiat_response, &crypto_objects, &mut report)
+                .iter()
+                .map(|mc| mc.currency.id.clone())
+                .collect();
+        assert_eq!(reversed_ids, ids);
+    }
+
+    #[test]
+    fn test_parse_crypto_objects() {
+        /*
+            Unit test expectations:
+
+            - Valid records are kept even when other records of the feed are broken.
+            - Every broken record is reported with the reason it was skipped.
+            - A feed that is not JSON at all is an error instead of a panic.
+        */
+        let text = r#"[
+            {"id": "bitcoin", "symbol": "btc", "name": "Bitcoin", "current_price": 50000},
+            {"id": "no-price", "symbol": "nop"},
+            {"id": "null-price", "symbol": "nul", "current_price": null},
+            {"id": "zero-price", "symbol": "zro", "current_price": 0},
+            {"id": "text-price", "symbol": "txt", "current_price": "NaN"},
+            {"id": "no-symbol", "current_price": 1},
+            {"id": "string-price", "symbol": "str", "current_price": "2.5"}
+        ]"#;
+        let mut report = create_fetch_report("crypto", "", 200);
+        let crypto_objects = parse_crypto_objects(text, &mut report).unwrap();
+
+        let symbols: Vec<&str> = crypto_objects.iter().map(|c| c.symbol.as_str()).collect();
+        assert_eq!(symbols, vec!["btc", "str"]);
+        assert_eq!(report.accepted_records, 2);
+        let reasons: Vec<(&str, &str)> = report
+            .skipped_records
+            .iter()
+            .map(|r| (r.key.as_str(), r.reason.as_str()))
+            .collect();
+        assert_eq!(
+            reasons,
+            vec![
+                ("NOP", "Missing price"),
+                ("NUL", "Missing price"),
+                ("ZRO", "Zero price"),
+                ("TXT", "Non-numeric price"),
+                ("#5", "Missing symbol"),
+            ]
+        );
+
+        assert!(parse_crypto_objects("<html>rate limited</html>", &mut report).is_err());
+    }
+
+    #[test]
+    fn test_parse_fiat_response() {
+        /*
+            Unit test expectations:
+
+            - Rates that are not positive numbers are skipped and reported.
+            - A response without rates is an error.
+        */
+        let text = r#"{"rates": {"EUR": 0.9, "BRL": "5.1", "XXX": 0, "YYY": -1, "ZZZ": {}}}"#;
+        let mut report = create_fetch_report("fiat", "", 200);
+        let fiat_response = parse_fiat_response(text, &mut report).unwrap();
+
+        assert_eq!(fiat_response.rates.len(), 2);
+        assert_eq!(
+            fiat_response.rates.get("EUR"),
+            Some(&Decimal::from_str("0.9").unwrap())
+        );
+        assert_eq!(report.accepted_records, 2);
+        assert_eq!(report.skipped_records.len(), 3);
+
+        assert!(parse_fiat_response(r#"{"base": "USD"}"#, &mut report).is_err());
+    }
+
+    #[test]
+    fn test_is_cache_fresh() {
+        /*
+            Unit test expectations:
+
+            - The cached market is used until the refresh interval has passed.
+        */
+        let fetched_at = Utc::now();
+        assert!(is_cache_fresh(
+            &fetched_at,
+            &(fetched_at + Duration::seconds(299)),
+            300
+        ));
+        assert!(!is_cache_fresh(
+            &fetched_at,
+            &(fetched_at + Duration::seconds(300)),
+            300
+        ));
+    }
 }
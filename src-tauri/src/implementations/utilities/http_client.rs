@@ -0,0 +1,535 @@
+use std::{
+    collections::HashMap,
+    fs::read_to_string,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use rand::Rng;
+use reqwest::{
+    header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+    Certificate, Client, ClientBuilder, NoProxy, Proxy, StatusCode,
+};
+
+use crate::{entities::network_settings::NetworkSettings, Error};
+
+/*
+    NOTE: versions are global rather than per client, so a response cached by a
+    client built with previous network settings is never mistaken for a new one
+*/
+static NEXT_VERSION: AtomicU64 = AtomicU64::new(1);
+
+#[derive(Clone, Debug)]
+pub struct HttpClientOptions {
+    pub connect_timeout: Duration,
+    pub request_timeout: Duration,
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for HttpClientOptions {
+    fn default() -> Self {
+        return HttpClientOptions {
+            connect_timeout: Duration::from_secs(10),
+            request_timeout: Duration::from_secs(30),
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(8),
+        };
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+    pub version: u64,
+    pub is_not_modified: bool,
+}
+
+#[derive(Clone, Debug)]
+struct CachedResponse {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub response: HttpResponse,
+}
+
+/*
+    Shared client for the rate feeds. Cloning it is cheap and every clone shares the
+    same connection pool and response cache, so it should be created once and handed
+    to every coin market instance.
+
+    Every request goes through the proxy and trusts the extra CA certificates of
+    the network settings the client was built with.
+
+    Responses carrying an `ETag` or a `Last-Modified` header are cached, and the next
+    request for the same URL is made conditional. When the server answers `304 Not
+    Modified` the cached body is returned with the same `version`, which lets callers
+    reuse whatever they parsed from it the last time.
+*/
+#[derive(Clone)]
+pub struct HttpClient {
+    client: Client,
+    options: HttpClientOptions,
+    cache: Arc<Mutex<HashMap<String, CachedResponse>>>,
+}
+
+impl HttpClient {
+    pub fn new(
+        options: HttpClientOptions,
+        network_settings: &NetworkSettings,
+    ) -> Result<HttpClient, Error> {
+        let mut builder = Client::builder()
+            .connect_timeout(options.connect_timeout)
+            .timeout(options.request_timeout);
+        builder = apply_network_settings(builder, network_settings)?;
+        let client = builder.build().map_err(|e| Error {
+            message: e.to_string(),
+            ..Default::default()
+        })?;
+        return Ok(HttpClient {
+            client,
+            options,
+            cache: Arc::new(Mutex::new(HashMap::new())),
+        });
+    }
+
+    pub async fn get_text(&self, url: &str) -> Result<HttpResponse, Error> {
+        let mut attempt: u32 = 0;
+        loop {
+            let result = self.try_get_text(url).await;
+            match result {
+                Ok(response) => return Ok(response),
+                Err(error) => {
+                    if !error.is_retryable || attempt >= self.options.max_retries {
+                        return Err(error.error);
+                    }
+                }
+            }
+            tokio::time::sleep(get_backoff(&self.options, attempt)).await;
+            attempt += 1;
+        }
+    }
+
+    async fn try_get_text(&self, url: &str) -> Result<HttpResponse, AttemptError> {
+        let maybe_cached = self.cache.lock().unwrap().get(url).cloned();
+        let mut request = self.client.get(url);
+        if let Some(cached) = &maybe_cached {
+            if let Some(etag) = &cached.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &cached.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let response = request.send().await.map_err(|e| AttemptError {
+            is_retryable: e.is_timeout() || e.is_connect() || e.is_request(),
+            error: Error {
+                message: format!("Could not fetch '{}': {}", url, e),
+                ..Default::default()
+            },
+        })?;
+        let status = response.status();
+        if status == StatusCode::NOT_MODIFIED {
+            if let Some(cached) = maybe_cached {
+                let mut cached_response = cached.response.clone();
+                cached_response.is_not_modified = true;
+                return Ok(cached_response);
+            }
+        }
+        if !status.is_success() {
+            return Err(AttemptError {
+                is_retryable: status.is_server_error()
+                    || status == StatusCode::TOO_MANY_REQUESTS
+                    || status == StatusCode::REQUEST_TIMEOUT,
+                error: Error {
+                    message: format!("'{}' answered with the HTTP status {}!", url, status),
+                    ..Default::default()
+                },
+            });
+        }
+
+        let etag = get_header(&response, ETAG);
+        let last_modified = get_header(&response, LAST_MODIFIED);
+        let body = response.text().await.map_err(|e| AttemptError {
+            is_retryable: e.is_timeout() || e.is_body(),
+            error: Error {
+                message: format!("Could not read the response of '{}': {}", url, e),
+                ..Default::default()
+            },
+        })?;
+        let http_response = HttpResponse {
+            status: status.as_u16(),
+            body,
+            version: NEXT_VERSION.fetch_add(1, Ordering::SeqCst),
+            is_not_modified: false,
+        };
+        if etag.is_some() || last_modified.is_some() {
+            self.cache.lock().unwrap().insert(
+                url.to_string(),
+                CachedResponse {
+                    etag,
+                    last_modified,
+                    response: http_response.clone(),
+                },
+            );
+        }
+        return Ok(http_response);
+    }
+}
+
+fn apply_network_settings(
+    mut builder: ClientBuilder,
+    network_settings: &NetworkSettings,
+) -> Result<ClientBuilder, Error> {
+    if let Some(proxy_settings) = &network_settings.proxy {
+        let mut proxy = Proxy::all(&proxy_settings.url).map_err(|e| Error {
+            message: format!("Invalid proxy URL '{}': {}", proxy_settings.url, e),
+            ..Default::default()
+        })?;
+        if let Some(username) = &proxy_settings.username {
+            let password = proxy_settings.password.clone().unwrap_or_default();
+            proxy = proxy.basic_auth(username, &password);
+        }
+        if proxy_settings.no_proxy.len() > 0 {
+            proxy = proxy.no_proxy(NoProxy::from_string(&proxy_settings.no_proxy.join(",")));
+        }
+        builder = builder.proxy(proxy);
+    }
+    for path in &network_settings.extra_ca_certificates {
+        for certificate in read_certificates(path)? {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+    return Ok(builder);
+}
+
+fn read_certificates(path: &str) -> Result<Vec<Certificate>, Error> {
+    const END_MARKER: &str = "-----END CERTIFICATE-----";
+    let contents = read_to_string(path).map_err(|e| Error {
+        message: format!("Could not read the CA certificate '{}': {}", path, e),
+        ..Default::default()
+    })?;
+    let mut certificates: Vec<Certificate> = vec![];
+    for block in contents.split_inclusive(END_MARKER) {
+        if !block.contains(END_MARKER) {
+            continue;
+        }
+        let certificate = Certificate::from_pem(block.trim().as_bytes()).map_err(|e| Error {
+            message: format!("Invalid CA certificate in '{}': {}", path, e),
+            ..Default::default()
+        })?;
+        certificates.push(certificate);
+    }
+    if certificates.len() == 0 {
+        return Err(Error {
+            message: format!("No PEM certificate found in '{}'!", path),
+            ..Default::default()
+        });
+    }
+    return Ok(certificates);
+}
+
+struct AttemptError {
+    pub is_retryable: bool,
+    pub error: Error,
+}
+
+fn get_header(response: &reqwest::Response, name: reqwest::header::HeaderName) -> Option<String> {
+    return response
+        .headers()
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string());
+}
+
+/*
+    NOTE: "full jitter" backoff, the delay is picked uniformly between zero and the
+    exponential backoff so that clients retrying at the same time spread out
+*/
+fn get_backoff(options: &HttpClientOptions, attempt: u32) -> Duration {
+    let exponential_backoff = options
+        .initial_backoff
+        .saturating_mul(2_u32.saturating_pow(attempt))
+        .min(options.max_backoff);
+    let max_millis = exponential_backoff.as_millis() as u64;
+    if max_millis == 0 {
+        return Duration::ZERO;
+    }
+    return Duration::from_millis(rand::thread_rng().gen_range(0..=max_millis));
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::AtomicUsize;
+
+    use crate::entities::network_settings::ProxySettings;
+
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    struct MockResponse {
+        pub status: &'static str,
+        pub headers: Vec<&'static str>,
+        pub body: &'static str,
+        pub delay: Duration,
+    }
+
+    /*
+        Minimal HTTP/1.1 server answering the scripted responses in order, one per
+        connection, and recording the raw requests it received. Connections are
+        handled concurrently so a delayed response does not block the next one.
+    */
+    async fn start_mock_server(
+        responses: Vec<MockResponse>,
+    ) -> (String, Arc<Mutex<Vec<String>>>, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/rates.json", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let hits = Arc::new(AtomicUsize::new(0));
+        let server_requests = requests.clone();
+        let server_hits = hits.clone();
+        tokio::spawn(async move {
+            for response in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let connection_requests = server_requests.clone();
+                let connection_hits = server_hits.clone();
+                tokio::spawn(async move {
+                    let mut buffer = vec![0; 4096];
+                    let read = socket.read(&mut buffer).await.unwrap();
+                    connection_requests
+                        .lock()
+                        .unwrap()
+                        .push(String::from_utf8_lossy(&buffer[..read]).to_lowercase());
+                    connection_hits.fetch_add(1, Ordering::SeqCst);
+                    tokio::time::sleep(response.delay).await;
+                    let mut raw_response = format!(
+                        "HTTP/1.1 {}\r\ncontent-length: {}\r\nconnection: close\r\n",
+                        response.status,
+                        response.body.len()
+                    );
+                    for header in &response.headers {
+                        raw_response.push_str(&format!("{}\r\n", header));
+                    }
+                    raw_response.push_str(&format!("\r\n{}", response.body));
+                    let _ = socket.write_all(raw_response.as_bytes()).await;
+                    let _ = socket.shutdown().await;
+                });
+            }
+        });
+        return (url, requests, hits);
+    }
+
+    fn create_test_options() -> HttpClientOptions {
+        return HttpClientOptions {
+            connect_timeout: Duration::from_secs(1),
+            request_timeout: Duration::from_millis(300),
+            max_retries: 2,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(5),
+        };
+    }
+
+    #[tokio::test]
+    async fn test_conditional_request() {
+        /*
+            Unit test expectations:
+
+            - The second request for the same URL sends the cached validators.
+            - A `304 Not Modified` answer returns the cached body with the same version.
+        */
+        let (url, requests, _) = start_mock_server(vec![
+            MockResponse {
+                status: "200 OK",
+                headers: vec![
+                    "etag: \"v1\"",
+                    "last-modified: Mon, 01 Jan 2024 00:00:00 GMT",
+                ],
+                body: "{\"rates\":{}}",
+                delay: Duration::ZERO,
+            },
+            MockResponse {
+                status: "304 Not Modified",
+                headers: vec![],
+                body: "",
+                delay: Duration::ZERO,
+            },
+        ])
+        .await;
+        let client = HttpClient::new(create_test_options(), &NetworkSettings::default()).unwrap();
+
+        let first_response = client.get_text(&url).await.unwrap();
+        assert_eq!(first_response.body, "{\"rates\":{}}");
+        assert!(!first_response.is_not_modified);
+
+        let second_response = client.clone().get_text(&url).await.unwrap();
+        assert_eq!(second_response.body, "{\"rates\":{}}");
+        assert_eq!(second_response.version, first_response.version);
+        assert!(second_response.is_not_modified);
+
+        let second_request = requests.lock().unwrap()[1].clone();
+        assert!(second_request.contains("if-none-match: \"v1\""));
+        assert!(second_request.contains("if-modified-since: mon, 01 jan 2024 00:00:00 gmt"));
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_server_error() {
+        /*
+            Unit test expectations:
+
+            - Server errors are retried until a successful answer arrives.
+            - Client errors are not retried.
+        */
+        let (url, _, hits) = start_mock_server(vec![
+            MockResponse {
+                status: "503 Service Unavailable",
+                headers: vec![],
+                body: "",
+                delay: Duration::ZERO,
+            },
+            MockResponse {
+                status: "200 OK",
+                headers: vec![],
+                body: "[]",
+                delay: Duration::ZERO,
+            },
+        ])
+        .await;
+        let client = HttpClient::new(create_test_options(), &NetworkSettings::default()).unwrap();
+        let response = client.get_text(&url).await.unwrap();
+        assert_eq!(response.body, "[]");
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+
+        let (url, _, hits) = start_mock_server(vec![MockResponse {
+            status: "404 Not Found",
+            headers: vec![],
+            body: "",
+            delay: Duration::ZERO,
+        }])
+        .await;
+        assert!(client.get_text(&url).await.is_err());
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_timeout() {
+        /*
+            Unit test expectations:
+
+            - A server slower than the request timeout fails the attempt, and the
+              request succeeds once a retry gets a timely answer.
+        */
+        let (url, _, hits) = start_mock_server(vec![
+            MockResponse {
+                status: "200 OK",
+                headers: vec![],
+                body: "slow",
+                delay: Duration::from_secs(2),
+            },
+            MockResponse {
+                status: "200 OK",
+                headers: vec![],
+                body: "fast",
+                delay: Duration::ZERO,
+            },
+        ])
+        .await;
+        let client = HttpClient::new(create_test_options(), &NetworkSettings::default()).unwrap();
+        let response = client.get_text(&url).await.unwrap();
+        assert_eq!(response.body, "fast");
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_proxy() {
+        /*
+            Unit test expectations:
+
+            - Requests are sent to the proxy with the absolute URL and the proxy credentials.
+            - Hosts in the no-proxy list are reached directly.
+        */
+        let (proxy_url, requests, _) = start_mock_server(vec![MockResponse {
+            status: "200 OK",
+            headers: vec![],
+            body: "proxied",
+            delay: Duration::ZERO,
+        }])
+        .await;
+        let network_settings = NetworkSettings {
+            proxy: Some(ProxySettings {
+                url: proxy_url.replace("/rates.json", ""),
+                username: Some(String::from("user")),
+                password: Some(String::from("pass")),
+                no_proxy: vec![String::from("127.0.0.1")],
+            }),
+            extra_ca_certificates: vec![],
+        };
+        let client = HttpClient::new(create_test_options(), &network_settings).unwrap();
+        let response = client
+            .get_text("http://rates.example/fiat.json")
+            .await
+            .unwrap();
+        assert_eq!(response.body, "proxied");
+        let proxied_request = requests.lock().unwrap()[0].clone();
+        assert!(proxied_request.starts_with("get http://rates.example/fiat.json http/1.1"));
+        assert!(proxied_request.contains("proxy-authorization: basic dxnlcjpwyxnz"));
+
+        let (url, requests, _) = start_mock_server(vec![MockResponse {
+            status: "200 OK",
+            headers: vec![],
+            body: "direct",
+            delay: Duration::ZERO,
+        }])
+        .await;
+        let response = client.get_text(&url).await.unwrap();
+        assert_eq!(response.body, "direct");
+        assert!(requests.lock().unwrap()[0].starts_with("get /rates.json http/1.1"));
+    }
+
+    #[test]
+    fn test_invalid_network_settings() {
+        /*
+            Unit test expectations:
+
+            - Unsupported proxy schemes and unreadable or empty CA files are reported as errors.
+        */
+        let network_settings = NetworkSettings {
+            proxy: Some(ProxySettings {
+                url: String::from("ftp://proxy.example:21"),
+                username: None,
+                password: None,
+                no_proxy: vec![],
+            }),
+            extra_ca_certificates: vec![],
+        };
+        assert!(HttpClient::new(create_test_options(), &network_settings).is_err());
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let empty_path = temp_dir.path().join("empty.pem");
+        std::fs::write(&empty_path, "not a certificate").unwrap();
+        for path in [
+            empty_path.to_str().unwrap().to_string(),
+            temp_dir
+                .path()
+                .join("missing.pem")
+                .to_str()
+                .unwrap()
+                .to_string(),
+        ] {
+            let network_settings = NetworkSettings {
+                proxy: None,
+                extra_ca_certificates: vec![path],
+            };
+            assert!(HttpClient::new(create_test_options(), &network_settings).is_err());
+        }
+    }
+}
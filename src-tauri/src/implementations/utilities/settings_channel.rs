@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use tokio::sync::watch::{self, Receiver, Sender};
+
+use crate::{entities::settings::Settings, utilities::settings_notifier::SettingsNotifier};
+
+/*
+    Broadcasts the current settings to every subscriber. Cloning the channel is
+    cheap and every clone shares the same underlying sender.
+*/
+#[derive(Clone)]
+pub struct SettingsChannel {
+    sender: Arc<Sender<Settings>>,
+}
+
+impl SettingsChannel {
+    pub fn new(settings: Settings) -> SettingsChannel {
+        let (sender, _) = watch::channel(settings);
+        return SettingsChannel {
+            sender: Arc::new(sender),
+        };
+    }
+
+    pub fn get_settings(&self) -> Settings {
+        return self.sender.borrow().clone();
+    }
+
+    pub fn subscribe(&self) -> Receiver<Settings> {
+        return self.sender.subscribe();
+    }
+}
+
+impl SettingsNotifier for SettingsChannel {
+    fn notify(&mut self, settings: &Settings) {
+        self.sender.send_if_modified(|current_settings| {
+            if current_settings == settings {
+                return false;
+            }
+            *current_settings = settings.clone();
+            return true;
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::utilities::default_settings::create_default_settings;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_notify() {
+        /*
+            Unit test expectations:
+
+            - Subscribers are woken up with the new settings.
+            - Notifying unchanged settings does not wake subscribers up.
+        */
+        let mut channel = SettingsChannel::new(create_default_settings());
+        let mut receiver = channel.subscribe();
+
+        let mut settings = create_default_settings();
+        settings.reference_currency = String::from("EUR");
+        channel.notify(&settings);
+        assert!(receiver.has_changed().unwrap());
+        assert_eq!(receiver.borrow_and_update().reference_currency, "EUR");
+        assert_eq!(channel.get_settings(), settings);
+
+        channel.notify(&settings);
+        assert!(!receiver.has_changed().unwrap());
+    }
+}
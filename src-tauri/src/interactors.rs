@@ -1,14 +1,41 @@
+pub mod add_transaction;
+pub mod adjust_asset_quantities;
+pub mod convert_amount;
+pub mod delete_account;
 pub mod delete_asset;
+pub mod delete_assets;
 pub mod delete_pair_group;
 pub mod delete_tag;
+pub mod delete_transaction;
+pub mod delete_watchlist;
 pub mod delete_watchlist_pair;
+pub mod export_tax_lots;
 pub mod interactor;
+pub mod merge_assets;
+pub mod move_assets;
+pub mod reorder_pair_group_pairs;
+pub mod reorder_pair_groups;
+pub mod reorder_watchlist_pairs;
+pub mod reorder_watchlists;
+pub mod retag_assets;
+pub mod save_account;
 pub mod save_pair_group;
 pub mod save_tag;
+pub mod save_watchlist;
 pub mod store_portfolios;
 pub mod store_watchlist_coins;
+pub mod suggest_rebalance;
 pub mod update_pair_group;
 pub mod update_portfolio;
+pub mod update_settings;
+pub mod update_tag;
+pub mod update_tag_targets;
+pub mod update_transaction;
+pub mod update_watchlist;
+pub mod view_currencies;
 pub mod view_pair_groups;
+pub mod view_portfolio_history;
 pub mod view_portfolios;
+pub mod view_settings;
+pub mod view_transactions;
 pub mod view_watchlist;
@@ -0,0 +1,123 @@
+use chrono::Utc;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    entities::{asset::Asset, transaction::Transaction},
+    utilities::{
+        ledger::{
+            create_balanced_assets, create_missing_opening_transactions, parse_transaction_kind,
+            validate_transaction,
+        },
+        rounding_policy::round_amount,
+    },
+    Error,
+};
+
+use super::interactor::Interactor;
+
+pub trait AddTransactionDataAccess {
+    async fn find_asset(&mut self, id: &str) -> Result<Option<Asset>, Error>;
+    async fn update_asset(&mut self, asset: &Asset) -> Result<(), Error>;
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error>;
+    async fn save_transaction(&mut self, transaction: &Transaction) -> Result<(), Error>;
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RequestTransaction {
+    pub asset_id: String,
+    pub kind: String,
+    pub quantity: Decimal,
+    pub price: Option<Decimal>,
+    pub price_currency: Option<String>,
+    pub counterpart_asset_id: Option<String>,
+    pub executed_at: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AddTransactionRequest {
+    pub transaction: RequestTransaction,
+}
+
+pub struct AddTransaction<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<AddTransactionRequest, ()> for AddTransaction<DA>
+where
+    DA: AddTransactionDataAccess,
+{
+    async fn perform(&mut self, request: AddTransactionRequest) -> Result<(), Error> {
+        let request_transaction = request.transaction;
+        let maybe_asset = self
+            .data_access
+            .find_asset(&request_transaction.asset_id)
+            .await?;
+        if maybe_asset.is_none() {
+            return Err(Error {
+                message: String::from("Asset does not exist!"),
+                ..Default::default()
+            });
+        }
+        let asset = maybe_asset.unwrap();
+        let mut affected_assets = vec![asset.clone()];
+        if let Some(counterpart_asset_id) = &request_transaction.counterpart_asset_id {
+            let maybe_counterpart_asset = self.data_access.find_asset(counterpart_asset_id).await?;
+            if maybe_counterpart_asset.is_none() {
+                return Err(Error {
+                    message: String::from("Destination asset does not exist!"),
+                    ..Default::default()
+                });
+            }
+            let counterpart_asset = maybe_counterpart_asset.unwrap();
+            if counterpart_asset.coin != asset.coin {
+                return Err(Error {
+                    message: String::from("Transfers must be between assets of the same coin!"),
+                    ..Default::default()
+                });
+            }
+            affected_assets.push(counterpart_asset);
+        }
+
+        let now = Utc::now().to_rfc3339();
+        let transaction = Transaction {
+            id: Uuid::new_v4().to_string(),
+            asset_id: asset.id.clone(),
+            kind: parse_transaction_kind(&request_transaction.kind)?,
+            quantity: round_amount(request_transaction.quantity, &asset.coin),
+            price: request_transaction.price,
+            price_currency: request_transaction.price_currency.clone(),
+            counterpart_asset_id: request_transaction.counterpart_asset_id.clone(),
+            executed_at: request_transaction.executed_at.unwrap_or(now.clone()),
+            notes: request_transaction.notes.unwrap_or_default(),
+            created_at: now.clone(),
+            updated_at: now.clone(),
+        };
+        validate_transaction(&transaction)?;
+
+        let mut transactions = self.data_access.fetch_transactions().await?;
+        let opening_transactions =
+            create_missing_opening_transactions(&affected_assets, &transactions);
+        transactions.extend(opening_transactions.clone());
+        transactions.push(transaction.clone());
+        let balanced_assets = create_balanced_assets(&affected_assets, &transactions)?;
+
+        for opening_transaction in &opening_transactions {
+            self.data_access
+                .save_transaction(opening_transaction)
+                .await?;
+        }
+        self.data_access.save_transaction(&transaction).await?;
+        for balanced_asset in &balanced_assets {
+            self.data_access.update_asset(balanced_asset).await?;
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
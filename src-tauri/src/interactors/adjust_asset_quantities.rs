@@ -0,0 +1,111 @@
+use chrono::Utc;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    entities::{asset::Asset, transaction::Transaction},
+    utilities::{
+        ledger::{create_adjustment_transaction, create_missing_opening_transactions, get_balance},
+        rounding_policy::round_amount,
+    },
+    Error,
+};
+
+use super::interactor::Interactor;
+
+pub trait AdjustAssetQuantitiesDataAccess {
+    async fn fetch_assets(&mut self) -> Result<Vec<Asset>, Error>;
+    async fn update_asset(&mut self, asset: &Asset) -> Result<(), Error>;
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error>;
+    async fn save_transaction(&mut self, transaction: &Transaction) -> Result<(), Error>;
+}
+
+// NOTE: every quantity is multiplied by `factor`, e.g. `0.5` halves them and `2` doubles them
+#[derive(Clone, Debug, Deserialize)]
+pub struct AdjustAssetQuantitiesRequest {
+    pub asset_ids: Vec<String>,
+    pub factor: Decimal,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct AdjustAssetQuantitiesResponse {
+    pub asset_count: usize,
+    pub transaction_count: usize,
+}
+
+/*
+    NOTE: like editing a single quantity, each change is recorded as a deposit or
+    a withdrawal, and all the ledgers are checked before anything is saved
+*/
+pub struct AdjustAssetQuantities<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<AdjustAssetQuantitiesRequest, AdjustAssetQuantitiesResponse>
+    for AdjustAssetQuantities<DA>
+where
+    DA: AdjustAssetQuantitiesDataAccess,
+{
+    async fn perform(
+        &mut self,
+        request: AdjustAssetQuantitiesRequest,
+    ) -> Result<AdjustAssetQuantitiesResponse, Error> {
+        if request.factor < Decimal::ZERO {
+            return Err(Error {
+                message: String::from("The adjustment factor must not be negative!"),
+                ..Default::default()
+            });
+        }
+        let all_assets = self.data_access.fetch_assets().await?;
+        let mut assets: Vec<Asset> = vec![];
+        for asset_id in &request.asset_ids {
+            let maybe_asset = all_assets.iter().find(|a| &a.id == asset_id);
+            if maybe_asset.is_none() {
+                return Err(Error {
+                    message: String::from("One of the assets was not found!"),
+                    ..Default::default()
+                });
+            }
+            let asset = maybe_asset.unwrap();
+            if !assets.iter().any(|a| a.id == asset.id) {
+                assets.push(asset.clone());
+            }
+        }
+
+        assets.retain(|a| round_amount(a.quantity * request.factor, &a.coin) != a.quantity);
+        let mut transactions = self.data_access.fetch_transactions().await?;
+        let mut new_transactions = create_missing_opening_transactions(&assets, &transactions);
+        transactions.extend(new_transactions.clone());
+        let mut updated_assets: Vec<Asset> = vec![];
+        for asset in &assets {
+            let quantity = round_amount(asset.quantity * request.factor, &asset.coin);
+            let adjustment = create_adjustment_transaction(
+                &asset.id,
+                quantity - asset.quantity,
+                "Bulk adjustment",
+            );
+            transactions.push(adjustment.clone());
+            new_transactions.push(adjustment);
+            let mut updated_asset = asset.clone();
+            updated_asset.quantity = get_balance(asset, &transactions)?;
+            updated_asset.updated_at = Utc::now().to_rfc3339();
+            updated_assets.push(updated_asset);
+        }
+
+        for transaction in &new_transactions {
+            self.data_access.save_transaction(transaction).await?;
+        }
+        for updated_asset in &updated_assets {
+            self.data_access.update_asset(updated_asset).await?;
+        }
+        return Ok(AdjustAssetQuantitiesResponse {
+            asset_count: updated_assets.len(),
+            transaction_count: new_transactions.len(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
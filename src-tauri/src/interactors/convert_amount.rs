@@ -0,0 +1,173 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    utilities::{
+        coin_market::CoinMarket,
+        currency_converter::{CurrencyConverter, DEFAULT_PIVOT},
+    },
+    Error,
+};
+
+use super::interactor::Interactor;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ConvertAmountRequest {
+    pub amount: Decimal,
+    pub from: String,
+    pub to: String,
+    pub pivot: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ConvertAmountResponse {
+    #[serde(with = "rust_decimal::serde::float")]
+    pub amount: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub converted_amount: Decimal,
+    pub from: String,
+    pub to: String,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub rate: Decimal,
+    pub path: Vec<String>,
+    pub rate_updated_at: String,
+}
+
+pub struct ConvertAmount<CM> {
+    pub coin_market: CM,
+}
+
+impl<CM> Interactor<ConvertAmountRequest, ConvertAmountResponse> for ConvertAmount<CM>
+where
+    CM: CoinMarket,
+{
+    async fn perform(
+        &mut self,
+        request: ConvertAmountRequest,
+    ) -> Result<ConvertAmountResponse, Error> {
+        validate_request(&request)?;
+        let usd_pairs = self.coin_market.fetch_usd_pairs().await?;
+        let pivot = request.pivot.clone().unwrap_or(String::from(DEFAULT_PIVOT));
+        let converter = CurrencyConverter::new(&usd_pairs, &pivot)?;
+        let conversion = converter.convert(request.amount, &request.from, &request.to)?;
+        return Ok(ConvertAmountResponse {
+            amount: conversion.amount,
+            converted_amount: conversion.converted_amount,
+            from: conversion.rate.from,
+            to: conversion.rate.to,
+            rate: conversion.rate.value,
+            path: conversion.rate.path,
+            rate_updated_at: conversion.rate.updated_at,
+        });
+    }
+}
+
+fn validate_request(request: &ConvertAmountRequest) -> Result<(), Error> {
+    if request.amount < Decimal::ZERO {
+        return Err(Error {
+            message: String::from("The amount to convert must not be negative!"),
+            ..Default::default()
+        });
+    }
+    if request.from.is_empty() || request.to.is_empty() {
+        return Err(Error {
+            message: String::from("Cannot convert without both source and target currencies!"),
+            ..Default::default()
+        });
+    }
+    return Ok(());
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use crate::{
+        entities::pair::Pair,
+        utilities::coin_market::{CoinMarket, MarketFetch},
+    };
+
+    use super::*;
+
+    struct FakeCoinMarket {
+        pairs: Vec<Pair>,
+    }
+
+    impl CoinMarket for FakeCoinMarket {
+        async fn fetch_market(&mut self) -> Result<MarketFetch, Error> {
+            return Ok(MarketFetch {
+                pairs: self.pairs.clone(),
+                currencies: vec![],
+                reports: vec![],
+            });
+        }
+    }
+
+    fn create_pair(comparison: &str, value: &str) -> Pair {
+        return Pair {
+            id: comparison.to_lowercase(),
+            value: Decimal::from_str(value).unwrap(),
+            base: String::from("USD"),
+            comparison: comparison.to_string(),
+            position: 0,
+            created_at: String::from("2024-01-01T00:00:00+00:00"),
+            updated_at: String::from("2024-01-01T00:00:00+00:00"),
+        };
+    }
+
+    fn create_request(amount: Decimal, from: &str, to: &str) -> ConvertAmountRequest {
+        return ConvertAmountRequest {
+            amount,
+            from: from.to_string(),
+            to: to.to_string(),
+            pivot: None,
+        };
+    }
+
+    #[tokio::test]
+    async fn test_convert_amount() {
+        /*
+            Unit test expectations:
+
+            - Currencies without a direct pair are converted through the pivot.
+            - Unknown currencies are rejected.
+            - Zero converts to zero, negative amounts are rejected.
+            - Amounts overflowing the conversion are rejected instead of panicking.
+        */
+        let mut interactor = ConvertAmount {
+            coin_market: FakeCoinMarket {
+                pairs: vec![create_pair("EUR", "0.5"), create_pair("BTC", "0.00002")],
+            },
+        };
+        let response = interactor
+            .perform(create_request(Decimal::from(1000), "EUR", "BTC"))
+            .await
+            .unwrap();
+        assert_eq!(response.rate, Decimal::from_str("0.00004").unwrap());
+        assert_eq!(
+            response.converted_amount,
+            Decimal::from_str("0.04").unwrap()
+        );
+        assert_eq!(response.path, vec!["EUR", "USD", "BTC"]);
+
+        assert!(interactor
+            .perform(create_request(Decimal::ONE, "EUR", "XYZ"))
+            .await
+            .is_err());
+
+        let response = interactor
+            .perform(create_request(Decimal::ZERO, "EUR", "BTC"))
+            .await
+            .unwrap();
+        assert_eq!(response.converted_amount, Decimal::ZERO);
+        assert!(interactor
+            .perform(create_request(-Decimal::ONE, "EUR", "BTC"))
+            .await
+            .is_err());
+
+        assert!(interactor
+            .perform(create_request(Decimal::MAX, "BTC", "USD"))
+            .await
+            .is_err());
+    }
+}
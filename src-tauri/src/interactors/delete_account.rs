@@ -0,0 +1,58 @@
+use chrono::Utc;
+use serde::Deserialize;
+
+use crate::{entities::asset::Asset, Error};
+
+use super::interactor::Interactor;
+
+pub trait DeleteAccountDataAccess {
+    async fn fetch_assets(&mut self) -> Result<Vec<Asset>, Error>;
+    async fn update_asset(&mut self, asset: &Asset) -> Result<(), Error>;
+    async fn delete_account(&mut self, id: &str) -> Result<(), Error>;
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RequestAccount {
+    pub id: String,
+}
+
+impl PartialEq for RequestAccount {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id;
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeleteAccountRequest {
+    pub account: RequestAccount,
+}
+
+// NOTE: the assets of the account are kept, they just no longer belong to any account
+pub struct DeleteAccount<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<DeleteAccountRequest, ()> for DeleteAccount<DA>
+where
+    DA: DeleteAccountDataAccess,
+{
+    async fn perform(&mut self, request: DeleteAccountRequest) -> Result<(), Error> {
+        self.data_access.delete_account(&request.account.id).await?;
+        let assets = self.data_access.fetch_assets().await?;
+        for asset in assets {
+            if asset.account_id.as_ref() != Some(&request.account.id) {
+                continue;
+            }
+            let mut updated_asset = asset.clone();
+            updated_asset.account_id = None;
+            updated_asset.updated_at = Utc::now().to_rfc3339();
+            self.data_access.update_asset(&updated_asset).await?;
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
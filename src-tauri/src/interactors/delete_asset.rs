@@ -1,6 +1,10 @@
 use serde::Deserialize;
 
-use crate::{entities::tag::Tag, Error};
+use crate::{
+    entities::{tag::Tag, transaction::Transaction},
+    utilities::ledger::create_ledger_cleanup,
+    Error,
+};
 
 use super::interactor::Interactor;
 
@@ -8,6 +12,9 @@ pub trait DeleteAssetDataAccess {
     async fn retrieve_tags_by_asset(&mut self, id: &str) -> Result<Vec<Tag>, Error>;
     async fn update_tag(&mut self, tag: &Tag) -> Result<(), Error>;
     async fn delete_asset(&mut self, id: &str) -> Result<(), Error>;
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error>;
+    async fn update_transaction(&mut self, transaction: &Transaction) -> Result<(), Error>;
+    async fn delete_transaction(&mut self, id: &str) -> Result<(), Error>;
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -46,6 +53,14 @@ where
                 self.data_access.update_tag(tag).await?;
             }
         }
+        let transactions = self.data_access.fetch_transactions().await?;
+        let ledger_cleanup = create_ledger_cleanup(&transactions, &vec![request.asset.id.clone()]);
+        for transaction_id in &ledger_cleanup.deleted_transaction_ids {
+            self.data_access.delete_transaction(transaction_id).await?;
+        }
+        for transaction in &ledger_cleanup.updated_transactions {
+            self.data_access.update_transaction(transaction).await?;
+        }
         self.data_access.delete_asset(&request.asset.id).await?;
         return Ok(());
     }
@@ -0,0 +1,99 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    entities::{asset::Asset, tag::Tag, transaction::Transaction},
+    utilities::ledger::create_ledger_cleanup,
+    Error,
+};
+
+use super::interactor::Interactor;
+
+pub trait DeleteAssetsDataAccess {
+    async fn fetch_tags(&mut self) -> Result<Vec<Tag>, Error>;
+    async fn fetch_assets(&mut self) -> Result<Vec<Asset>, Error>;
+    async fn update_tag(&mut self, tag: &Tag) -> Result<(), Error>;
+    async fn delete_asset(&mut self, id: &str) -> Result<(), Error>;
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error>;
+    async fn update_transaction(&mut self, transaction: &Transaction) -> Result<(), Error>;
+    async fn delete_transaction(&mut self, id: &str) -> Result<(), Error>;
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeleteAssetsRequest {
+    pub asset_ids: Vec<String>,
+}
+
+// NOTE: `transaction_count` is the number of transactions deleted or rewritten
+#[derive(Clone, Debug, Serialize)]
+pub struct DeleteAssetsResponse {
+    pub deleted_asset_count: usize,
+    pub updated_tag_count: usize,
+    pub transaction_count: usize,
+}
+
+// NOTE: every asset is checked before anything is deleted, so an unknown id deletes nothing
+pub struct DeleteAssets<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<DeleteAssetsRequest, DeleteAssetsResponse> for DeleteAssets<DA>
+where
+    DA: DeleteAssetsDataAccess,
+{
+    async fn perform(
+        &mut self,
+        request: DeleteAssetsRequest,
+    ) -> Result<DeleteAssetsResponse, Error> {
+        let assets = self.data_access.fetch_assets().await?;
+        let mut asset_ids: Vec<String> = vec![];
+        for asset_id in &request.asset_ids {
+            if !assets.iter().any(|a| &a.id == asset_id) {
+                return Err(Error {
+                    message: String::from("One of the assets was not found!"),
+                    ..Default::default()
+                });
+            }
+            if !asset_ids.contains(asset_id) {
+                asset_ids.push(asset_id.clone());
+            }
+        }
+
+        let tags = self.data_access.fetch_tags().await?;
+        let mut updated_tags: Vec<Tag> = vec![];
+        for tag in &tags {
+            let mut updated_tag = tag.clone();
+            updated_tag.assets.retain(|a| !asset_ids.contains(&a.id));
+            if updated_tag.assets.len() != tag.assets.len() {
+                updated_tag.updated_at = Utc::now().to_rfc3339();
+                updated_tags.push(updated_tag);
+            }
+        }
+        let transactions = self.data_access.fetch_transactions().await?;
+        let ledger_cleanup = create_ledger_cleanup(&transactions, &asset_ids);
+
+        for updated_tag in &updated_tags {
+            self.data_access.update_tag(updated_tag).await?;
+        }
+        for transaction_id in &ledger_cleanup.deleted_transaction_ids {
+            self.data_access.delete_transaction(transaction_id).await?;
+        }
+        for transaction in &ledger_cleanup.updated_transactions {
+            self.data_access.update_transaction(transaction).await?;
+        }
+        for asset_id in &asset_ids {
+            self.data_access.delete_asset(asset_id).await?;
+        }
+        return Ok(DeleteAssetsResponse {
+            deleted_asset_count: asset_ids.len(),
+            updated_tag_count: updated_tags.len(),
+            transaction_count: ledger_cleanup.deleted_transaction_ids.len()
+                + ledger_cleanup.updated_transactions.len(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
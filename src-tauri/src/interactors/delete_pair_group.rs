@@ -42,6 +42,7 @@ where
         if maybe_pair_group.is_none() {
             return Err(Error {
                 message: String::from("Pair group to delete does not exist!"),
+                ..Default::default()
             });
         }
         let pair_group = maybe_pair_group.unwrap();
@@ -1,11 +1,23 @@
-use serde::Deserialize;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 
-use crate::Error;
+use crate::{
+    entities::{tag::Tag, transaction::Transaction},
+    utilities::{ledger::create_ledger_cleanup, tag_hierarchy::validate_tag_name},
+    Error,
+};
 
 use super::interactor::Interactor;
 
 pub trait DeleteTagDataAccess {
+    async fn fetch_tags(&mut self) -> Result<Vec<Tag>, Error>;
+    async fn update_tag(&mut self, tag: &Tag) -> Result<(), Error>;
     async fn delete_tag(&mut self, id: &str) -> Result<(), Error>;
+    async fn delete_asset(&mut self, id: &str) -> Result<(), Error>;
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error>;
+    async fn update_transaction(&mut self, transaction: &Transaction) -> Result<(), Error>;
+    async fn delete_transaction(&mut self, id: &str) -> Result<(), Error>;
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -19,26 +31,271 @@ impl PartialEq for RequestTag {
     }
 }
 
+/*
+    NOTE: `mode` is either "detach", which keeps the tagged assets, or "cascade",
+    which deletes them too. A preview changes nothing and only reports what the
+    deletion would affect.
+*/
 #[derive(Clone, Debug, Deserialize)]
 pub struct DeleteTagRequest {
     pub tag: RequestTag,
+    pub mode: String,
+    pub is_preview: bool,
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseTag {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseAsset {
+    pub id: String,
+    pub coin: String,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub quantity: Decimal,
+    pub other_tag_count: usize,
+}
+
+// NOTE: `transaction_count` is the number of transactions deleted or rewritten
+#[derive(Clone, Debug, Serialize)]
+pub struct DeleteTagResponse {
+    pub mode: String,
+    pub is_preview: bool,
+    pub tag: ResponseTag,
+    pub child_tags: Vec<ResponseTag>,
+    pub assets: Vec<ResponseAsset>,
+    pub transaction_count: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum DeleteTagMode {
+    Detach,
+    Cascade,
+}
+
+// NOTE: the children of the deleted tag are moved up to its own parent
 pub struct DeleteTag<DA> {
     pub data_access: DA,
 }
 
-impl<DA> Interactor<DeleteTagRequest, ()> for DeleteTag<DA>
+impl<DA> Interactor<DeleteTagRequest, DeleteTagResponse> for DeleteTag<DA>
 where
     DA: DeleteTagDataAccess,
 {
-    async fn perform(&mut self, request: DeleteTagRequest) -> Result<(), Error> {
-        self.data_access.delete_tag(&request.tag.id).await?;
-        return Ok(());
+    async fn perform(&mut self, request: DeleteTagRequest) -> Result<DeleteTagResponse, Error> {
+        let mode = parse_delete_tag_mode(&request.mode)?;
+        let tags = self.data_access.fetch_tags().await?;
+        let maybe_tag = tags.iter().find(|t| t.id == request.tag.id);
+        if maybe_tag.is_none() {
+            return Err(Error {
+                message: String::from("Tag to delete does not exist!"),
+                ..Default::default()
+            });
+        }
+        let tag = maybe_tag.unwrap();
+        let deleted_asset_ids: Vec<String> = match mode {
+            DeleteTagMode::Detach => vec![],
+            DeleteTagMode::Cascade => tag.assets.iter().map(|a| a.id.clone()).collect(),
+        };
+        let updated_tags = create_updated_tags(&tags, tag, &deleted_asset_ids)?;
+        let transactions = self.data_access.fetch_transactions().await?;
+        let ledger_cleanup = create_ledger_cleanup(&transactions, &deleted_asset_ids);
+
+        let response = DeleteTagResponse {
+            mode: request.mode.clone(),
+            is_preview: request.is_preview,
+            tag: create_response_tag(tag),
+            child_tags: tags
+                .iter()
+                .filter(|t| t.parent_id.as_ref() == Some(&tag.id))
+                .map(create_response_tag)
+                .collect(),
+            assets: tag
+                .assets
+                .iter()
+                .map(|a| ResponseAsset {
+                    id: a.id.clone(),
+                    coin: a.coin.clone(),
+                    quantity: a.quantity,
+                    other_tag_count: tags
+                        .iter()
+                        .filter(|t| t.id != tag.id && t.assets.iter().any(|ta| ta.id == a.id))
+                        .count(),
+                })
+                .collect(),
+            transaction_count: ledger_cleanup.deleted_transaction_ids.len()
+                + ledger_cleanup.updated_transactions.len(),
+        };
+        if request.is_preview {
+            return Ok(response);
+        }
+
+        self.data_access.delete_tag(&tag.id).await?;
+        for updated_tag in &updated_tags {
+            self.data_access.update_tag(updated_tag).await?;
+        }
+        for transaction_id in &ledger_cleanup.deleted_transaction_ids {
+            self.data_access.delete_transaction(transaction_id).await?;
+        }
+        for transaction in &ledger_cleanup.updated_transactions {
+            self.data_access.update_transaction(transaction).await?;
+        }
+        for asset_id in &deleted_asset_ids {
+            self.data_access.delete_asset(asset_id).await?;
+        }
+        return Ok(response);
+    }
+}
+
+fn parse_delete_tag_mode(mode: &str) -> Result<DeleteTagMode, Error> {
+    match mode {
+        "detach" => return Ok(DeleteTagMode::Detach),
+        "cascade" => return Ok(DeleteTagMode::Cascade),
+        _ => {
+            return Err(Error {
+                message: format!("Unknown tag deletion mode '{}'!", mode),
+                ..Default::default()
+            })
+        }
+    }
+}
+
+fn create_response_tag(tag: &Tag) -> ResponseTag {
+    return ResponseTag {
+        id: tag.id.clone(),
+        name: tag.name.clone(),
+    };
+}
+
+/*
+    NOTE: the remaining tags that change, either because of their parent or their
+    deleted assets. Children moved up must not collide with their new siblings.
+*/
+fn create_updated_tags(
+    tags: &Vec<Tag>,
+    deleted_tag: &Tag,
+    deleted_asset_ids: &Vec<String>,
+) -> Result<Vec<Tag>, Error> {
+    let mut updated_tags: Vec<Tag> = vec![];
+    for tag in tags {
+        if tag.id == deleted_tag.id {
+            continue;
+        }
+        let mut updated_tag = tag.clone();
+        if updated_tag.parent_id.as_ref() == Some(&deleted_tag.id) {
+            updated_tag.parent_id = deleted_tag.parent_id.clone();
+        }
+        updated_tag
+            .assets
+            .retain(|a| !deleted_asset_ids.contains(&a.id));
+        if updated_tag != *tag {
+            updated_tag.updated_at = Utc::now().to_rfc3339();
+            updated_tags.push(updated_tag);
+        }
     }
+
+    let remaining_tags: Vec<Tag> = tags
+        .iter()
+        .filter(|t| t.id != deleted_tag.id)
+        .map(|t| {
+            return updated_tags
+                .iter()
+                .find(|ut| ut.id == t.id)
+                .unwrap_or(t)
+                .clone();
+        })
+        .collect();
+    for tag in tags {
+        if tag.parent_id.as_ref() == Some(&deleted_tag.id) {
+            validate_tag_name(
+                &remaining_tags,
+                &tag.id,
+                &tag.name,
+                &deleted_tag.parent_id,
+            )
+            .map_err(|_| Error {
+                message: format!(
+                    "The child tag '{}' cannot be moved up, since a tag with its name already exists there!",
+                    tag.name
+                ),
+                ..Default::default()
+            })?;
+        }
+    }
+    return Ok(updated_tags);
 }
 
 #[cfg(test)]
 mod test {
-    // TODO: write tests for this `impl``
+    use crate::entities::asset::Asset;
+
+    use super::*;
+
+    fn create_asset(id: &str) -> Asset {
+        return Asset {
+            id: id.to_string(),
+            coin: String::from("BTC"),
+            quantity: Decimal::ONE,
+            usd_value: Decimal::ONE,
+            account_id: None,
+            created_at: String::from("2024-01-01T00:00:00+00:00"),
+            updated_at: String::from("2024-01-01T00:00:00+00:00"),
+        };
+    }
+
+    fn create_tag(id: &str, parent_id: Option<&str>, asset_ids: Vec<&str>) -> Tag {
+        return Tag {
+            id: id.to_string(),
+            name: id.to_string(),
+            color: None,
+            description: String::new(),
+            parent_id: parent_id.map(|p| p.to_string()),
+            assets: asset_ids.into_iter().map(create_asset).collect(),
+            targets: vec![],
+            created_at: String::from("2024-01-01T00:00:00+00:00"),
+            updated_at: String::from("2024-01-01T00:00:00+00:00"),
+        };
+    }
+
+    #[test]
+    fn test_create_updated_tags() {
+        /*
+            Unit test expectations:
+
+            - Children of the deleted tag are moved up to its parent.
+            - Deleted assets are removed from every other tag.
+            - Tags left untouched are not updated.
+            - Children colliding with the names of their new siblings are rejected.
+        */
+        let tags = vec![
+            create_tag("root", None, vec![]),
+            create_tag("deleted", Some("root"), vec!["a1", "a2"]),
+            create_tag("child", Some("deleted"), vec!["a3"]),
+            create_tag("other", None, vec!["a2", "a3"]),
+            create_tag("untouched", None, vec!["a3"]),
+        ];
+        let updated_tags = create_updated_tags(&tags, &tags[1], &vec![]).unwrap();
+        assert_eq!(updated_tags.len(), 1);
+        assert_eq!(updated_tags[0].id, "child");
+        assert_eq!(updated_tags[0].parent_id, Some(String::from("root")));
+
+        let updated_tags = create_updated_tags(
+            &tags,
+            &tags[1],
+            &vec![String::from("a1"), String::from("a2")],
+        )
+        .unwrap();
+        assert_eq!(updated_tags.len(), 2);
+        assert_eq!(updated_tags[1].id, "other");
+        assert_eq!(updated_tags[1].assets.len(), 1);
+        assert_eq!(updated_tags[1].assets[0].id, "a3");
+
+        let mut tags = tags.clone();
+        tags.push(create_tag("sibling", Some("root"), vec![]));
+        tags[4].name = String::from("Sibling");
+        tags[4].parent_id = Some(String::from("deleted"));
+        assert!(create_updated_tags(&tags, &tags[1], &vec![]).is_err());
+    }
 }
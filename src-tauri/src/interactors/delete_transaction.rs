@@ -0,0 +1,72 @@
+use serde::Deserialize;
+
+use crate::{
+    entities::{asset::Asset, transaction::Transaction},
+    utilities::ledger::create_balanced_assets,
+    Error,
+};
+
+use super::interactor::Interactor;
+
+pub trait DeleteTransactionDataAccess {
+    async fn find_asset(&mut self, id: &str) -> Result<Option<Asset>, Error>;
+    async fn update_asset(&mut self, asset: &Asset) -> Result<(), Error>;
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error>;
+    async fn delete_transaction(&mut self, id: &str) -> Result<(), Error>;
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RequestTransaction {
+    pub id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeleteTransactionRequest {
+    pub transaction: RequestTransaction,
+}
+
+pub struct DeleteTransaction<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<DeleteTransactionRequest, ()> for DeleteTransaction<DA>
+where
+    DA: DeleteTransactionDataAccess,
+{
+    async fn perform(&mut self, request: DeleteTransactionRequest) -> Result<(), Error> {
+        let mut transactions = self.data_access.fetch_transactions().await?;
+        let maybe_transaction_idx = transactions
+            .iter()
+            .position(|t| t.id == request.transaction.id);
+        if maybe_transaction_idx.is_none() {
+            return Err(Error {
+                message: String::from("Transaction does not exist!"),
+                ..Default::default()
+            });
+        }
+        let transaction = transactions.remove(maybe_transaction_idx.unwrap());
+
+        let mut affected_asset_ids = vec![transaction.asset_id.clone()];
+        if let Some(counterpart_asset_id) = &transaction.counterpart_asset_id {
+            affected_asset_ids.push(counterpart_asset_id.clone());
+        }
+        let mut affected_assets: Vec<Asset> = vec![];
+        for affected_asset_id in &affected_asset_ids {
+            if let Some(affected_asset) = self.data_access.find_asset(affected_asset_id).await? {
+                affected_assets.push(affected_asset);
+            }
+        }
+        let balanced_assets = create_balanced_assets(&affected_assets, &transactions)?;
+
+        self.data_access.delete_transaction(&transaction.id).await?;
+        for balanced_asset in &balanced_assets {
+            self.data_access.update_asset(balanced_asset).await?;
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
@@ -0,0 +1,65 @@
+use serde::Deserialize;
+
+use crate::{entities::watchlist::Watchlist, Error};
+
+use super::interactor::Interactor;
+
+pub trait DeleteWatchlistDataAccess {
+    async fn find_watchlist(&mut self, id: &str) -> Result<Option<Watchlist>, Error>;
+    async fn delete_pair(&mut self, id: &str) -> Result<(), Error>;
+    async fn delete_watchlist(&mut self, id: &str) -> Result<(), Error>;
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RequestWatchlist {
+    pub id: String,
+}
+
+impl PartialEq for RequestWatchlist {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id;
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeleteWatchlistRequest {
+    pub watchlist: RequestWatchlist,
+}
+
+/*
+    NOTE: the pairs of the watchlist are deleted with it. The settings are left
+    alone when it was the default one, a missing default falls back to the first
+    watchlist.
+*/
+pub struct DeleteWatchlist<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<DeleteWatchlistRequest, ()> for DeleteWatchlist<DA>
+where
+    DA: DeleteWatchlistDataAccess,
+{
+    async fn perform(&mut self, request: DeleteWatchlistRequest) -> Result<(), Error> {
+        let maybe_watchlist = self
+            .data_access
+            .find_watchlist(&request.watchlist.id)
+            .await?;
+        if maybe_watchlist.is_none() {
+            return Err(Error {
+                message: String::from("Watchlist to delete does not exist!"),
+                ..Default::default()
+            });
+        }
+        let watchlist = maybe_watchlist.unwrap();
+        self.data_access.delete_watchlist(&watchlist.id).await?;
+        for pair in watchlist.pairs.iter().chain(watchlist.cross_pairs.iter()) {
+            self.data_access.delete_pair(&pair.id).await?;
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
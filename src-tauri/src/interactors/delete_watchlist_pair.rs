@@ -1,12 +1,14 @@
 use serde::Deserialize;
 
-use crate::{entities::watchlist::Watchlist, Error};
+use crate::{
+    entities::watchlist::Watchlist, utilities::watchlist_selection::select_watchlist, Error,
+};
 
 use super::interactor::Interactor;
 
 pub trait DeleteWatchlistPairDataAccess {
     async fn delete_pair(&mut self, id: &str) -> Result<(), Error>;
-    async fn get_watchlist(&mut self) -> Result<Watchlist, Error>;
+    async fn fetch_watchlists(&mut self) -> Result<Vec<Watchlist>, Error>;
     async fn update_watchlist(&mut self, watchlist: &Watchlist) -> Result<(), Error>;
 }
 
@@ -23,11 +25,13 @@ impl PartialEq for RequestPair {
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct DeleteWatchlistPairRequest {
+    pub watchlist_id: Option<String>,
     pub pair: RequestPair,
 }
 
 pub struct DeleteWatchlistPair<DA> {
     pub data_access: DA,
+    pub default_watchlist_id: Option<String>,
 }
 
 impl<DA> Interactor<DeleteWatchlistPairRequest, ()> for DeleteWatchlistPair<DA>
@@ -35,12 +39,33 @@ where
     DA: DeleteWatchlistPairDataAccess,
 {
     async fn perform(&mut self, request: DeleteWatchlistPairRequest) -> Result<(), Error> {
-        /* TODO:
-            - Check for non-existing pair
-            - Check if pair is really from watchlist (to avoid deleting a pair from a pair group for example)
-        */
-        let mut watchlist = self.data_access.get_watchlist().await?;
+        let watchlists = self.data_access.fetch_watchlists().await?;
+        let maybe_watchlist = select_watchlist(
+            &watchlists,
+            &request.watchlist_id,
+            &self.default_watchlist_id,
+        )?;
+        if maybe_watchlist.is_none() {
+            return Err(Error {
+                message: String::from("Watchlist not found!"),
+                ..Default::default()
+            });
+        }
+        let mut watchlist = maybe_watchlist.unwrap();
+        // NOTE: this also keeps pairs of pair groups from being deleted through a watchlist
+        let is_watched = watchlist
+            .pairs
+            .iter()
+            .chain(watchlist.cross_pairs.iter())
+            .any(|p| p.id == request.pair.id);
+        if !is_watched {
+            return Err(Error {
+                message: String::from("Pair is not part of the watchlist!"),
+                ..Default::default()
+            });
+        }
         watchlist.pairs.retain(|p| p.id != request.pair.id);
+        watchlist.cross_pairs.retain(|p| p.id != request.pair.id);
         self.data_access.delete_pair(&request.pair.id).await?;
         self.data_access.update_watchlist(&watchlist).await?;
         return Ok(());
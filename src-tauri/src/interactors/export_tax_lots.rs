@@ -0,0 +1,248 @@
+use chrono::{DateTime, FixedOffset, Months, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    entities::{
+        asset::Asset, currency::CurrencyKind, rate_snapshot::RateSnapshot,
+        settings::CostBasisMethod, transaction::Transaction,
+    },
+    utilities::{
+        coin_market::CoinMarket,
+        cost_basis::{
+            convert_unit_price, create_cost_basis_report, get_unit_price_rate_dates, Disposal,
+        },
+        currency_converter::{CurrencyConverter, DEFAULT_PIVOT},
+        ledger::create_missing_opening_transactions,
+        rate_history::RateHistory,
+        rounding_policy::round_amount,
+    },
+    Error,
+};
+
+use super::interactor::Interactor;
+
+// NOTE: holdings disposed of after more than this many months are long-term
+const LONG_TERM_HOLDING_MONTHS: u32 = 12;
+
+pub trait ExportTaxLotsDataAccess {
+    async fn fetch_assets(&mut self) -> Result<Vec<Asset>, Error>;
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error>;
+    async fn fetch_daily_rate_snapshots_on(
+        &mut self,
+        dates: &Vec<NaiveDate>,
+    ) -> Result<Vec<RateSnapshot>, Error>;
+}
+
+// NOTE: `from` and `to` are inclusive `YYYY-MM-DD` dates
+#[derive(Clone, Debug, Deserialize)]
+pub struct ExportTaxLotsRequest {
+    pub from: String,
+    pub to: String,
+    pub currency: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ExportTaxLotsResponse {
+    pub file_name: String,
+    pub csv: String,
+    pub currency: String,
+    pub disposal_count: usize,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub total_proceeds: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub total_cost_basis: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub total_gain: Decimal,
+}
+
+/*
+    NOTE: transaction prices are converted with the daily rates recorded around
+    the transaction, only falling back to the current rates when none were, in
+    which case the `is_rate_historical` column of the lot is `false`
+*/
+pub struct ExportTaxLots<DA, CM> {
+    pub data_access: DA,
+    pub coin_market: CM,
+    pub cost_basis_method: CostBasisMethod,
+}
+
+struct TaxLot {
+    coin: String,
+    disposal: Disposal,
+    is_long_term: bool,
+}
+
+impl<DA, CM> Interactor<ExportTaxLotsRequest, ExportTaxLotsResponse> for ExportTaxLots<DA, CM>
+where
+    DA: ExportTaxLotsDataAccess,
+    CM: CoinMarket,
+{
+    async fn perform(
+        &mut self,
+        request: ExportTaxLotsRequest,
+    ) -> Result<ExportTaxLotsResponse, Error> {
+        let from = parse_date(&request.from)?;
+        let to = parse_date(&request.to)?;
+        if from > to {
+            return Err(Error {
+                message: String::from("The report period must not end before it starts!"),
+                ..Default::default()
+            });
+        }
+        let currencies = self.coin_market.fetch_currencies().await?;
+        if !currencies
+            .iter()
+            .any(|c| c.code == request.currency && c.kind == CurrencyKind::Fiat)
+        {
+            return Err(Error {
+                message: format!("'{}' is not a known fiat currency!", request.currency),
+                ..Default::default()
+            });
+        }
+        let usd_pairs = self.coin_market.fetch_usd_pairs().await?;
+        let converter = CurrencyConverter::new(&usd_pairs, DEFAULT_PIVOT)?;
+
+        let assets = self.data_access.fetch_assets().await?;
+        let mut transactions = self.data_access.fetch_transactions().await?;
+        transactions.extend(create_missing_opening_transactions(&assets, &transactions));
+        let rate_history = RateHistory::new(
+            &self
+                .data_access
+                .fetch_daily_rate_snapshots_on(&get_unit_price_rate_dates(&transactions))
+                .await?,
+        );
+        let cost_basis_report =
+            create_cost_basis_report(&transactions, &self.cost_basis_method, |t| {
+                convert_unit_price(t, &rate_history, &converter, &request.currency)
+            })?;
+
+        let mut tax_lots: Vec<TaxLot> = vec![];
+        for disposal in &cost_basis_report.disposals {
+            let disposed_at = parse_date_time(&disposal.disposed_at)?;
+            if disposed_at.date_naive() < from || disposed_at.date_naive() > to {
+                continue;
+            }
+            let acquired_at = parse_date_time(&disposal.acquired_at)?;
+            let coin = assets
+                .iter()
+                .find(|a| a.id == disposal.asset_id)
+                .map(|a| a.coin.clone())
+                .unwrap_or_default();
+            tax_lots.push(TaxLot {
+                coin,
+                disposal: disposal.clone(),
+                is_long_term: acquired_at + Months::new(LONG_TERM_HOLDING_MONTHS) < disposed_at,
+            });
+        }
+
+        let total_proceeds = tax_lots
+            .iter()
+            .map(|tl| tl.disposal.proceeds)
+            .sum::<Decimal>();
+        let total_cost_basis = tax_lots
+            .iter()
+            .map(|tl| tl.disposal.cost_basis)
+            .sum::<Decimal>();
+        return Ok(ExportTaxLotsResponse {
+            file_name: format!("tax-lots_{}_{}_{}.csv", from, to, request.currency),
+            csv: create_csv(&tax_lots, &request.currency),
+            currency: request.currency.clone(),
+            disposal_count: tax_lots.len(),
+            total_proceeds: round_amount(total_proceeds, &request.currency),
+            total_cost_basis: round_amount(total_cost_basis, &request.currency),
+            total_gain: round_amount(total_proceeds - total_cost_basis, &request.currency),
+        });
+    }
+}
+
+fn parse_date(date: &str) -> Result<NaiveDate, Error> {
+    return NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| Error {
+        message: format!("'{}' is not a valid YYYY-MM-DD date!", date),
+        ..Default::default()
+    });
+}
+
+fn parse_date_time(date_time: &str) -> Result<DateTime<FixedOffset>, Error> {
+    return DateTime::parse_from_rfc3339(date_time).map_err(|e| Error {
+        message: e.to_string(),
+        ..Default::default()
+    });
+}
+
+fn create_csv(tax_lots: &Vec<TaxLot>, currency: &str) -> String {
+    let mut csv = String::from(
+        "coin,quantity,acquired_at,disposed_at,proceeds,cost_basis,gain,holding_period,is_cost_known,is_rate_historical\n",
+    );
+    for tax_lot in tax_lots {
+        let disposal = &tax_lot.disposal;
+        let proceeds = round_amount(disposal.proceeds, currency);
+        let cost_basis = round_amount(disposal.cost_basis, currency);
+        let fields = vec![
+            escape_csv_field(&tax_lot.coin),
+            disposal.quantity.normalize().to_string(),
+            escape_csv_field(&disposal.acquired_at),
+            escape_csv_field(&disposal.disposed_at),
+            proceeds.to_string(),
+            cost_basis.to_string(),
+            (proceeds - cost_basis).to_string(),
+            String::from(if tax_lot.is_long_term {
+                "long"
+            } else {
+                "short"
+            }),
+            disposal.is_cost_known.to_string(),
+            disposal.is_rate_historical.to_string(),
+        ];
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    return csv;
+}
+
+fn escape_csv_field(field: &str) -> String {
+    if field.contains(|c| c == ',' || c == '"' || c == '\n') {
+        return format!("\"{}\"", field.replace('"', "\"\""));
+    }
+    return field.to_string();
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_create_csv() {
+        /*
+            Unit test expectations:
+
+            - Each tax lot becomes one row with its gain and holding period.
+            - Lots valued at current rates are flagged.
+            - Fields containing separators are quoted.
+        */
+        let tax_lots = vec![TaxLot {
+            coin: String::from("BTC,old"),
+            disposal: Disposal {
+                transaction_id: String::from("t1"),
+                asset_id: String::from("a1"),
+                quantity: Decimal::from_str("0.50").unwrap(),
+                acquired_at: String::from("2023-01-01T00:00:00+00:00"),
+                disposed_at: String::from("2024-06-01T00:00:00+00:00"),
+                proceeds: Decimal::from_str("1000.004").unwrap(),
+                cost_basis: Decimal::from(400),
+                is_cost_known: true,
+                is_rate_historical: false,
+            },
+            is_long_term: true,
+        }];
+        let csv = create_csv(&tax_lots, "USD");
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[1],
+            "\"BTC,old\",0.5,2023-01-01T00:00:00+00:00,2024-06-01T00:00:00+00:00,1000,400,600,long,true,false"
+        );
+    }
+}
@@ -0,0 +1,255 @@
+use chrono::Utc;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    entities::{asset::Asset, tag::Tag, transaction::Transaction},
+    utilities::ledger::{create_missing_opening_transactions, get_balance, LedgerCleanup},
+    Error,
+};
+
+use super::interactor::Interactor;
+
+pub trait MergeAssetsDataAccess {
+    async fn fetch_tags(&mut self) -> Result<Vec<Tag>, Error>;
+    async fn fetch_assets(&mut self) -> Result<Vec<Asset>, Error>;
+    async fn update_tag(&mut self, tag: &Tag) -> Result<(), Error>;
+    async fn update_asset(&mut self, asset: &Asset) -> Result<(), Error>;
+    async fn delete_asset(&mut self, id: &str) -> Result<(), Error>;
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error>;
+    async fn save_transaction(&mut self, transaction: &Transaction) -> Result<(), Error>;
+    async fn update_transaction(&mut self, transaction: &Transaction) -> Result<(), Error>;
+    async fn delete_transaction(&mut self, id: &str) -> Result<(), Error>;
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MergeAssetsRequest {
+    pub asset_ids: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseAsset {
+    pub id: String,
+    pub coin: String,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub quantity: Decimal,
+}
+
+// NOTE: `transaction_count` is the number of transactions moved to the merged asset or deleted
+#[derive(Clone, Debug, Serialize)]
+pub struct MergeAssetsResponse {
+    pub asset: ResponseAsset,
+    pub merged_asset_count: usize,
+    pub updated_tag_count: usize,
+    pub transaction_count: usize,
+}
+
+/*
+    NOTE: the assets must share the same coin and account. The oldest one is kept,
+    it takes over the ledgers and the tags of the others, which are then deleted.
+*/
+pub struct MergeAssets<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<MergeAssetsRequest, MergeAssetsResponse> for MergeAssets<DA>
+where
+    DA: MergeAssetsDataAccess,
+{
+    async fn perform(&mut self, request: MergeAssetsRequest) -> Result<MergeAssetsResponse, Error> {
+        let all_assets = self.data_access.fetch_assets().await?;
+        let mut assets: Vec<Asset> = vec![];
+        for asset_id in &request.asset_ids {
+            let maybe_asset = all_assets.iter().find(|a| &a.id == asset_id);
+            if maybe_asset.is_none() {
+                return Err(Error {
+                    message: String::from("One of the assets was not found!"),
+                    ..Default::default()
+                });
+            }
+            let asset = maybe_asset.unwrap();
+            if !assets.iter().any(|a| a.id == asset.id) {
+                assets.push(asset.clone());
+            }
+        }
+        if assets.len() < 2 {
+            return Err(Error {
+                message: String::from("At least two assets are needed to merge!"),
+                ..Default::default()
+            });
+        }
+        if assets
+            .iter()
+            .any(|a| a.coin != assets[0].coin || a.account_id != assets[0].account_id)
+        {
+            return Err(Error {
+                message: String::from("Only assets of the same coin and account can be merged!"),
+                ..Default::default()
+            });
+        }
+        assets.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        let target = assets[0].clone();
+        let source_ids: Vec<String> = assets.iter().skip(1).map(|a| a.id.clone()).collect();
+
+        let mut transactions = self.data_access.fetch_transactions().await?;
+        let opening_transactions = create_missing_opening_transactions(&assets, &transactions);
+        transactions.extend(opening_transactions.clone());
+        let ledger_merge = create_ledger_merge(&transactions, &target.id, &source_ids);
+        let merged_transactions: Vec<Transaction> = transactions
+            .iter()
+            .filter(|t| !ledger_merge.deleted_transaction_ids.contains(&t.id))
+            .map(|t| {
+                let maybe_updated = ledger_merge
+                    .updated_transactions
+                    .iter()
+                    .find(|ut| ut.id == t.id);
+                return maybe_updated.unwrap_or(t).clone();
+            })
+            .collect();
+        let mut merged_asset = target.clone();
+        merged_asset.quantity = get_balance(&target, &merged_transactions)?;
+        merged_asset.updated_at = Utc::now().to_rfc3339();
+
+        let tags = self.data_access.fetch_tags().await?;
+        let mut updated_tags: Vec<Tag> = vec![];
+        for tag in &tags {
+            let mut updated_tag = tag.clone();
+            let has_source = updated_tag
+                .assets
+                .iter()
+                .any(|a| source_ids.contains(&a.id));
+            updated_tag.assets.retain(|a| !source_ids.contains(&a.id));
+            if has_source && !updated_tag.assets.iter().any(|a| a.id == target.id) {
+                updated_tag.assets.push(merged_asset.clone());
+            }
+            if has_source {
+                updated_tag.updated_at = Utc::now().to_rfc3339();
+                updated_tags.push(updated_tag);
+            }
+        }
+
+        // NOTE: opening transactions are saved first, so they are moved like the others
+        for transaction in &opening_transactions {
+            self.data_access.save_transaction(transaction).await?;
+        }
+        for transaction_id in &ledger_merge.deleted_transaction_ids {
+            self.data_access.delete_transaction(transaction_id).await?;
+        }
+        for transaction in &ledger_merge.updated_transactions {
+            self.data_access.update_transaction(transaction).await?;
+        }
+        self.data_access.update_asset(&merged_asset).await?;
+        for updated_tag in &updated_tags {
+            self.data_access.update_tag(updated_tag).await?;
+        }
+        for source_id in &source_ids {
+            self.data_access.delete_asset(source_id).await?;
+        }
+        return Ok(MergeAssetsResponse {
+            asset: ResponseAsset {
+                id: merged_asset.id.clone(),
+                coin: merged_asset.coin.clone(),
+                quantity: merged_asset.quantity,
+            },
+            merged_asset_count: source_ids.len(),
+            updated_tag_count: updated_tags.len(),
+            transaction_count: ledger_merge.deleted_transaction_ids.len()
+                + ledger_merge.updated_transactions.len(),
+        });
+    }
+}
+
+/*
+    Moves the transactions of the source assets to the target asset. Transfers
+    between the merged assets would become transfers of the asset to itself, so
+    they are deleted instead.
+*/
+fn create_ledger_merge(
+    transactions: &Vec<Transaction>,
+    target_id: &str,
+    source_ids: &Vec<String>,
+) -> LedgerCleanup {
+    let mut merge = LedgerCleanup {
+        deleted_transaction_ids: vec![],
+        updated_transactions: vec![],
+    };
+    for transaction in transactions {
+        let mut updated_transaction = transaction.clone();
+        if source_ids.contains(&updated_transaction.asset_id) {
+            updated_transaction.asset_id = target_id.to_string();
+        }
+        if let Some(counterpart_asset_id) = &updated_transaction.counterpart_asset_id {
+            if source_ids.contains(counterpart_asset_id) {
+                updated_transaction.counterpart_asset_id = Some(target_id.to_string());
+            }
+        }
+        if updated_transaction.counterpart_asset_id.as_deref() == Some(target_id)
+            && updated_transaction.asset_id == target_id
+        {
+            merge.deleted_transaction_ids.push(transaction.id.clone());
+        } else if updated_transaction != *transaction {
+            updated_transaction.updated_at = Utc::now().to_rfc3339();
+            merge.updated_transactions.push(updated_transaction);
+        }
+    }
+    return merge;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::entities::transaction::TransactionKind;
+
+    use super::*;
+
+    fn create_transaction(
+        id: &str,
+        asset_id: &str,
+        counterpart_asset_id: Option<&str>,
+    ) -> Transaction {
+        return Transaction {
+            id: id.to_string(),
+            asset_id: asset_id.to_string(),
+            kind: if counterpart_asset_id.is_some() {
+                TransactionKind::Transfer
+            } else {
+                TransactionKind::Deposit
+            },
+            quantity: Decimal::ONE,
+            price: None,
+            price_currency: None,
+            counterpart_asset_id: counterpart_asset_id.map(|c| c.to_string()),
+            executed_at: String::from("2024-01-01T00:00:00+00:00"),
+            notes: String::new(),
+            created_at: String::from("2024-01-01T00:00:00+00:00"),
+            updated_at: String::from("2024-01-01T00:00:00+00:00"),
+        };
+    }
+
+    #[test]
+    fn test_create_ledger_merge() {
+        /*
+            Unit test expectations:
+
+            - Transactions of the sources, on either side, are moved to the target.
+            - Transfers between the merged assets are deleted.
+            - Transactions of other assets are left untouched.
+        */
+        let transactions = vec![
+            create_transaction("t1", "target", None),
+            create_transaction("t2", "source", None),
+            create_transaction("t3", "other", Some("source")),
+            create_transaction("t4", "source", Some("target")),
+            create_transaction("t5", "other", None),
+        ];
+        let merge = create_ledger_merge(&transactions, "target", &vec![String::from("source")]);
+        assert_eq!(merge.deleted_transaction_ids, vec!["t4"]);
+        assert_eq!(merge.updated_transactions.len(), 2);
+        assert_eq!(merge.updated_transactions[0].id, "t2");
+        assert_eq!(merge.updated_transactions[0].asset_id, "target");
+        assert_eq!(merge.updated_transactions[1].id, "t3");
+        assert_eq!(
+            merge.updated_transactions[1].counterpart_asset_id,
+            Some(String::from("target"))
+        );
+    }
+}
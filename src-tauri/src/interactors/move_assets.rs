@@ -0,0 +1,72 @@
+use chrono::Utc;
+use serde::Deserialize;
+
+use crate::{
+    entities::{account::Account, asset::Asset},
+    Error,
+};
+
+use super::interactor::Interactor;
+
+pub trait MoveAssetsDataAccess {
+    async fn find_account(&mut self, id: &str) -> Result<Option<Account>, Error>;
+    async fn find_asset(&mut self, id: &str) -> Result<Option<Asset>, Error>;
+    async fn update_asset(&mut self, asset: &Asset) -> Result<(), Error>;
+}
+
+// NOTE: without an `account_id` the assets are taken out of their account
+#[derive(Clone, Debug, Deserialize)]
+pub struct MoveAssetsRequest {
+    pub asset_ids: Vec<String>,
+    pub account_id: Option<String>,
+}
+
+/*
+    NOTE: moving an asset changes who holds it, not how much of it there is, so
+    no transfer is recorded and its ledger moves along with it
+*/
+pub struct MoveAssets<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<MoveAssetsRequest, ()> for MoveAssets<DA>
+where
+    DA: MoveAssetsDataAccess,
+{
+    async fn perform(&mut self, request: MoveAssetsRequest) -> Result<(), Error> {
+        if let Some(account_id) = &request.account_id {
+            let maybe_account = self.data_access.find_account(account_id).await?;
+            if maybe_account.is_none() {
+                return Err(Error {
+                    message: String::from("Account to move the assets to does not exist!"),
+                    ..Default::default()
+                });
+            }
+        }
+        let mut assets: Vec<Asset> = vec![];
+        for asset_id in &request.asset_ids {
+            let maybe_asset = self.data_access.find_asset(asset_id).await?;
+            if maybe_asset.is_none() {
+                return Err(Error {
+                    message: String::from("Asset to move does not exist!"),
+                    ..Default::default()
+                });
+            }
+            assets.push(maybe_asset.unwrap());
+        }
+        for asset in &mut assets {
+            if asset.account_id == request.account_id {
+                continue;
+            }
+            asset.account_id = request.account_id.clone();
+            asset.updated_at = Utc::now().to_rfc3339();
+            self.data_access.update_asset(asset).await?;
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
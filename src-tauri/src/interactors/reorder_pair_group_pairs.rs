@@ -0,0 +1,72 @@
+use chrono::Utc;
+use serde::Deserialize;
+
+use crate::{
+    entities::{pair::Pair, pair_group::PairGroup},
+    utilities::manual_order::{is_complete_order, reorder_pairs},
+    Error,
+};
+
+use super::interactor::Interactor;
+
+pub trait ReorderPairGroupPairsDataAccess {
+    async fn update_pair(&mut self, pair: &Pair) -> Result<(), Error>;
+    async fn find_pair_group(&mut self, id: &str) -> Result<Option<PairGroup>, Error>;
+    async fn update_pair_group(&mut self, pair_group: &PairGroup) -> Result<(), Error>;
+}
+
+// NOTE: `pair_ids` lists every pair of the pair group, in the new order
+#[derive(Clone, Debug, Deserialize)]
+pub struct ReorderPairGroupPairsRequest {
+    pub pair_group_id: String,
+    pub pair_ids: Vec<String>,
+}
+
+pub struct ReorderPairGroupPairs<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<ReorderPairGroupPairsRequest, ()> for ReorderPairGroupPairs<DA>
+where
+    DA: ReorderPairGroupPairsDataAccess,
+{
+    async fn perform(&mut self, request: ReorderPairGroupPairsRequest) -> Result<(), Error> {
+        let maybe_pair_group = self
+            .data_access
+            .find_pair_group(&request.pair_group_id)
+            .await?;
+        if maybe_pair_group.is_none() {
+            return Err(Error {
+                message: String::from("Pair group to reorder does not exist!"),
+                ..Default::default()
+            });
+        }
+        let pair_group = maybe_pair_group.unwrap();
+        let pair_ids: Vec<String> = pair_group.pairs.iter().map(|p| p.id.clone()).collect();
+        if !is_complete_order(&request.pair_ids, &pair_ids) {
+            return Err(Error {
+                message: String::from(
+                    "The new order must list every pair of the pair group exactly once!",
+                ),
+                ..Default::default()
+            });
+        }
+        let mut updated_pair_group = pair_group.clone();
+        updated_pair_group.pairs = reorder_pairs(&pair_group.pairs, &request.pair_ids);
+        updated_pair_group.updated_at = Utc::now().to_rfc3339();
+        for pair in &updated_pair_group.pairs {
+            if !pair_group.pairs.contains(pair) {
+                self.data_access.update_pair(pair).await?;
+            }
+        }
+        self.data_access
+            .update_pair_group(&updated_pair_group)
+            .await?;
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
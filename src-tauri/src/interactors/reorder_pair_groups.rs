@@ -0,0 +1,56 @@
+use chrono::Utc;
+use serde::Deserialize;
+
+use crate::{entities::pair_group::PairGroup, utilities::manual_order::is_complete_order, Error};
+
+use super::interactor::Interactor;
+
+pub trait ReorderPairGroupsDataAccess {
+    async fn fetch_pair_groups(&mut self) -> Result<Vec<PairGroup>, Error>;
+    async fn update_pair_group(&mut self, pair_group: &PairGroup) -> Result<(), Error>;
+}
+
+// NOTE: `pair_group_ids` lists every pair group, in the new order
+#[derive(Clone, Debug, Deserialize)]
+pub struct ReorderPairGroupsRequest {
+    pub pair_group_ids: Vec<String>,
+}
+
+pub struct ReorderPairGroups<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<ReorderPairGroupsRequest, ()> for ReorderPairGroups<DA>
+where
+    DA: ReorderPairGroupsDataAccess,
+{
+    async fn perform(&mut self, request: ReorderPairGroupsRequest) -> Result<(), Error> {
+        let pair_groups = self.data_access.fetch_pair_groups().await?;
+        let pair_group_ids: Vec<String> = pair_groups.iter().map(|pg| pg.id.clone()).collect();
+        if !is_complete_order(&request.pair_group_ids, &pair_group_ids) {
+            return Err(Error {
+                message: String::from("The new order must list every pair group exactly once!"),
+                ..Default::default()
+            });
+        }
+        for (position, pair_group_id) in request.pair_group_ids.iter().enumerate() {
+            let mut pair_group = pair_groups
+                .iter()
+                .find(|pg| &pg.id == pair_group_id)
+                .unwrap()
+                .clone();
+            if pair_group.position == position as u32 {
+                continue;
+            }
+            pair_group.position = position as u32;
+            pair_group.updated_at = Utc::now().to_rfc3339();
+            self.data_access.update_pair_group(&pair_group).await?;
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
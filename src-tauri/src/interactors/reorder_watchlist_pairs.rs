@@ -0,0 +1,92 @@
+use chrono::Utc;
+use serde::Deserialize;
+
+use crate::{
+    entities::{pair::Pair, watchlist::Watchlist},
+    utilities::{
+        manual_order::{is_complete_order, reorder_pairs},
+        watchlist_selection::select_watchlist,
+    },
+    Error,
+};
+
+use super::interactor::Interactor;
+
+pub trait ReorderWatchlistPairsDataAccess {
+    async fn update_pair(&mut self, pair: &Pair) -> Result<(), Error>;
+    async fn fetch_watchlists(&mut self) -> Result<Vec<Watchlist>, Error>;
+    async fn update_watchlist(&mut self, watchlist: &Watchlist) -> Result<(), Error>;
+}
+
+/*
+    NOTE: `pair_ids` and `cross_pair_ids` list every pair and every cross pair of
+    the watchlist, in the new order, since both lists are arranged separately
+*/
+#[derive(Clone, Debug, Deserialize)]
+pub struct ReorderWatchlistPairsRequest {
+    pub watchlist_id: Option<String>,
+    pub pair_ids: Vec<String>,
+    pub cross_pair_ids: Vec<String>,
+}
+
+pub struct ReorderWatchlistPairs<DA> {
+    pub data_access: DA,
+    pub default_watchlist_id: Option<String>,
+}
+
+impl<DA> Interactor<ReorderWatchlistPairsRequest, ()> for ReorderWatchlistPairs<DA>
+where
+    DA: ReorderWatchlistPairsDataAccess,
+{
+    async fn perform(&mut self, request: ReorderWatchlistPairsRequest) -> Result<(), Error> {
+        let watchlists = self.data_access.fetch_watchlists().await?;
+        let maybe_watchlist = select_watchlist(
+            &watchlists,
+            &request.watchlist_id,
+            &self.default_watchlist_id,
+        )?;
+        if maybe_watchlist.is_none() {
+            return Err(Error {
+                message: String::from("Watchlist not found!"),
+                ..Default::default()
+            });
+        }
+        let watchlist = maybe_watchlist.unwrap();
+        let pair_ids: Vec<String> = watchlist.pairs.iter().map(|p| p.id.clone()).collect();
+        let cross_pair_ids: Vec<String> =
+            watchlist.cross_pairs.iter().map(|p| p.id.clone()).collect();
+        if !is_complete_order(&request.pair_ids, &pair_ids)
+            || !is_complete_order(&request.cross_pair_ids, &cross_pair_ids)
+        {
+            return Err(Error {
+                message: String::from(
+                    "The new order must list every pair of the watchlist exactly once!",
+                ),
+                ..Default::default()
+            });
+        }
+        let mut updated_watchlist = watchlist.clone();
+        updated_watchlist.pairs = reorder_pairs(&watchlist.pairs, &request.pair_ids);
+        updated_watchlist.cross_pairs =
+            reorder_pairs(&watchlist.cross_pairs, &request.cross_pair_ids);
+        updated_watchlist.updated_at = Utc::now().to_rfc3339();
+        for pair in updated_watchlist
+            .pairs
+            .iter()
+            .chain(updated_watchlist.cross_pairs.iter())
+        {
+            if !watchlist.pairs.contains(pair) && !watchlist.cross_pairs.contains(pair) {
+                self.data_access.update_pair(pair).await?;
+            }
+        }
+        self.data_access
+            .update_watchlist(&updated_watchlist)
+            .await?;
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
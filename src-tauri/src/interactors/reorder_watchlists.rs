@@ -0,0 +1,56 @@
+use chrono::Utc;
+use serde::Deserialize;
+
+use crate::{entities::watchlist::Watchlist, utilities::manual_order::is_complete_order, Error};
+
+use super::interactor::Interactor;
+
+pub trait ReorderWatchlistsDataAccess {
+    async fn fetch_watchlists(&mut self) -> Result<Vec<Watchlist>, Error>;
+    async fn update_watchlist(&mut self, watchlist: &Watchlist) -> Result<(), Error>;
+}
+
+// NOTE: `watchlist_ids` lists every watchlist, in the new order
+#[derive(Clone, Debug, Deserialize)]
+pub struct ReorderWatchlistsRequest {
+    pub watchlist_ids: Vec<String>,
+}
+
+pub struct ReorderWatchlists<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<ReorderWatchlistsRequest, ()> for ReorderWatchlists<DA>
+where
+    DA: ReorderWatchlistsDataAccess,
+{
+    async fn perform(&mut self, request: ReorderWatchlistsRequest) -> Result<(), Error> {
+        let watchlists = self.data_access.fetch_watchlists().await?;
+        let watchlist_ids: Vec<String> = watchlists.iter().map(|w| w.id.clone()).collect();
+        if !is_complete_order(&request.watchlist_ids, &watchlist_ids) {
+            return Err(Error {
+                message: String::from("The new order must list every watchlist exactly once!"),
+                ..Default::default()
+            });
+        }
+        for (position, watchlist_id) in request.watchlist_ids.iter().enumerate() {
+            let mut watchlist = watchlists
+                .iter()
+                .find(|w| &w.id == watchlist_id)
+                .unwrap()
+                .clone();
+            if watchlist.position == position as u32 {
+                continue;
+            }
+            watchlist.position = position as u32;
+            watchlist.updated_at = Utc::now().to_rfc3339();
+            self.data_access.update_watchlist(&watchlist).await?;
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
@@ -0,0 +1,182 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    entities::{asset::Asset, tag::Tag},
+    Error,
+};
+
+use super::interactor::Interactor;
+
+pub trait RetagAssetsDataAccess {
+    async fn fetch_tags(&mut self) -> Result<Vec<Tag>, Error>;
+    async fn fetch_assets(&mut self) -> Result<Vec<Asset>, Error>;
+    async fn update_tag(&mut self, tag: &Tag) -> Result<(), Error>;
+}
+
+// NOTE: tags that are neither added nor removed are left as they are
+#[derive(Clone, Debug, Deserialize)]
+pub struct RetagAssetsRequest {
+    pub asset_ids: Vec<String>,
+    pub added_tag_ids: Vec<String>,
+    pub removed_tag_ids: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct RetagAssetsResponse {
+    pub asset_count: usize,
+    pub updated_tag_count: usize,
+}
+
+pub struct RetagAssets<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<RetagAssetsRequest, RetagAssetsResponse> for RetagAssets<DA>
+where
+    DA: RetagAssetsDataAccess,
+{
+    async fn perform(&mut self, request: RetagAssetsRequest) -> Result<RetagAssetsResponse, Error> {
+        if request
+            .added_tag_ids
+            .iter()
+            .any(|id| request.removed_tag_ids.contains(id))
+        {
+            return Err(Error {
+                message: String::from("A tag cannot be both added and removed!"),
+                ..Default::default()
+            });
+        }
+        let tags = self.data_access.fetch_tags().await?;
+        let all_assets = self.data_access.fetch_assets().await?;
+        let mut assets: Vec<Asset> = vec![];
+        for asset_id in &request.asset_ids {
+            let maybe_asset = all_assets.iter().find(|a| &a.id == asset_id);
+            if maybe_asset.is_none() {
+                return Err(Error {
+                    message: String::from("One of the assets was not found!"),
+                    ..Default::default()
+                });
+            }
+            assets.push(maybe_asset.unwrap().clone());
+        }
+        for tag_id in request
+            .added_tag_ids
+            .iter()
+            .chain(request.removed_tag_ids.iter())
+        {
+            if !tags.iter().any(|t| &t.id == tag_id) {
+                return Err(Error {
+                    message: String::from("One of the tags was not found!"),
+                    ..Default::default()
+                });
+            }
+        }
+
+        let updated_tags = create_retagged_tags(
+            &tags,
+            &assets,
+            &request.added_tag_ids,
+            &request.removed_tag_ids,
+        );
+        for updated_tag in &updated_tags {
+            self.data_access.update_tag(updated_tag).await?;
+        }
+        return Ok(RetagAssetsResponse {
+            asset_count: assets.len(),
+            updated_tag_count: updated_tags.len(),
+        });
+    }
+}
+
+// NOTE: only the tags whose assets actually change are returned
+fn create_retagged_tags(
+    tags: &Vec<Tag>,
+    assets: &Vec<Asset>,
+    added_tag_ids: &Vec<String>,
+    removed_tag_ids: &Vec<String>,
+) -> Vec<Tag> {
+    let mut updated_tags: Vec<Tag> = vec![];
+    for tag in tags {
+        let mut updated_tag = tag.clone();
+        if added_tag_ids.contains(&tag.id) {
+            for asset in assets {
+                if !updated_tag.assets.iter().any(|a| a.id == asset.id) {
+                    updated_tag.assets.push(asset.clone());
+                }
+            }
+        } else if removed_tag_ids.contains(&tag.id) {
+            updated_tag
+                .assets
+                .retain(|a| !assets.iter().any(|asset| asset.id == a.id));
+        }
+        if updated_tag.assets.len() != tag.assets.len() {
+            updated_tag.updated_at = Utc::now().to_rfc3339();
+            updated_tags.push(updated_tag);
+        }
+    }
+    return updated_tags;
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal::Decimal;
+
+    use super::*;
+
+    fn create_asset(id: &str) -> Asset {
+        return Asset {
+            id: id.to_string(),
+            coin: String::from("BTC"),
+            quantity: Decimal::ONE,
+            usd_value: Decimal::ONE,
+            account_id: None,
+            created_at: String::from("2024-01-01T00:00:00+00:00"),
+            updated_at: String::from("2024-01-01T00:00:00+00:00"),
+        };
+    }
+
+    fn create_tag(id: &str, asset_ids: Vec<&str>) -> Tag {
+        return Tag {
+            id: id.to_string(),
+            name: id.to_string(),
+            color: None,
+            description: String::new(),
+            parent_id: None,
+            assets: asset_ids.into_iter().map(create_asset).collect(),
+            targets: vec![],
+            created_at: String::from("2024-01-01T00:00:00+00:00"),
+            updated_at: String::from("2024-01-01T00:00:00+00:00"),
+        };
+    }
+
+    #[test]
+    fn test_create_retagged_tags() {
+        /*
+            Unit test expectations:
+
+            - Added tags get the assets they do not have yet.
+            - Removed tags lose the assets, other tags are left untouched.
+            - Tags whose assets do not change are not updated.
+        */
+        let tags = vec![
+            create_tag("added", vec!["a1"]),
+            create_tag("removed", vec!["a1", "a2", "a3"]),
+            create_tag("other", vec!["a1"]),
+            create_tag("already", vec!["a1", "a2"]),
+        ];
+        let assets = vec![create_asset("a1"), create_asset("a2")];
+        let updated_tags = create_retagged_tags(
+            &tags,
+            &assets,
+            &vec![String::from("added"), String::from("already")],
+            &vec![String::from("removed")],
+        );
+        assert_eq!(updated_tags.len(), 2);
+        assert_eq!(updated_tags[0].id, "added");
+        assert_eq!(updated_tags[0].assets.len(), 2);
+        assert_eq!(updated_tags[1].id, "removed");
+        assert_eq!(updated_tags[1].assets.len(), 1);
+        assert_eq!(updated_tags[1].assets[0].id, "a3");
+    }
+}
@@ -0,0 +1,59 @@
+use chrono::Utc;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{entities::account::Account, Error};
+
+use super::interactor::Interactor;
+
+pub trait SaveAccountDataAccess {
+    async fn save_account(&mut self, account: &Account) -> Result<(), Error>;
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RequestAccount {
+    pub name: String,
+}
+
+impl PartialEq for RequestAccount {
+    fn eq(&self, other: &Self) -> bool {
+        return self.name == other.name;
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SaveAccountRequest {
+    pub account: RequestAccount,
+}
+
+pub struct SaveAccount<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<SaveAccountRequest, ()> for SaveAccount<DA>
+where
+    DA: SaveAccountDataAccess,
+{
+    async fn perform(&mut self, request: SaveAccountRequest) -> Result<(), Error> {
+        let name = request.account.name.trim();
+        if name.is_empty() {
+            return Err(Error {
+                message: String::from("Account name must not be empty!"),
+                ..Default::default()
+            });
+        }
+        let account = Account {
+            id: Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            created_at: Utc::now().to_rfc3339(),
+            updated_at: Utc::now().to_rfc3339(),
+        };
+        self.data_access.save_account(&account).await?;
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
@@ -1,22 +1,25 @@
 use chrono::Utc;
+use rust_decimal::Decimal;
 use serde::Deserialize;
 use uuid::Uuid;
 
 use crate::{
     entities::{pair::Pair, pair_group::PairGroup},
+    utilities::manual_order::get_next_position,
     Error,
 };
 
 use super::interactor::Interactor;
 
 pub trait SavePairGroupDataAccess {
+    async fn fetch_pair_groups(&mut self) -> Result<Vec<PairGroup>, Error>;
     async fn save_pair(&mut self, pair: &Pair) -> Result<(), Error>;
     async fn save_pair_group(&mut self, pair_group: &PairGroup) -> Result<(), Error>;
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct RequestPair {
-    pub value: f64,
+    pub value: Decimal,
     pub base: String,
     pub comparison: String,
 }
@@ -32,7 +35,7 @@ impl PartialEq for RequestPair {
 #[derive(Clone, Debug, Deserialize)]
 pub struct RequestPairGroup {
     pub is_pinned: bool,
-    pub multiplier: f64,
+    pub multiplier: Decimal,
     pub pairs: Vec<RequestPair>,
 }
 
@@ -49,6 +52,7 @@ pub struct SavePairGroupRequest {
     pub pair_group: RequestPairGroup,
 }
 
+// NOTE: new pair groups are placed after the existing ones, their pairs in the requested order
 pub struct SavePairGroup<DA> {
     pub data_access: DA,
 }
@@ -59,19 +63,23 @@ where
 {
     async fn perform(&mut self, request: SavePairGroupRequest) -> Result<(), Error> {
         validate_request(&request)?;
+        let pair_groups = self.data_access.fetch_pair_groups().await?;
         let pair_group = PairGroup {
             id: Uuid::new_v4().to_string(),
             is_pinned: request.pair_group.is_pinned,
             multiplier: request.pair_group.multiplier,
+            position: get_next_position(&pair_groups.iter().map(|pg| pg.position).collect()),
             pairs: request
                 .pair_group
                 .pairs
                 .iter()
-                .map(|p| Pair {
+                .enumerate()
+                .map(|(position, p)| Pair {
                     id: Uuid::new_v4().to_string(),
                     base: p.base.clone(),
                     value: p.value.clone(),
                     comparison: p.comparison.clone(),
+                    position: position as u32,
                     created_at: Utc::now().to_rfc3339(),
                     updated_at: Utc::now().to_rfc3339(),
                 })
@@ -92,6 +100,7 @@ fn validate_request(request: &SavePairGroupRequest) -> Result<(), Error> {
     if pairs_len == 0 {
         return Err(Error {
             message: String::from("Cannot save a pair group that does not have pairs!"),
+            ..Default::default()
         });
     }
     if pairs_len > 1 {
@@ -111,6 +120,7 @@ fn validate_request_pair_bases(request: &SavePairGroupRequest) -> Result<(), Err
                 message: String::from(
                     "Cannot save a pair group that contains pairs with different bases!",
                 ),
+                ..Default::default()
             });
         }
     }
@@ -128,6 +138,7 @@ fn validate_request_duplicate_pairs(request: &SavePairGroupRequest) -> Result<()
                     message: String::from(
                         "Cannot save a pair group that contains duplicate pairs!",
                     ),
+                    ..Default::default()
                 });
             }
         }
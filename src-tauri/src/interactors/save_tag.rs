@@ -2,22 +2,33 @@ use chrono::Utc;
 use serde::Deserialize;
 use uuid::Uuid;
 
-use crate::{entities::tag::Tag, Error};
+use crate::{
+    entities::tag::Tag,
+    utilities::tag_hierarchy::{validate_color, validate_parent, validate_tag_name},
+    Error,
+};
 
 use super::interactor::Interactor;
 
 pub trait SaveTagDataAccess {
+    async fn fetch_tags(&mut self) -> Result<Vec<Tag>, Error>;
     async fn save_tag(&mut self, tag: &Tag) -> Result<(), Error>;
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct RequestTag {
     pub name: String,
+    pub color: Option<String>,
+    pub description: Option<String>,
+    pub parent_id: Option<String>,
 }
 
 impl PartialEq for RequestTag {
     fn eq(&self, other: &Self) -> bool {
-        return self.name == other.name;
+        return self.name == other.name
+            && self.color == other.color
+            && self.description == other.description
+            && self.parent_id == other.parent_id;
     }
 }
 
@@ -38,10 +49,18 @@ where
         let tag = Tag {
             id: Uuid::new_v4().to_string(),
             assets: vec![],
-            name: request.tag.name,
+            name: request.tag.name.trim().to_string(),
+            color: request.tag.color,
+            description: request.tag.description.unwrap_or_default(),
+            parent_id: request.tag.parent_id,
+            targets: vec![],
             created_at: Utc::now().to_rfc3339(),
             updated_at: Utc::now().to_rfc3339(),
         };
+        validate_color(&tag.color)?;
+        let tags = self.data_access.fetch_tags().await?;
+        validate_parent(&tags, &tag.id, &tag.parent_id)?;
+        validate_tag_name(&tags, &tag.id, &tag.name, &tag.parent_id)?;
         self.data_access.save_tag(&tag).await?;
         return Ok(());
     }
@@ -0,0 +1,66 @@
+use chrono::Utc;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    entities::watchlist::Watchlist, utilities::watchlist_selection::validate_watchlist_name, Error,
+};
+
+use super::interactor::Interactor;
+
+pub trait SaveWatchlistDataAccess {
+    async fn fetch_watchlists(&mut self) -> Result<Vec<Watchlist>, Error>;
+    async fn save_watchlist(&mut self, watchlist: &Watchlist) -> Result<(), Error>;
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RequestWatchlist {
+    pub name: String,
+}
+
+impl PartialEq for RequestWatchlist {
+    fn eq(&self, other: &Self) -> bool {
+        return self.name == other.name;
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SaveWatchlistRequest {
+    pub watchlist: RequestWatchlist,
+}
+
+// NOTE: new watchlists are placed after the existing ones
+pub struct SaveWatchlist<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<SaveWatchlistRequest, ()> for SaveWatchlist<DA>
+where
+    DA: SaveWatchlistDataAccess,
+{
+    async fn perform(&mut self, request: SaveWatchlistRequest) -> Result<(), Error> {
+        let watchlists = self.data_access.fetch_watchlists().await?;
+        let id = Uuid::new_v4().to_string();
+        validate_watchlist_name(&watchlists, &id, &request.watchlist.name)?;
+        let watchlist = Watchlist {
+            id,
+            name: request.watchlist.name.trim().to_string(),
+            position: watchlists
+                .iter()
+                .map(|w| w.position + 1)
+                .max()
+                .unwrap_or_default(),
+            pairs: vec![],
+            cross_pairs: vec![],
+            created_at: Utc::now().to_rfc3339(),
+            updated_at: Utc::now().to_rfc3339(),
+        };
+        self.data_access.save_watchlist(&watchlist).await?;
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
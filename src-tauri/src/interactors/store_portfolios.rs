@@ -1,19 +1,29 @@
 use chrono::Utc;
+use rust_decimal::Decimal;
 use serde::Deserialize;
 use uuid::Uuid;
 
 use crate::{
-    entities::{asset::Asset, pair::Pair, tag::Tag},
-    utilities::coin_market::CoinMarket,
+    entities::{account::Account, asset::Asset, tag::Tag, transaction::Transaction},
+    error::FieldError,
+    utilities::{
+        coin_market::CoinMarket,
+        currency_converter::{CurrencyConverter, DEFAULT_PIVOT},
+        ledger::{create_opening_transaction, validate_transaction},
+        portfolio_validation::{create_validation_error, validate_acquisition, validate_asset},
+        rounding_policy::round_amount,
+    },
     Error,
 };
 
 use super::interactor::Interactor;
 
 pub trait StorePortfoliosDataAccess {
+    async fn find_account(&mut self, id: &str) -> Result<Option<Account>, Error>;
     async fn find_tag(&mut self, id: &str) -> Result<Option<Tag>, Error>;
     async fn update_tag(&mut self, tag: &Tag) -> Result<(), Error>;
     async fn save_asset(&mut self, asset: &Asset) -> Result<(), Error>;
+    async fn save_transaction(&mut self, transaction: &Transaction) -> Result<(), Error>;
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -21,15 +31,28 @@ pub struct RequestTag {
     pub id: String,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct RequestAccount {
+    pub id: String,
+}
+
+/*
+    NOTE: the acquisition price and date end up in the opening transaction of the
+    asset, and a `null` quantity is one the frontend could not parse
+*/
 #[derive(Clone, Debug, Deserialize)]
 pub struct RequestAsset {
     pub coin: String,
-    pub quantity: f64,
+    pub quantity: Option<Decimal>,
+    pub acquisition_price: Option<Decimal>,
+    pub acquisition_currency: Option<String>,
+    pub acquired_at: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct StorePortfoliosRequest {
     pub tag: Option<RequestTag>,
+    pub account: Option<RequestAccount>,
     pub assets: Vec<RequestAsset>,
 }
 
@@ -45,51 +68,70 @@ where
     CM: CoinMarket,
 {
     async fn perform(&mut self, request: StorePortfoliosRequest) -> Result<(), Error> {
+        if let Some(request_account) = &request.account {
+            let maybe_account = self.data_access.find_account(&request_account.id).await?;
+            if maybe_account.is_none() {
+                return Err(Error {
+                    message: String::from("Account not found!"),
+                    ..Default::default()
+                });
+            }
+        }
+        let usd_pairs = self.coin_market.fetch_usd_pairs().await?;
+        let converter = CurrencyConverter::new(&usd_pairs, DEFAULT_PIVOT)?;
+        let mut field_errors: Vec<FieldError> = vec![];
+        for (idx, request_asset) in request.assets.iter().enumerate() {
+            field_errors.extend(validate_asset(
+                &format!("assets[{}]", idx),
+                &request_asset.coin,
+                &request_asset.quantity,
+                &converter,
+            ));
+            field_errors.extend(validate_acquisition(
+                &format!("assets[{}]", idx),
+                &request_asset.acquisition_price,
+                &request_asset.acquisition_currency,
+                &request_asset.acquired_at,
+                &converter,
+            ));
+        }
+        create_validation_error(&field_errors)?;
         if request.tag.is_none() {
-            return store_assets(&mut self.data_access, &mut self.coin_market, &request).await;
+            return store_assets(&mut self.data_access, &converter, &request).await;
         } else {
-            return store_tagged_assets(&mut self.data_access, &mut self.coin_market, &request)
-                .await;
+            return store_tagged_assets(&mut self.data_access, &converter, &request).await;
         }
     }
 }
 
 async fn store_assets(
     data_access: &mut impl StorePortfoliosDataAccess,
-    coin_market: &mut impl CoinMarket,
+    converter: &CurrencyConverter,
     request: &StorePortfoliosRequest,
 ) -> Result<(), Error> {
-    let usd_pairs = coin_market.fetch_usd_pairs().await?;
     for request_asset in &request.assets {
-        let usd_value = get_equivalent_usd_value(&usd_pairs, &request_asset.coin)?;
+        let usd_value = converter.get_rate(&request_asset.coin, "USD")?.value;
         let asset = Asset {
             id: Uuid::new_v4().to_string(),
             usd_value,
             coin: request_asset.coin.clone(),
-            quantity: request_asset.quantity.clone(),
+            quantity: round_amount(request_asset.quantity.unwrap(), &request_asset.coin),
+            account_id: request.account.as_ref().map(|a| a.id.clone()),
             created_at: Utc::now().to_rfc3339(),
             updated_at: Utc::now().to_rfc3339(),
         };
+        let maybe_opening_transaction = create_asset_opening_transaction(&asset, request_asset)?;
         data_access.save_asset(&asset).await?;
-    }
-    return Ok(());
-}
-
-// TODO: maybe extract it to a utility function, since it seems to be used in several code parts
-fn get_equivalent_usd_value(usd_pairs: &Vec<Pair>, target_base: &str) -> Result<f64, Error> {
-    for usd_pair in usd_pairs {
-        if usd_pair.comparison == target_base {
-            return Ok(1.0 / usd_pair.value);
+        if let Some(opening_transaction) = &maybe_opening_transaction {
+            data_access.save_transaction(opening_transaction).await?;
         }
     }
-    return Err(Error {
-        message: String::from("Could not find the equivalent USD value for the target base!"),
-    });
+    return Ok(());
 }
 
 async fn store_tagged_assets(
     data_access: &mut impl StorePortfoliosDataAccess,
-    coin_market: &mut impl CoinMarket,
+    converter: &CurrencyConverter,
     request: &StorePortfoliosRequest,
 ) -> Result<(), Error> {
     let request_tag = request.tag.clone().unwrap();
@@ -97,26 +139,48 @@ async fn store_tagged_assets(
     if maybe_tag.is_none() {
         return Err(Error {
             message: String::from("Tag not found!"),
+            ..Default::default()
         });
     }
     let mut tag = maybe_tag.unwrap();
-    let usd_pairs = coin_market.fetch_usd_pairs().await?;
     for request_asset in &request.assets {
-        let usd_value = get_equivalent_usd_value(&usd_pairs, &request_asset.coin)?;
+        let usd_value = converter.get_rate(&request_asset.coin, "USD")?.value;
         let asset = Asset {
             id: Uuid::new_v4().to_string(),
             usd_value,
             coin: request_asset.coin.clone(),
-            quantity: request_asset.quantity.clone(),
+            quantity: round_amount(request_asset.quantity.unwrap(), &request_asset.coin),
+            account_id: request.account.as_ref().map(|a| a.id.clone()),
             created_at: Utc::now().to_rfc3339(),
             updated_at: Utc::now().to_rfc3339(),
         };
+        let maybe_opening_transaction = create_asset_opening_transaction(&asset, request_asset)?;
         data_access.save_asset(&asset).await?;
+        if let Some(opening_transaction) = &maybe_opening_transaction {
+            data_access.save_transaction(opening_transaction).await?;
+        }
         tag.assets.push(asset);
     }
     return data_access.update_tag(&tag).await;
 }
 
+fn create_asset_opening_transaction(
+    asset: &Asset,
+    request_asset: &RequestAsset,
+) -> Result<Option<Transaction>, Error> {
+    if asset.quantity <= Decimal::ZERO {
+        return Ok(None);
+    }
+    let mut opening_transaction = create_opening_transaction(asset);
+    opening_transaction.price = request_asset.acquisition_price;
+    opening_transaction.price_currency = request_asset.acquisition_currency.clone();
+    if let Some(acquired_at) = &request_asset.acquired_at {
+        opening_transaction.executed_at = acquired_at.clone();
+    }
+    validate_transaction(&opening_transaction)?;
+    return Ok(Some(opening_transaction));
+}
+
 #[cfg(test)]
 mod test {
     // TODO: write tests for this `impl``
@@ -4,7 +4,12 @@ use uuid::Uuid;
 
 use crate::{
     entities::{pair::Pair, watchlist::Watchlist},
-    utilities::coin_market::CoinMarket,
+    utilities::{
+        coin_market::CoinMarket,
+        currency_converter::{CurrencyConverter, DEFAULT_PIVOT},
+        manual_order::get_next_position,
+        watchlist_selection::select_watchlist,
+    },
     Error,
 };
 
@@ -12,18 +17,38 @@ use super::interactor::Interactor;
 
 pub trait StoreWatchlistCoinsDataAccess {
     async fn save_pair(&mut self, pair: &Pair) -> Result<(), Error>;
-    async fn get_watchlist(&mut self) -> Result<Watchlist, Error>;
+    async fn fetch_watchlists(&mut self) -> Result<Vec<Watchlist>, Error>;
     async fn update_watchlist(&mut self, watchlist: &Watchlist) -> Result<(), Error>;
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct RequestPair {
+    pub base: String,
+    pub comparison: String,
+}
+
+impl PartialEq for RequestPair {
+    fn eq(&self, other: &Self) -> bool {
+        return self.base == other.base && self.comparison == other.comparison;
+    }
+}
+
+/*
+    NOTE: `coins` are quoted against the reference currency, while `pairs` keep
+    their own base and end up in the cross pairs of the watchlist
+*/
 #[derive(Clone, Debug, Deserialize)]
 pub struct StoreWatchlistCoinsRequest {
+    pub watchlist_id: Option<String>,
     pub coins: Vec<String>,
+    pub pairs: Vec<RequestPair>,
 }
 
 pub struct StoreWatchlistCoins<DA, CM> {
     pub data_access: DA,
     pub coin_market: CM,
+    pub reference_currency: String,
+    pub default_watchlist_id: Option<String>,
 }
 
 impl<DA, CM> Interactor<StoreWatchlistCoinsRequest, ()> for StoreWatchlistCoins<DA, CM>
@@ -32,34 +57,199 @@ where
     CM: CoinMarket,
 {
     async fn perform(&mut self, request: StoreWatchlistCoinsRequest) -> Result<(), Error> {
-        /* TODO:
-            - Check for repeated coins
-        */
-        let mut watchlist = self.data_access.get_watchlist().await?;
+        let mut watchlist = get_watchlist(
+            &mut self.data_access,
+            &request.watchlist_id,
+            &self.default_watchlist_id,
+        )
+        .await?;
         let usd_pairs = self.coin_market.fetch_usd_pairs().await?;
-        for coin in &request.coins {
-            for usd_pair in &usd_pairs {
-                if usd_pair.comparison.eq(coin) {
-                    let pair = Pair {
-                        id: Uuid::new_v4().to_string(),
-                        base: String::from("USD"),
-                        comparison: coin.clone(),
-                        value: usd_pair.value.clone(),
-                        created_at: Utc::now().to_rfc3339(),
-                        updated_at: Utc::now().to_rfc3339(),
-                    };
-                    self.data_access.save_pair(&pair).await?;
-                    watchlist.pairs.push(pair);
-                    break;
-                }
-            }
+        let converter = CurrencyConverter::new(&usd_pairs, DEFAULT_PIVOT)?;
+        let request_pairs: Vec<RequestPair> = request
+            .coins
+            .iter()
+            .map(|c| RequestPair {
+                base: self.reference_currency.clone(),
+                comparison: c.clone(),
+            })
+            .collect();
+        let pairs = create_pairs(&converter, &watchlist.pairs, &request_pairs, true)?;
+        let cross_pairs = create_pairs(&converter, &watchlist.cross_pairs, &request.pairs, false)?;
+        for pair in pairs.iter().chain(cross_pairs.iter()) {
+            self.data_access.save_pair(pair).await?;
         }
+        watchlist.pairs.extend(pairs);
+        watchlist.cross_pairs.extend(cross_pairs);
+        watchlist.updated_at = Utc::now().to_rfc3339();
         self.data_access.update_watchlist(&watchlist).await?;
         return Ok(());
     }
 }
 
+async fn get_watchlist(
+    data_access: &mut impl StoreWatchlistCoinsDataAccess,
+    watchlist_id: &Option<String>,
+    default_watchlist_id: &Option<String>,
+) -> Result<Watchlist, Error> {
+    let watchlists = data_access.fetch_watchlists().await?;
+    let maybe_watchlist = select_watchlist(&watchlists, watchlist_id, default_watchlist_id)?;
+    if maybe_watchlist.is_none() {
+        return Err(Error {
+            message: String::from("Watchlist not found!"),
+            ..Default::default()
+        });
+    }
+    return Ok(maybe_watchlist.unwrap());
+}
+
+/*
+    Creates the pairs to add after `watched_pairs`, valued through cross rates.
+    Pairs that follow the reference currency are told apart by their comparison
+    only, since their base changes along with the reference currency.
+*/
+fn create_pairs(
+    converter: &CurrencyConverter,
+    watched_pairs: &Vec<Pair>,
+    request_pairs: &Vec<RequestPair>,
+    follows_reference: bool,
+) -> Result<Vec<Pair>, Error> {
+    let mut pairs: Vec<Pair> = vec![];
+    for request_pair in request_pairs {
+        let name = format!("{}/{}", request_pair.base, request_pair.comparison);
+        if request_pair.base == request_pair.comparison {
+            return Err(Error {
+                message: format!("'{}' compares a currency with itself!", name),
+                ..Default::default()
+            });
+        }
+        let is_duplicate = watched_pairs.iter().chain(pairs.iter()).any(|p| {
+            p.comparison == request_pair.comparison
+                && (follows_reference || p.base == request_pair.base)
+        });
+        if is_duplicate {
+            return Err(Error {
+                message: format!("'{}' is already in the watchlist!", name),
+                ..Default::default()
+            });
+        }
+        let maybe_rate = converter.get_rate(&request_pair.base, &request_pair.comparison);
+        if maybe_rate.is_err() {
+            return Err(Error {
+                message: format!("Could not find a rate for '{}'!", name),
+                ..Default::default()
+            });
+        }
+        let positions: Vec<u32> = watched_pairs
+            .iter()
+            .chain(pairs.iter())
+            .map(|p| p.position)
+            .collect();
+        pairs.push(Pair {
+            id: Uuid::new_v4().to_string(),
+            base: request_pair.base.clone(),
+            comparison: request_pair.comparison.clone(),
+            position: get_next_position(&positions),
+            value: maybe_rate.unwrap().value,
+            created_at: Utc::now().to_rfc3339(),
+            updated_at: Utc::now().to_rfc3339(),
+        });
+    }
+    return Ok(pairs);
+}
+
 #[cfg(test)]
 mod test {
-    // TODO: write tests for this `impl``
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    use super::*;
+
+    fn create_pair(base: &str, comparison: &str, value: &str) -> Pair {
+        return Pair {
+            id: format!("{}{}", base, comparison).to_lowercase(),
+            value: Decimal::from_str(value).unwrap(),
+            base: base.to_string(),
+            comparison: comparison.to_string(),
+            position: 0,
+            created_at: String::from("2024-01-01T00:00:00+00:00"),
+            updated_at: String::from("2024-01-01T00:00:00+00:00"),
+        };
+    }
+
+    fn create_request_pair(base: &str, comparison: &str) -> RequestPair {
+        return RequestPair {
+            base: base.to_string(),
+            comparison: comparison.to_string(),
+        };
+    }
+
+    #[test]
+    fn test_create_pairs() {
+        /*
+            Unit test expectations:
+
+            - Cross pairs are valued through the pivot currency.
+            - A cross pair may share its comparison with another one of a different base.
+            - Duplicates, either watched or repeated in the request, are rejected.
+            - Pairs following the reference currency are duplicates by comparison alone.
+            - Unknown currencies and pairs of a currency with itself are rejected.
+        */
+        let usd_pairs = vec![
+            create_pair("USD", "EUR", "0.5"),
+            create_pair("USD", "BTC", "0.0001"),
+            create_pair("USD", "ETH", "0.001"),
+        ];
+        let converter = CurrencyConverter::new(&usd_pairs, DEFAULT_PIVOT).unwrap();
+        let watched_pairs = vec![create_pair("EUR", "BTC", "0.0002")];
+
+        let pairs = create_pairs(
+            &converter,
+            &watched_pairs,
+            &vec![create_request_pair("ETH", "BTC")],
+            false,
+        )
+        .unwrap();
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].value, Decimal::from_str("0.1").unwrap());
+
+        assert!(create_pairs(
+            &converter,
+            &watched_pairs,
+            &vec![create_request_pair("EUR", "BTC")],
+            false,
+        )
+        .is_err());
+        assert!(create_pairs(
+            &converter,
+            &vec![],
+            &vec![
+                create_request_pair("ETH", "BTC"),
+                create_request_pair("ETH", "BTC")
+            ],
+            false,
+        )
+        .is_err());
+        assert!(create_pairs(
+            &converter,
+            &watched_pairs,
+            &vec![create_request_pair("USD", "BTC")],
+            true,
+        )
+        .is_err());
+        assert!(create_pairs(
+            &converter,
+            &vec![],
+            &vec![create_request_pair("ETH", "XYZ")],
+            false,
+        )
+        .is_err());
+        assert!(create_pairs(
+            &converter,
+            &vec![],
+            &vec![create_request_pair("ETH", "ETH")],
+            false,
+        )
+        .is_err());
+    }
 }
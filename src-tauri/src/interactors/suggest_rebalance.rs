@@ -0,0 +1,329 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    entities::{
+        rate_snapshot::RateSnapshot,
+        tag::{Tag, TargetWeight},
+    },
+    utilities::{
+        currency_converter::{CurrencyConverter, DEFAULT_PIVOT},
+        rounding_policy::round_amount,
+    },
+    Error,
+};
+
+use super::interactor::Interactor;
+
+pub trait SuggestRebalanceDataAccess {
+    async fn find_tag(&mut self, id: &str) -> Result<Option<Tag>, Error>;
+    async fn find_rate_snapshot(&mut self) -> Result<Option<RateSnapshot>, Error>;
+}
+
+/*
+    NOTE: `threshold` is the allocation drift, from 0 to 1, a coin may have before
+    a trade is suggested, and `min_trade_value` is in the reference currency
+*/
+#[derive(Clone, Debug, Deserialize)]
+pub struct SuggestRebalanceRequest {
+    pub tag_id: String,
+    pub threshold: Decimal,
+    pub min_trade_value: Decimal,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseSuggestion {
+    pub coin: String,
+    pub action: String,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub quantity: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub value: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub current_value: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub current_allocation: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub target_allocation: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub drift: Decimal,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct SuggestRebalanceResponse {
+    pub tag_id: String,
+    pub reference_currency: String,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub total_value: Decimal,
+    pub is_balanced: bool,
+    pub suggestions: Vec<ResponseSuggestion>,
+}
+
+/*
+    NOTE: the tag assets are valued with the `USD` values and rates recorded the
+    last time the portfolios were viewed, so no coin market request is needed
+*/
+pub struct SuggestRebalance<DA> {
+    pub data_access: DA,
+    pub reference_currency: String,
+}
+
+struct Holding {
+    coin: String,
+    quantity: Decimal,
+    unit_value: Decimal,
+}
+
+impl<DA> Interactor<SuggestRebalanceRequest, SuggestRebalanceResponse> for SuggestRebalance<DA>
+where
+    DA: SuggestRebalanceDataAccess,
+{
+    async fn perform(
+        &mut self,
+        request: SuggestRebalanceRequest,
+    ) -> Result<SuggestRebalanceResponse, Error> {
+        if request.threshold < Decimal::ZERO || request.threshold > Decimal::ONE {
+            return Err(Error {
+                message: String::from("The rebalance threshold must be between 0 and 1!"),
+                ..Default::default()
+            });
+        }
+        if request.min_trade_value < Decimal::ZERO {
+            return Err(Error {
+                message: String::from("The minimum trade value must not be negative!"),
+                ..Default::default()
+            });
+        }
+        let maybe_tag = self.data_access.find_tag(&request.tag_id).await?;
+        if maybe_tag.is_none() {
+            return Err(Error {
+                message: String::from("Tag to rebalance does not exist!"),
+                ..Default::default()
+            });
+        }
+        let tag = maybe_tag.unwrap();
+        if tag.targets.len() == 0 {
+            return Err(Error {
+                message: String::from("Tag to rebalance has no target weights!"),
+                ..Default::default()
+            });
+        }
+        let maybe_rate_snapshot = self.data_access.find_rate_snapshot().await?;
+        if maybe_rate_snapshot.is_none() {
+            return Err(Error {
+                message: String::from("The portfolios must be viewed once before rebalancing!"),
+                ..Default::default()
+            });
+        }
+        let converter = CurrencyConverter::new(&maybe_rate_snapshot.unwrap().pairs, DEFAULT_PIVOT)?;
+        let holdings = create_holdings(&converter, &self.reference_currency, &tag)?;
+        let suggestions = create_suggestions(
+            &holdings,
+            &tag.targets,
+            request.threshold,
+            request.min_trade_value,
+            &self.reference_currency,
+        );
+        return Ok(SuggestRebalanceResponse {
+            tag_id: tag.id.clone(),
+            reference_currency: self.reference_currency.clone(),
+            total_value: round_amount(get_total_value(&holdings), &self.reference_currency),
+            is_balanced: suggestions.iter().all(|s| s.action == "hold"),
+            suggestions,
+        });
+    }
+}
+
+/*
+    NOTE: assets of the same coin are merged, and target coins the tag does not
+    hold yet are valued with the recorded rates so they can be bought
+*/
+fn create_holdings(
+    converter: &CurrencyConverter,
+    reference_currency: &str,
+    tag: &Tag,
+) -> Result<Vec<Holding>, Error> {
+    let reference_rate = converter.get_rate("USD", reference_currency)?.value;
+    let mut holdings: Vec<Holding> = vec![];
+    for asset in &tag.assets {
+        let maybe_holding = holdings.iter_mut().find(|h| h.coin == asset.coin);
+        if maybe_holding.is_none() {
+            holdings.push(Holding {
+                coin: asset.coin.clone(),
+                quantity: asset.quantity,
+                unit_value: asset.usd_value * reference_rate,
+            });
+            continue;
+        }
+        let holding = maybe_holding.unwrap();
+        let value = holding.quantity * holding.unit_value
+            + asset.quantity * asset.usd_value * reference_rate;
+        holding.quantity += asset.quantity;
+        holding.unit_value = value
+            .checked_div(holding.quantity)
+            .unwrap_or(holding.unit_value);
+    }
+    for target in &tag.targets {
+        if holdings.iter().any(|h| h.coin == target.coin) {
+            continue;
+        }
+        holdings.push(Holding {
+            coin: target.coin.clone(),
+            quantity: Decimal::ZERO,
+            unit_value: converter.get_rate(&target.coin, reference_currency)?.value,
+        });
+    }
+    return Ok(holdings);
+}
+
+/*
+    Compares the allocation of each coin with its target, coins without a target
+    having a target of 0 so they are sold off. A trade is only suggested when the
+    drift reaches the threshold and the trade is worth at least the minimum value.
+*/
+fn create_suggestions(
+    holdings: &Vec<Holding>,
+    targets: &Vec<TargetWeight>,
+    threshold: Decimal,
+    min_trade_value: Decimal,
+    reference_currency: &str,
+) -> Vec<ResponseSuggestion> {
+    let total_value = get_total_value(holdings);
+    let mut suggestions: Vec<ResponseSuggestion> = vec![];
+    for holding in holdings {
+        let current_value = holding.quantity * holding.unit_value;
+        let current_allocation = current_value.checked_div(total_value).unwrap_or_default();
+        let target_allocation = targets
+            .iter()
+            .find(|t| t.coin == holding.coin)
+            .map(|t| t.weight)
+            .unwrap_or_default();
+        let drift = current_allocation - target_allocation;
+        let difference = total_value * target_allocation - current_value;
+
+        let mut action = "hold";
+        let mut quantity = Decimal::ZERO;
+        let mut value = Decimal::ZERO;
+        if total_value > Decimal::ZERO
+            && difference != Decimal::ZERO
+            && drift.abs() >= threshold
+            && difference.abs() >= min_trade_value
+        {
+            action = if difference > Decimal::ZERO {
+                "buy"
+            } else {
+                "sell"
+            };
+            value = round_amount(difference.abs(), reference_currency);
+            quantity = round_amount(
+                difference
+                    .abs()
+                    .checked_div(holding.unit_value)
+                    .unwrap_or_default(),
+                &holding.coin,
+            );
+            if action == "sell" {
+                quantity = quantity.min(holding.quantity);
+            }
+        }
+        suggestions.push(ResponseSuggestion {
+            coin: holding.coin.clone(),
+            action: action.to_string(),
+            quantity,
+            value,
+            current_value: round_amount(current_value, reference_currency),
+            current_allocation,
+            target_allocation,
+            drift,
+        });
+    }
+    return suggestions;
+}
+
+fn get_total_value(holdings: &Vec<Holding>) -> Decimal {
+    return holdings
+        .iter()
+        .map(|h| h.quantity * h.unit_value)
+        .sum::<Decimal>();
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn create_holding(coin: &str, quantity: &str, unit_value: i64) -> Holding {
+        return Holding {
+            coin: coin.to_string(),
+            quantity: Decimal::from_str(quantity).unwrap(),
+            unit_value: Decimal::from(unit_value),
+        };
+    }
+
+    fn create_target(coin: &str, weight: &str) -> TargetWeight {
+        return TargetWeight {
+            coin: coin.to_string(),
+            weight: Decimal::from_str(weight).unwrap(),
+        };
+    }
+
+    #[test]
+    fn test_create_suggestions() {
+        /*
+            Unit test expectations:
+
+            - Overweight coins are sold and underweight coins are bought back to their target.
+            - Coins without a target are sold off.
+            - Drifts below the threshold and trades below the minimum value are held.
+        */
+        let holdings = vec![
+            create_holding("BTC", "0.2", 50000),
+            create_holding("ETH", "1", 1000),
+            create_holding("DOGE", "1000", 1),
+            create_holding("SOL", "0", 100),
+        ];
+        let targets = vec![
+            create_target("BTC", "0.6"),
+            create_target("ETH", "0.1"),
+            create_target("SOL", "0.3"),
+        ];
+        let suggestions = create_suggestions(
+            &holdings,
+            &targets,
+            Decimal::from_str("0.05").unwrap(),
+            Decimal::from(50),
+            "USD",
+        );
+        assert_eq!(suggestions.len(), 4);
+        assert_eq!(suggestions[0].action, "sell");
+        assert_eq!(suggestions[0].value, Decimal::from(2800));
+        assert_eq!(suggestions[0].quantity, Decimal::from_str("0.056").unwrap());
+        assert_eq!(suggestions[1].action, "hold");
+        assert_eq!(suggestions[1].quantity, Decimal::ZERO);
+        assert_eq!(suggestions[2].action, "sell");
+        assert_eq!(suggestions[2].quantity, Decimal::from(1000));
+        assert_eq!(suggestions[3].action, "buy");
+        assert_eq!(suggestions[3].value, Decimal::from(3600));
+        assert_eq!(suggestions[3].quantity, Decimal::from(36));
+
+        let suggestions = create_suggestions(
+            &holdings,
+            &targets,
+            Decimal::ZERO,
+            Decimal::from(500),
+            "USD",
+        );
+        assert_eq!(suggestions[0].action, "sell");
+        assert_eq!(suggestions[1].action, "hold");
+
+        let suggestions = create_suggestions(
+            &holdings,
+            &targets,
+            Decimal::from_str("0.5").unwrap(),
+            Decimal::ZERO,
+            "USD",
+        );
+        assert!(suggestions.iter().all(|s| s.action == "hold"));
+    }
+}
@@ -1,4 +1,5 @@
 use chrono::Utc;
+use rust_decimal::Decimal;
 use serde::Deserialize;
 use uuid::Uuid;
 
@@ -21,7 +22,7 @@ pub trait UpdatePairGroupDataAccess {
 #[derive(Clone, Debug, Deserialize)]
 pub struct RequestPair {
     pub id: String,
-    pub value: f64,
+    pub value: Decimal,
     pub base: String,
     pub comparison: String,
 }
@@ -39,7 +40,7 @@ impl PartialEq for RequestPair {
 pub struct RequestPairGroup {
     pub id: String,
     pub is_pinned: bool,
-    pub multiplier: f64,
+    pub multiplier: Decimal,
     pub pairs: Vec<RequestPair>,
 }
 
@@ -74,6 +75,7 @@ where
         if maybe_pair_group.is_none() {
             return Err(Error {
                 message: String::from("Pair group to update does not exist!"),
+                ..Default::default()
             });
         }
         let pair_group = maybe_pair_group.unwrap();
@@ -81,6 +83,7 @@ where
             id: request.pair_group.id,
             is_pinned: request.pair_group.is_pinned,
             multiplier: request.pair_group.multiplier,
+            position: pair_group.position,
             pairs: vec![],
             created_at: pair_group.created_at.clone(),
             updated_at: Utc::now().to_rfc3339(),
@@ -90,7 +93,7 @@ where
         let mut updated_pairs: Vec<Pair> = vec![];
         let mut removed_pairs: Vec<Pair> = vec![];
 
-        for request_pair in &request.pair_group.pairs {
+        for (position, request_pair) in request.pair_group.pairs.iter().enumerate() {
             let maybe_pair = self.data_access.find_pair(&request_pair.id).await?;
             if maybe_pair.is_none() {
                 let pair = Pair {
@@ -98,6 +101,7 @@ where
                     base: request_pair.base.clone(),
                     value: request_pair.value.clone(),
                     comparison: request_pair.comparison.clone(),
+                    position: position as u32,
                     created_at: Utc::now().to_rfc3339(),
                     updated_at: Utc::now().to_rfc3339(),
                 };
@@ -109,6 +113,7 @@ where
             if !pair_group.pairs.contains(&pair) {
                 return Err(Error {
                     message: String::from("Cannot borrow pairs from other pair groups!"),
+                    ..Default::default()
                 });
             }
             let updated_pair = Pair {
@@ -116,6 +121,7 @@ where
                 base: request_pair.base.clone(),
                 value: request_pair.value.clone(),
                 comparison: request_pair.comparison.clone(),
+                position: position as u32,
                 created_at: pair_group.created_at.clone(),
                 updated_at: Utc::now().to_rfc3339(),
             };
@@ -158,6 +164,7 @@ fn validate_request(request: &UpdatePairGroupRequest) -> Result<(), Error> {
     if pairs_len == 0 {
         return Err(Error {
             message: String::from("Cannot update a pair group that does not have pairs!"),
+            ..Default::default()
         });
     }
     if pairs_len > 1 {
@@ -177,6 +184,7 @@ fn validate_request_pair_bases(request: &UpdatePairGroupRequest) -> Result<(), E
                 message: String::from(
                     "Cannot update a pair group that contains pairs with different bases!",
                 ),
+                ..Default::default()
             });
         }
     }
@@ -194,6 +202,7 @@ fn validate_request_duplicate_pairs(request: &UpdatePairGroupRequest) -> Result<
                     message: String::from(
                         "Cannot update a pair group that contains duplicate pairs!",
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -1,8 +1,20 @@
 use chrono::Utc;
+use rust_decimal::Decimal;
 use serde::Deserialize;
 
 use crate::{
-    entities::{asset::Asset, tag::Tag},
+    entities::{asset::Asset, tag::Tag, transaction::Transaction},
+    error::FieldError,
+    utilities::{
+        coin_market::CoinMarket,
+        currency_converter::{CurrencyConverter, DEFAULT_PIVOT},
+        ledger::{
+            create_adjustment_transaction, create_missing_opening_transactions, get_balance,
+            is_transaction_of,
+        },
+        portfolio_validation::{create_validation_error, validate_asset},
+        rounding_policy::round_amount,
+    },
     Error,
 };
 
@@ -14,13 +26,16 @@ pub trait UpdatePortfolioDataAccess {
     async fn update_tag(&mut self, tag: &Tag) -> Result<(), Error>;
     async fn find_asset(&mut self, id: &str) -> Result<Option<Asset>, Error>;
     async fn update_asset(&mut self, asset: &Asset) -> Result<(), Error>;
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error>;
+    async fn save_transaction(&mut self, transaction: &Transaction) -> Result<(), Error>;
 }
 
+// NOTE: a `null` quantity is one the frontend could not parse
 #[derive(Clone, Debug, Deserialize)]
 pub struct RequestAsset {
     pub id: String,
     pub coin: String,
-    pub quantity: f64,
+    pub quantity: Option<Decimal>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -29,44 +44,81 @@ pub struct UpdatePortfolioRequest {
     pub asset: RequestAsset,
 }
 
-// TODO: should it be called `UpdateAsset` instead?
-pub struct UpdatePortfolio<DA> {
+/*
+    TODO: should it be called `UpdateAsset` instead?
+
+    NOTE: the `USD` value is refreshed from the coin market whenever the coin or
+    the quantity changes, so it never belongs to the previous coin
+*/
+pub struct UpdatePortfolio<DA, CM> {
     pub data_access: DA,
+    pub coin_market: CM,
 }
 
-impl<DA> Interactor<UpdatePortfolioRequest, ()> for UpdatePortfolio<DA>
+impl<DA, CM> Interactor<UpdatePortfolioRequest, ()> for UpdatePortfolio<DA, CM>
 where
     DA: UpdatePortfolioDataAccess,
+    CM: CoinMarket,
 {
     async fn perform(&mut self, request: UpdatePortfolioRequest) -> Result<(), Error> {
         let maybe_asset = self.data_access.find_asset(&request.asset.id).await?;
         if maybe_asset.is_none() {
             return Err(Error {
                 message: String::from("Asset does not exist!"),
+                ..Default::default()
             });
         }
         let asset = maybe_asset.unwrap();
-        let updated_asset = Asset {
-            id: asset.id.clone(),
-            coin: request.asset.coin.clone(),
-            quantity: request.asset.quantity.clone(),
-            usd_value: asset.usd_value.clone(),
-            created_at: asset.created_at.clone(),
-            updated_at: Utc::now().to_rfc3339(),
-        };
-        self.data_access.update_asset(&updated_asset).await?;
-
+        let usd_pairs = self.coin_market.fetch_usd_pairs().await?;
+        let converter = CurrencyConverter::new(&usd_pairs, DEFAULT_PIVOT)?;
+        let mut field_errors = validate_asset(
+            "asset",
+            &request.asset.coin,
+            &request.asset.quantity,
+            &converter,
+        );
         let mut request_tags: Vec<Tag> = vec![];
-        for tag_id in &request.tag_ids {
+        for (idx, tag_id) in request.tag_ids.iter().enumerate() {
             let maybe_tag = self.data_access.find_tag(tag_id).await?;
             if maybe_tag.is_none() {
-                return Err(Error {
-                    message: String::from("One of the tags was not found!"),
+                field_errors.push(FieldError {
+                    field: format!("tag_ids[{}]", idx),
+                    message: String::from("Tag not found!"),
                 });
+                continue;
             }
             let tag = maybe_tag.unwrap();
             request_tags.push(tag);
         }
+        if request.asset.coin != asset.coin
+            && has_transactions(&mut self.data_access, &asset.id).await?
+        {
+            field_errors.push(FieldError {
+                field: String::from("asset.coin"),
+                message: String::from(
+                    "The coin of an asset with transactions cannot be changed! Create a new asset and transfer or withdraw the quantity instead.",
+                ),
+            });
+        }
+        create_validation_error(&field_errors)?;
+
+        let mut updated_asset = Asset {
+            id: asset.id.clone(),
+            coin: request.asset.coin.clone(),
+            quantity: round_amount(request.asset.quantity.unwrap(), &request.asset.coin),
+            usd_value: asset.usd_value.clone(),
+            account_id: asset.account_id.clone(),
+            created_at: asset.created_at.clone(),
+            updated_at: Utc::now().to_rfc3339(),
+        };
+        if updated_asset.coin != asset.coin || updated_asset.quantity != asset.quantity {
+            updated_asset.usd_value = converter.get_rate(&updated_asset.coin, "USD")?.value;
+        }
+        if updated_asset.quantity != asset.quantity {
+            updated_asset.quantity =
+                record_adjustment(&mut self.data_access, &asset, &updated_asset).await?;
+        }
+        self.data_access.update_asset(&updated_asset).await?;
 
         let mut updated_tags: Vec<Tag> = vec![];
         let current_tags = self.data_access.retrieve_tags_by_asset(&asset.id).await?;
@@ -110,6 +162,46 @@ where
     }
 }
 
+/*
+    NOTE: the transactions of the asset are quantities of its coin, so changing the
+    coin would reinterpret them
+*/
+async fn has_transactions(
+    data_access: &mut impl UpdatePortfolioDataAccess,
+    asset_id: &str,
+) -> Result<bool, Error> {
+    let transactions = data_access.fetch_transactions().await?;
+    return Ok(transactions.iter().any(|t| is_transaction_of(t, asset_id)));
+}
+
+/*
+    NOTE: the quantity is derived from the ledger, so editing it directly records
+    the difference as a deposit or a withdrawal
+*/
+async fn record_adjustment(
+    data_access: &mut impl UpdatePortfolioDataAccess,
+    asset: &Asset,
+    updated_asset: &Asset,
+) -> Result<Decimal, Error> {
+    let mut transactions = data_access.fetch_transactions().await?;
+    let opening_transactions =
+        create_missing_opening_transactions(&vec![asset.clone()], &transactions);
+    transactions.extend(opening_transactions.clone());
+    let adjustment = create_adjustment_transaction(
+        &asset.id,
+        updated_asset.quantity - asset.quantity,
+        "Manual adjustment",
+    );
+    transactions.push(adjustment.clone());
+    let balance = get_balance(updated_asset, &transactions)?;
+
+    for opening_transaction in &opening_transactions {
+        data_access.save_transaction(opening_transaction).await?;
+    }
+    data_access.save_transaction(&adjustment).await?;
+    return Ok(balance);
+}
+
 #[cfg(test)]
 mod test {
     // TODO: write tests for this `impl``
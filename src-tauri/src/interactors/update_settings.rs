@@ -0,0 +1,392 @@
+use std::path::Path;
+
+use chrono::Utc;
+use serde::Deserialize;
+
+use crate::{
+    entities::{
+        network_settings::{NetworkSettings, ProxySettings},
+        settings::Settings,
+        watchlist::Watchlist,
+    },
+    utilities::{
+        coin_market::CoinMarket,
+        cost_basis::parse_cost_basis_method,
+        currency_converter::{CurrencyConverter, DEFAULT_PIVOT},
+        settings_notifier::SettingsNotifier,
+    },
+    Error,
+};
+
+use super::interactor::Interactor;
+
+const MIN_REFRESH_INTERVAL: u64 = 60;
+const MAX_REFRESH_INTERVAL: u64 = 60 * 60 * 24;
+const MAX_CURRENCY_ID_LENGTH: usize = 64;
+
+pub trait UpdateSettingsDataAccess {
+    async fn fetch_settings(&mut self) -> Result<Settings, Error>;
+    async fn update_settings(&mut self, settings: &Settings) -> Result<(), Error>;
+    // NOTE: looks in `data_location`, or the default location when it is `None`
+    async fn find_watchlist(
+        &mut self,
+        data_location: &Option<String>,
+        id: &str,
+    ) -> Result<Option<Watchlist>, Error>;
+}
+
+/*
+    NOTE: a missing `password` keeps the stored one as long as the username does
+    not change, an empty one removes it
+*/
+#[derive(Clone, Debug, Deserialize)]
+pub struct RequestProxySettings {
+    pub url: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub no_proxy: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RequestNetworkSettings {
+    pub proxy: Option<RequestProxySettings>,
+    pub extra_ca_certificates: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateSettingsRequest {
+    pub fiat_rates_url: String,
+    pub crypto_rates_url: String,
+    pub reference_currency: String,
+    pub refresh_interval: u64,
+    pub data_location: Option<String>,
+    pub network: RequestNetworkSettings,
+    pub cost_basis_method: String,
+    pub default_watchlist_id: Option<String>,
+}
+
+pub struct UpdateSettings<DA, CM, SN> {
+    pub data_access: DA,
+    pub coin_market: CM,
+    pub settings_notifier: SN,
+}
+
+impl<DA, CM, SN> Interactor<UpdateSettingsRequest, ()> for UpdateSettings<DA, CM, SN>
+where
+    DA: UpdateSettingsDataAccess,
+    CM: CoinMarket,
+    SN: SettingsNotifier,
+{
+    async fn perform(&mut self, request: UpdateSettingsRequest) -> Result<(), Error> {
+        let current_settings = self.data_access.fetch_settings().await?;
+        let updated_settings = create_updated_settings(&current_settings, &request)?;
+        validate_settings(&updated_settings)?;
+        /*
+            NOTE: the market is only fetched when the reference currency changes, so
+            that fixing the feed URLs or the proxy does not depend on the current ones
+        */
+        if updated_settings.reference_currency != current_settings.reference_currency {
+            let usd_pairs = self.coin_market.fetch_usd_pairs().await?;
+            let converter = CurrencyConverter::new(&usd_pairs, DEFAULT_PIVOT)?;
+            validate_reference_currency(&converter, &updated_settings.reference_currency)?;
+        }
+        /*
+            NOTE: the watchlists live in the data location, so the default one is
+            looked up where it will be read from once the settings are saved
+        */
+        if let Some(default_watchlist_id) = &updated_settings.default_watchlist_id {
+            let maybe_watchlist = self
+                .data_access
+                .find_watchlist(&updated_settings.data_location, default_watchlist_id)
+                .await?;
+            if maybe_watchlist.is_none() {
+                return Err(Error {
+                    message: String::from("The default watchlist does not exist!"),
+                    ..Default::default()
+                });
+            }
+        }
+        self.data_access.update_settings(&updated_settings).await?;
+        self.settings_notifier.notify(&updated_settings);
+        return Ok(());
+    }
+}
+
+fn create_updated_settings(
+    current_settings: &Settings,
+    request: &UpdateSettingsRequest,
+) -> Result<Settings, Error> {
+    let proxy = request.network.proxy.as_ref().map(|p| {
+        let username = p
+            .username
+            .as_ref()
+            .map(|u| u.trim().to_string())
+            .filter(|u| u.len() > 0);
+        let mut password = p.password.clone();
+        if password.is_none() {
+            if let Some(current_proxy) = &current_settings.network.proxy {
+                if current_proxy.username == username {
+                    password = current_proxy.password.clone();
+                }
+            }
+        }
+        return ProxySettings {
+            url: p.url.trim().to_string(),
+            username,
+            password: password.filter(|p| p.len() > 0),
+            no_proxy: get_non_empty_values(&p.no_proxy),
+        };
+    });
+    return Ok(Settings {
+        fiat_rates_url: request.fiat_rates_url.trim().to_string(),
+        crypto_rates_url: request.crypto_rates_url.trim().to_string(),
+        reference_currency: request.reference_currency.trim().to_string(),
+        refresh_interval: request.refresh_interval,
+        data_location: request
+            .data_location
+            .as_ref()
+            .map(|l| l.trim().to_string())
+            .filter(|l| l.len() > 0),
+        network: NetworkSettings {
+            proxy,
+            extra_ca_certificates: get_non_empty_values(&request.network.extra_ca_certificates),
+        },
+        cost_basis_method: parse_cost_basis_method(&request.cost_basis_method)?,
+        default_watchlist_id: request
+            .default_watchlist_id
+            .as_ref()
+            .map(|w| w.trim().to_string())
+            .filter(|w| w.len() > 0),
+        updated_at: Utc::now().to_rfc3339(),
+    });
+}
+
+fn get_non_empty_values(values: &Vec<String>) -> Vec<String> {
+    return values
+        .iter()
+        .map(|v| v.trim().to_string())
+        .filter(|v| v.len() > 0)
+        .collect();
+}
+
+fn validate_settings(settings: &Settings) -> Result<(), Error> {
+    if !has_url_scheme(&settings.fiat_rates_url, &["http", "https"]) {
+        return Err(Error {
+            message: String::from("The fiat rates URL must be an HTTP(S) URL!"),
+            ..Default::default()
+        });
+    }
+    if !has_url_scheme(&settings.crypto_rates_url, &["http", "https"]) {
+        return Err(Error {
+            message: String::from("The crypto rates URL must be an HTTP(S) URL!"),
+            ..Default::default()
+        });
+    }
+    if settings.reference_currency.len() == 0
+        || settings.reference_currency.len() > MAX_CURRENCY_ID_LENGTH
+        || settings.reference_currency.contains(char::is_whitespace)
+    {
+        return Err(Error {
+            message: String::from("The reference currency is not a valid currency id!"),
+            ..Default::default()
+        });
+    }
+    if settings.refresh_interval < MIN_REFRESH_INTERVAL
+        || settings.refresh_interval > MAX_REFRESH_INTERVAL
+    {
+        return Err(Error {
+            message: format!(
+                "The refresh interval must be between {} and {} seconds!",
+                MIN_REFRESH_INTERVAL, MAX_REFRESH_INTERVAL
+            ),
+            ..Default::default()
+        });
+    }
+    if let Some(data_location) = &settings.data_location {
+        if !Path::new(data_location).is_absolute() {
+            return Err(Error {
+                message: String::from("The data location must be an absolute path!"),
+                ..Default::default()
+            });
+        }
+    }
+    if let Some(proxy) = &settings.network.proxy {
+        if !has_url_scheme(&proxy.url, &["http", "https", "socks5", "socks5h"]) {
+            return Err(Error {
+                message: String::from("The proxy URL must be an HTTP(S) or SOCKS5 URL!"),
+                ..Default::default()
+            });
+        }
+        if proxy.password.is_some() && proxy.username.is_none() {
+            return Err(Error {
+                message: String::from("The proxy password requires a username!"),
+                ..Default::default()
+            });
+        }
+    }
+    for path in &settings.network.extra_ca_certificates {
+        if !Path::new(path).is_file() {
+            return Err(Error {
+                message: format!("The CA certificate '{}' does not exist!", path),
+                ..Default::default()
+            });
+        }
+    }
+    return Ok(());
+}
+
+fn validate_reference_currency(
+    converter: &CurrencyConverter,
+    reference_currency: &str,
+) -> Result<(), Error> {
+    if !converter.has_currency(reference_currency) {
+        return Err(Error {
+            message: format!(
+                "The reference currency '{}' is not quoted by the rate feeds!",
+                reference_currency
+            ),
+            ..Default::default()
+        });
+    }
+    return Ok(());
+}
+
+fn has_url_scheme(url: &str, schemes: &[&str]) -> bool {
+    let maybe_parts = url.split_once("://");
+    if maybe_parts.is_none() {
+        return false;
+    }
+    let (scheme, rest) = maybe_parts.unwrap();
+    return schemes.contains(&scheme.to_lowercase().as_str())
+        && rest.len() > 0
+        && !rest.starts_with('/')
+        && !rest.contains(char::is_whitespace);
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal::Decimal;
+
+    use crate::{entities::pair::Pair, utilities::default_settings::create_default_settings};
+
+    use super::*;
+
+    fn create_request(current_settings: &Settings) -> UpdateSettingsRequest {
+        return UpdateSettingsRequest {
+            fiat_rates_url: current_settings.fiat_rates_url.clone(),
+            crypto_rates_url: current_settings.crypto_rates_url.clone(),
+            reference_currency: current_settings.reference_currency.clone(),
+            refresh_interval: current_settings.refresh_interval,
+            data_location: None,
+            network: RequestNetworkSettings {
+                proxy: None,
+                extra_ca_certificates: vec![],
+            },
+            cost_basis_method: String::from("fifo"),
+            default_watchlist_id: None,
+        };
+    }
+
+    #[test]
+    fn test_validate_settings() {
+        /*
+            Unit test expectations:
+
+            - The default settings are valid.
+            - Invalid URLs, intervals, paths and proxy credentials are rejected.
+            - Unknown cost basis methods are rejected.
+        */
+        let current_settings = create_default_settings();
+        let request = create_request(&current_settings);
+        let settings = create_updated_settings(&current_settings, &request).unwrap();
+        assert!(validate_settings(&settings).is_ok());
+
+        let mut request = create_request(&current_settings);
+        request.fiat_rates_url = String::from("ftp://rates.example/fiat.json");
+        let settings = create_updated_settings(&current_settings, &request).unwrap();
+        assert!(validate_settings(&settings).is_err());
+
+        let mut request = create_request(&current_settings);
+        request.refresh_interval = 1;
+        let settings = create_updated_settings(&current_settings, &request).unwrap();
+        assert!(validate_settings(&settings).is_err());
+
+        let mut request = create_request(&current_settings);
+        request.data_location = Some(String::from("relative/data"));
+        let settings = create_updated_settings(&current_settings, &request).unwrap();
+        assert!(validate_settings(&settings).is_err());
+
+        let mut request = create_request(&current_settings);
+        request.network.proxy = Some(RequestProxySettings {
+            url: String::from("socks5://proxy.example:1080"),
+            username: None,
+            password: Some(String::from("secret")),
+            no_proxy: vec![],
+        });
+        let settings = create_updated_settings(&current_settings, &request).unwrap();
+        assert!(validate_settings(&settings).is_err());
+        let mut request = create_request(&current_settings);
+        request.cost_basis_method = String::from("hifo");
+        assert!(create_updated_settings(&current_settings, &request).is_err());
+    }
+
+    #[test]
+    fn test_validate_reference_currency() {
+        /*
+            Unit test expectations:
+
+            - Currencies quoted by the rate feeds, and the pivot itself, are accepted.
+            - Currencies missing from the rate feeds are rejected.
+        */
+        let pairs = vec![Pair {
+            id: String::from("eur"),
+            value: Decimal::from(2),
+            base: String::from("USD"),
+            comparison: String::from("EUR"),
+            position: 0,
+            created_at: String::from("2024-01-01T00:00:00+00:00"),
+            updated_at: String::from("2024-01-01T00:00:00+00:00"),
+        }];
+        let converter = CurrencyConverter::new(&pairs, DEFAULT_PIVOT).unwrap();
+        assert!(validate_reference_currency(&converter, "EUR").is_ok());
+        assert!(validate_reference_currency(&converter, "USD").is_ok());
+        assert!(validate_reference_currency(&converter, "XYZ").is_err());
+    }
+
+    #[test]
+    fn test_keep_proxy_password() {
+        /*
+            Unit test expectations:
+
+            - A missing password keeps the stored one while the username is unchanged.
+            - Changing the username or sending an empty password drops it.
+        */
+        let mut current_settings = create_default_settings();
+        current_settings.network.proxy = Some(ProxySettings {
+            url: String::from("http://proxy.example:3128"),
+            username: Some(String::from("user")),
+            password: Some(String::from("secret")),
+            no_proxy: vec![],
+        });
+        let mut request = create_request(&current_settings);
+        request.network.proxy = Some(RequestProxySettings {
+            url: String::from("http://proxy.example:3128"),
+            username: Some(String::from("user")),
+            password: None,
+            no_proxy: vec![String::from(" localhost "), String::from("")],
+        });
+        let settings = create_updated_settings(&current_settings, &request).unwrap();
+        let proxy = settings.network.proxy.unwrap();
+        assert_eq!(proxy.password, Some(String::from("secret")));
+        assert_eq!(proxy.no_proxy, vec!["localhost"]);
+
+        request.network.proxy.as_mut().unwrap().password = Some(String::new());
+        let settings = create_updated_settings(&current_settings, &request).unwrap();
+        assert_eq!(settings.network.proxy.unwrap().password, None);
+
+        request.network.proxy.as_mut().unwrap().password = None;
+        request.network.proxy.as_mut().unwrap().username = Some(String::from("other"));
+        let settings = create_updated_settings(&current_settings, &request).unwrap();
+        assert_eq!(settings.network.proxy.unwrap().password, None);
+    }
+}
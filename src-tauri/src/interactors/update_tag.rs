@@ -0,0 +1,81 @@
+use chrono::Utc;
+use serde::Deserialize;
+
+use crate::{
+    entities::tag::Tag,
+    utilities::tag_hierarchy::{validate_color, validate_parent, validate_tag_name},
+    Error,
+};
+
+use super::interactor::Interactor;
+
+pub trait UpdateTagDataAccess {
+    async fn fetch_tags(&mut self) -> Result<Vec<Tag>, Error>;
+    async fn update_tag(&mut self, tag: &Tag) -> Result<(), Error>;
+}
+
+// NOTE: every field is replaced, so a `None` color or parent removes it
+#[derive(Clone, Debug, Deserialize)]
+pub struct RequestTag {
+    pub id: String,
+    pub name: String,
+    pub color: Option<String>,
+    pub description: String,
+    pub parent_id: Option<String>,
+}
+
+impl PartialEq for RequestTag {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.name == other.name
+            && self.color == other.color
+            && self.description == other.description
+            && self.parent_id == other.parent_id;
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateTagRequest {
+    pub tag: RequestTag,
+}
+
+pub struct UpdateTag<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<UpdateTagRequest, ()> for UpdateTag<DA>
+where
+    DA: UpdateTagDataAccess,
+{
+    async fn perform(&mut self, request: UpdateTagRequest) -> Result<(), Error> {
+        validate_color(&request.tag.color)?;
+        let tags = self.data_access.fetch_tags().await?;
+        let maybe_tag = tags.iter().find(|t| t.id == request.tag.id);
+        if maybe_tag.is_none() {
+            return Err(Error {
+                message: String::from("Tag to update does not exist!"),
+                ..Default::default()
+            });
+        }
+        validate_parent(&tags, &request.tag.id, &request.tag.parent_id)?;
+        validate_tag_name(
+            &tags,
+            &request.tag.id,
+            &request.tag.name,
+            &request.tag.parent_id,
+        )?;
+        let mut tag = maybe_tag.unwrap().clone();
+        tag.name = request.tag.name.trim().to_string();
+        tag.color = request.tag.color.clone();
+        tag.description = request.tag.description.clone();
+        tag.parent_id = request.tag.parent_id.clone();
+        tag.updated_at = Utc::now().to_rfc3339();
+        self.data_access.update_tag(&tag).await?;
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
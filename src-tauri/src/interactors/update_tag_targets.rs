@@ -0,0 +1,118 @@
+use chrono::Utc;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::{
+    entities::tag::{Tag, TargetWeight},
+    Error,
+};
+
+use super::interactor::Interactor;
+
+pub trait UpdateTagTargetsDataAccess {
+    async fn find_tag(&mut self, id: &str) -> Result<Option<Tag>, Error>;
+    async fn update_tag(&mut self, tag: &Tag) -> Result<(), Error>;
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RequestTargetWeight {
+    pub coin: String,
+    pub weight: Decimal,
+}
+
+impl PartialEq for RequestTargetWeight {
+    fn eq(&self, other: &Self) -> bool {
+        return self.coin == other.coin && self.weight == other.weight;
+    }
+}
+
+// NOTE: an empty list of targets removes the targets of the tag
+#[derive(Clone, Debug, Deserialize)]
+pub struct RequestTag {
+    pub id: String,
+    pub targets: Vec<RequestTargetWeight>,
+}
+
+impl PartialEq for RequestTag {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id && self.targets == other.targets;
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateTagTargetsRequest {
+    pub tag: RequestTag,
+}
+
+pub struct UpdateTagTargets<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<UpdateTagTargetsRequest, ()> for UpdateTagTargets<DA>
+where
+    DA: UpdateTagTargetsDataAccess,
+{
+    async fn perform(&mut self, request: UpdateTagTargetsRequest) -> Result<(), Error> {
+        let targets = create_targets(&request.tag.targets)?;
+        let maybe_tag = self.data_access.find_tag(&request.tag.id).await?;
+        if maybe_tag.is_none() {
+            return Err(Error {
+                message: String::from("Tag to update does not exist!"),
+                ..Default::default()
+            });
+        }
+        let mut tag = maybe_tag.unwrap();
+        tag.targets = targets;
+        tag.updated_at = Utc::now().to_rfc3339();
+        self.data_access.update_tag(&tag).await?;
+        return Ok(());
+    }
+}
+
+fn create_targets(request_targets: &Vec<RequestTargetWeight>) -> Result<Vec<TargetWeight>, Error> {
+    let mut targets: Vec<TargetWeight> = vec![];
+    for request_target in request_targets {
+        if request_target.coin.trim().is_empty() {
+            return Err(Error {
+                message: String::from("A target must have a coin!"),
+                ..Default::default()
+            });
+        }
+        if request_target.weight <= Decimal::ZERO || request_target.weight > Decimal::ONE {
+            return Err(Error {
+                message: format!(
+                    "The target weight of '{}' must be above 0 and at most 1!",
+                    request_target.coin
+                ),
+                ..Default::default()
+            });
+        }
+        if targets.iter().any(|t| t.coin == request_target.coin) {
+            return Err(Error {
+                message: format!("'{}' has more than one target!", request_target.coin),
+                ..Default::default()
+            });
+        }
+        targets.push(TargetWeight {
+            coin: request_target.coin.clone(),
+            weight: request_target.weight,
+        });
+    }
+    /*
+        NOTE: weights come from the frontend as floats, so a sum that is only off
+        because of their representation is still accepted
+    */
+    let total_weight = targets.iter().map(|t| t.weight).sum::<Decimal>();
+    if targets.len() > 0 && (total_weight - Decimal::ONE).abs() > Decimal::new(1, 6) {
+        return Err(Error {
+            message: String::from("The target weights must add up to 1!"),
+            ..Default::default()
+        });
+    }
+    return Ok(targets);
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
@@ -0,0 +1,152 @@
+use chrono::Utc;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::{
+    entities::{asset::Asset, transaction::Transaction},
+    utilities::{
+        ledger::{
+            create_balanced_assets, create_missing_opening_transactions, parse_transaction_kind,
+            validate_transaction,
+        },
+        rounding_policy::round_amount,
+    },
+    Error,
+};
+
+use super::interactor::Interactor;
+
+pub trait UpdateTransactionDataAccess {
+    async fn find_asset(&mut self, id: &str) -> Result<Option<Asset>, Error>;
+    async fn update_asset(&mut self, asset: &Asset) -> Result<(), Error>;
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error>;
+    async fn find_transaction(&mut self, id: &str) -> Result<Option<Transaction>, Error>;
+    async fn save_transaction(&mut self, transaction: &Transaction) -> Result<(), Error>;
+    async fn update_transaction(&mut self, transaction: &Transaction) -> Result<(), Error>;
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RequestTransaction {
+    pub id: String,
+    pub kind: String,
+    pub quantity: Decimal,
+    pub price: Option<Decimal>,
+    pub price_currency: Option<String>,
+    pub counterpart_asset_id: Option<String>,
+    pub executed_at: String,
+    pub notes: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateTransactionRequest {
+    pub transaction: RequestTransaction,
+}
+
+pub struct UpdateTransaction<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<UpdateTransactionRequest, ()> for UpdateTransaction<DA>
+where
+    DA: UpdateTransactionDataAccess,
+{
+    async fn perform(&mut self, request: UpdateTransactionRequest) -> Result<(), Error> {
+        let request_transaction = request.transaction;
+        let maybe_transaction = self
+            .data_access
+            .find_transaction(&request_transaction.id)
+            .await?;
+        if maybe_transaction.is_none() {
+            return Err(Error {
+                message: String::from("Transaction does not exist!"),
+                ..Default::default()
+            });
+        }
+        let transaction = maybe_transaction.unwrap();
+        let maybe_asset = self.data_access.find_asset(&transaction.asset_id).await?;
+        if maybe_asset.is_none() {
+            return Err(Error {
+                message: String::from("Asset does not exist!"),
+                ..Default::default()
+            });
+        }
+        let asset = maybe_asset.unwrap();
+
+        /*
+            NOTE: the previous destination of a transfer has to be rebalanced too,
+            since the updated transaction may no longer move anything into it
+        */
+        let mut affected_asset_ids: Vec<String> = vec![];
+        if let Some(counterpart_asset_id) = &transaction.counterpart_asset_id {
+            affected_asset_ids.push(counterpart_asset_id.clone());
+        }
+        if let Some(counterpart_asset_id) = &request_transaction.counterpart_asset_id {
+            if !affected_asset_ids.contains(counterpart_asset_id) {
+                affected_asset_ids.push(counterpart_asset_id.clone());
+            }
+        }
+        let mut affected_assets = vec![asset.clone()];
+        for affected_asset_id in &affected_asset_ids {
+            let maybe_affected_asset = self.data_access.find_asset(affected_asset_id).await?;
+            if maybe_affected_asset.is_none() {
+                if request_transaction.counterpart_asset_id.as_ref() == Some(affected_asset_id) {
+                    return Err(Error {
+                        message: String::from("Destination asset does not exist!"),
+                        ..Default::default()
+                    });
+                }
+                continue;
+            }
+            let affected_asset = maybe_affected_asset.unwrap();
+            if affected_asset.coin != asset.coin {
+                return Err(Error {
+                    message: String::from("Transfers must be between assets of the same coin!"),
+                    ..Default::default()
+                });
+            }
+            affected_assets.push(affected_asset);
+        }
+
+        let updated_transaction = Transaction {
+            id: transaction.id.clone(),
+            asset_id: transaction.asset_id.clone(),
+            kind: parse_transaction_kind(&request_transaction.kind)?,
+            quantity: round_amount(request_transaction.quantity, &asset.coin),
+            price: request_transaction.price,
+            price_currency: request_transaction.price_currency.clone(),
+            counterpart_asset_id: request_transaction.counterpart_asset_id.clone(),
+            executed_at: request_transaction.executed_at.clone(),
+            notes: request_transaction.notes.clone(),
+            created_at: transaction.created_at.clone(),
+            updated_at: Utc::now().to_rfc3339(),
+        };
+        validate_transaction(&updated_transaction)?;
+
+        let mut transactions = self.data_access.fetch_transactions().await?;
+        let opening_transactions =
+            create_missing_opening_transactions(&affected_assets, &transactions);
+        transactions.extend(opening_transactions.clone());
+        if let Some(transaction_idx) = transactions.iter().position(|t| t.id == transaction.id) {
+            transactions[transaction_idx] = updated_transaction.clone();
+        }
+        let balanced_assets = create_balanced_assets(&affected_assets, &transactions)?;
+
+        for opening_transaction in &opening_transactions {
+            self.data_access
+                .save_transaction(opening_transaction)
+                .await?;
+        }
+        self.data_access
+            .update_transaction(&updated_transaction)
+            .await?;
+        for balanced_asset in &balanced_assets {
+            self.data_access.update_asset(balanced_asset).await?;
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
@@ -0,0 +1,61 @@
+use chrono::Utc;
+use serde::Deserialize;
+
+use crate::{
+    entities::watchlist::Watchlist, utilities::watchlist_selection::validate_watchlist_name, Error,
+};
+
+use super::interactor::Interactor;
+
+pub trait UpdateWatchlistDataAccess {
+    async fn fetch_watchlists(&mut self) -> Result<Vec<Watchlist>, Error>;
+    async fn update_watchlist(&mut self, watchlist: &Watchlist) -> Result<(), Error>;
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RequestWatchlist {
+    pub id: String,
+    pub name: String,
+}
+
+impl PartialEq for RequestWatchlist {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id && self.name == other.name;
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateWatchlistRequest {
+    pub watchlist: RequestWatchlist,
+}
+
+pub struct UpdateWatchlist<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<UpdateWatchlistRequest, ()> for UpdateWatchlist<DA>
+where
+    DA: UpdateWatchlistDataAccess,
+{
+    async fn perform(&mut self, request: UpdateWatchlistRequest) -> Result<(), Error> {
+        let watchlists = self.data_access.fetch_watchlists().await?;
+        let maybe_watchlist = watchlists.iter().find(|w| w.id == request.watchlist.id);
+        if maybe_watchlist.is_none() {
+            return Err(Error {
+                message: String::from("Watchlist to update does not exist!"),
+                ..Default::default()
+            });
+        }
+        validate_watchlist_name(&watchlists, &request.watchlist.id, &request.watchlist.name)?;
+        let mut watchlist = maybe_watchlist.unwrap().clone();
+        watchlist.name = request.watchlist.name.trim().to_string();
+        watchlist.updated_at = Utc::now().to_rfc3339();
+        self.data_access.update_watchlist(&watchlist).await?;
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
@@ -0,0 +1,116 @@
+use serde::Serialize;
+
+use crate::{
+    entities::currency::Currency,
+    utilities::{coin_market::CoinMarket, currency_catalogue::get_currency_kind_name},
+    Error,
+};
+
+use super::interactor::Interactor;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseCurrency {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub symbol: String,
+    pub kind: String,
+    pub decimals: u32,
+    pub icon_id: Option<String>,
+    pub provider_id: Option<String>,
+    pub ambiguous_with: Vec<String>,
+}
+
+impl PartialEq for ResponseCurrency {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.code == other.code
+            && self.name == other.name
+            && self.symbol == other.symbol
+            && self.kind == other.kind
+            && self.decimals == other.decimals
+            && self.icon_id == other.icon_id
+            && self.provider_id == other.provider_id
+            && self.ambiguous_with == other.ambiguous_with;
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseSkippedRecord {
+    pub key: String,
+    pub reason: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseFetchReport {
+    pub source: String,
+    pub url: String,
+    pub status: u16,
+    pub fetched_at: String,
+    pub accepted_records: usize,
+    pub skipped_records: Vec<ResponseSkippedRecord>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ViewCurrenciesResponse {
+    pub currencies: Vec<ResponseCurrency>,
+    pub reports: Vec<ResponseFetchReport>,
+}
+
+pub struct ViewCurrencies<CM> {
+    pub coin_market: CM,
+}
+
+impl<CM> Interactor<(), ViewCurrenciesResponse> for ViewCurrencies<CM>
+where
+    CM: CoinMarket,
+{
+    async fn perform(&mut self, _request: ()) -> Result<ViewCurrenciesResponse, Error> {
+        let market_fetch = self.coin_market.fetch_market().await?;
+        return Ok(ViewCurrenciesResponse {
+            currencies: market_fetch
+                .currencies
+                .iter()
+                .map(create_response_currency)
+                .collect(),
+            reports: market_fetch
+                .reports
+                .iter()
+                .map(|r| ResponseFetchReport {
+                    source: r.source.clone(),
+                    url: r.url.clone(),
+                    status: r.status,
+                    fetched_at: r.fetched_at.clone(),
+                    accepted_records: r.accepted_records,
+                    skipped_records: r
+                        .skipped_records
+                        .iter()
+                        .map(|sr| ResponseSkippedRecord {
+                            key: sr.key.clone(),
+                            reason: sr.reason.clone(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        });
+    }
+}
+
+fn create_response_currency(currency: &Currency) -> ResponseCurrency {
+    return ResponseCurrency {
+        id: currency.id.clone(),
+        code: currency.code.clone(),
+        name: currency.name.clone(),
+        symbol: currency.symbol.clone(),
+        kind: get_currency_kind_name(&currency.kind),
+        decimals: currency.decimals,
+        icon_id: currency.icon_id.clone(),
+        provider_id: currency.provider_id.clone(),
+        ambiguous_with: currency.ambiguous_with.clone(),
+    };
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: create unit tests for the interactor
+}
@@ -1,9 +1,22 @@
-use chrono::Utc;
-use serde::Serialize;
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    entities::{pair::Pair, pair_group::PairGroup},
-    utilities::coin_market::CoinMarket,
+    entities::{
+        currency::Currency, pair::Pair, pair_group::PairGroup, rate_snapshot::RateSnapshot,
+    },
+    utilities::{
+        coin_market::CoinMarket,
+        currency_catalogue::get_currency_kind_name,
+        currency_converter::{CurrencyConverter, DEFAULT_PIVOT},
+        fluctuation_windows::{get_fluctuation_history_start, get_fluctuation_windows},
+        manual_order::{sort_pair_groups, sort_pairs},
+        rate_history::RateHistory,
+        view_query::{matches_search, parse_sort_key, sort_items, SortFields, SortKey},
+    },
     Error,
 };
 
@@ -13,12 +26,33 @@ pub trait ViewPairGroupsDataAccess {
     async fn update_pair(&mut self, pair: &Pair) -> Result<(), Error>;
     async fn fetch_pair_groups(&mut self) -> Result<Vec<PairGroup>, Error>;
     async fn update_pair_group(&mut self, pair_group: &PairGroup) -> Result<(), Error>;
+    async fn fetch_daily_rate_snapshots_since(
+        &mut self,
+        date_time: &DateTime<Utc>,
+    ) -> Result<Vec<RateSnapshot>, Error>;
+    async fn fetch_hourly_rate_snapshots(&mut self) -> Result<Vec<RateSnapshot>, Error>;
+}
+
+/*
+    NOTE: every field is optional. Without `sort_by` pair groups keep the order the
+    user arranged, and `coin` and `search` keep the pair groups with a matching pair.
+    The change of a pair group is the change of its first rate over the last day.
+*/
+#[derive(Clone, Debug, Deserialize)]
+pub struct ViewPairGroupsRequest {
+    pub sort_by: Option<String>,
+    #[serde(default)]
+    pub sort_descending: bool,
+    pub is_pinned: Option<bool>,
+    pub coin: Option<String>,
+    pub search: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize)]
 pub struct ResponsePair {
     pub id: String,
-    pub value: f64,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub value: Decimal,
     pub base: String,
     pub comparison: String,
     pub created_at: String,
@@ -40,7 +74,8 @@ impl PartialEq for ResponsePair {
 pub struct ResponsePairGroup {
     pub id: String,
     pub is_pinned: bool,
-    pub multiplier: f64,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub multiplier: Decimal,
     pub pairs: Vec<ResponsePair>,
     pub created_at: String,
     pub updated_at: String,
@@ -56,8 +91,36 @@ impl PartialEq for ResponsePairGroup {
     }
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseCurrency {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub symbol: String,
+    pub kind: String,
+    pub decimals: u32,
+    pub icon_id: Option<String>,
+    pub provider_id: Option<String>,
+    pub ambiguous_with: Vec<String>,
+}
+
+impl PartialEq for ResponseCurrency {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.code == other.code
+            && self.name == other.name
+            && self.symbol == other.symbol
+            && self.kind == other.kind
+            && self.decimals == other.decimals
+            && self.icon_id == other.icon_id
+            && self.provider_id == other.provider_id
+            && self.ambiguous_with == other.ambiguous_with;
+    }
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct ViewPairGroupsResponse {
+    pub currencies: Vec<ResponseCurrency>,
     pub usd_pairs: Vec<ResponsePair>,
     pub pair_groups: Vec<ResponsePairGroup>,
 }
@@ -67,29 +130,60 @@ pub struct ViewPairGroups<DA, CM> {
     pub coin_market: CM,
 }
 
-impl<DA, CM> Interactor<(), ViewPairGroupsResponse> for ViewPairGroups<DA, CM>
+impl<DA, CM> Interactor<ViewPairGroupsRequest, ViewPairGroupsResponse> for ViewPairGroups<DA, CM>
 where
     DA: ViewPairGroupsDataAccess,
     CM: CoinMarket,
 {
-    async fn perform(&mut self, _request: ()) -> Result<ViewPairGroupsResponse, Error> {
+    async fn perform(
+        &mut self,
+        request: ViewPairGroupsRequest,
+    ) -> Result<ViewPairGroupsResponse, Error> {
+        let mut maybe_sort_key: Option<SortKey> = None;
+        if let Some(sort_by) = &request.sort_by {
+            maybe_sort_key = Some(parse_sort_key(sort_by)?);
+        }
         let mut pair_groups: Vec<PairGroup> = vec![];
         let fresh_usd_pairs: Vec<Pair> = self.coin_market.fetch_usd_pairs().await?;
+        let currencies: Vec<Currency> = self.coin_market.fetch_currencies().await?;
         let stored_pair_groups: Vec<PairGroup> = self.data_access.fetch_pair_groups().await?;
+        let converter = CurrencyConverter::new(&fresh_usd_pairs, DEFAULT_PIVOT)?;
         for stored_pair_group in &stored_pair_groups {
             if stored_pair_group.is_pinned {
-                let fresh_pair_group = refresh_pair_group(&fresh_usd_pairs, stored_pair_group)?;
+                let fresh_pair_group = refresh_pair_group(&converter, stored_pair_group)?;
                 update_pair_group(&mut self.data_access, &fresh_pair_group).await?;
                 pair_groups.push(fresh_pair_group);
             } else {
                 pair_groups.push(stored_pair_group.clone())
             }
         }
-        pair_groups.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        pair_groups.retain(|pg| matches_query(&request, &currencies, pg));
+        // NOTE: the recorded rates are only read when pair groups are sorted by change
+        let mut changes: HashMap<String, Decimal> = HashMap::new();
+        if maybe_sort_key == Some(SortKey::Change) {
+            let now = Utc::now();
+            let mut rate_snapshots = self
+                .data_access
+                .fetch_daily_rate_snapshots_since(&get_fluctuation_history_start(&now))
+                .await?;
+            rate_snapshots.extend(self.data_access.fetch_hourly_rate_snapshots().await?);
+            let rate_history = RateHistory::new(&rate_snapshots);
+            for pair_group in &pair_groups {
+                let change = get_change(&converter, &rate_history, &now, pair_group);
+                changes.insert(pair_group.id.clone(), change.unwrap_or_default());
+            }
+        }
+        sort_pair_groups(&mut pair_groups);
+        if let Some(sort_key) = &maybe_sort_key {
+            sort_items(&mut pair_groups, sort_key, request.sort_descending, |pg| {
+                return create_sort_fields(&changes, pg);
+            });
+        }
         pair_groups
             .iter_mut()
-            .for_each(|pg| pg.pairs.sort_by(|a, b| a.created_at.cmp(&b.created_at)));
+            .for_each(|pg| sort_pairs(&mut pg.pairs));
         return Ok(ViewPairGroupsResponse {
+            currencies: currencies.iter().map(create_response_currency).collect(),
             usd_pairs: fresh_usd_pairs
                 .iter()
                 .map(|p| ResponsePair {
@@ -128,92 +222,108 @@ where
 }
 
 fn refresh_pair_group(
-    fresh_usd_pairs: &Vec<Pair>,
+    converter: &CurrencyConverter,
     pair_group: &PairGroup,
 ) -> Result<PairGroup, Error> {
-    /*
-        TODO:
-            - got to make sure the new pair group has the same size as the old one
-    */
-    if pair_group.pairs[0].base == "USD" {
-        return refresh_usd_pair_group(fresh_usd_pairs, pair_group);
-    } else {
-        return refresh_non_usd_pair_group(fresh_usd_pairs, pair_group);
-    }
-}
-
-fn refresh_usd_pair_group(
-    fresh_usd_pairs: &Vec<Pair>,
-    usd_pair_group: &PairGroup,
-) -> Result<PairGroup, Error> {
-    let mut fresh_usd_pair_group = PairGroup {
-        id: usd_pair_group.id.clone(),
+    let mut fresh_pair_group = PairGroup {
+        id: pair_group.id.clone(),
         pairs: vec![],
-        is_pinned: usd_pair_group.is_pinned,
-        multiplier: usd_pair_group.multiplier,
-        created_at: usd_pair_group.created_at.clone(),
+        is_pinned: pair_group.is_pinned,
+        multiplier: pair_group.multiplier,
+        position: pair_group.position,
+        created_at: pair_group.created_at.clone(),
         updated_at: Utc::now().to_rfc3339(),
     };
-    for usd_pair in &usd_pair_group.pairs {
-        for fresh_usd_pair in fresh_usd_pairs {
-            if usd_pair.comparison == fresh_usd_pair.comparison {
-                fresh_usd_pair_group.pairs.push(Pair {
-                    id: usd_pair.id.clone(),
-                    base: String::from("USD"),
-                    value: fresh_usd_pair.value.clone(),
-                    comparison: usd_pair.comparison.clone(),
-                    created_at: usd_pair.created_at.clone(),
-                    updated_at: Utc::now().to_rfc3339(),
-                });
-                break;
-            };
+    for pair in &pair_group.pairs {
+        if !converter.has_currency(&pair.base) || !converter.has_currency(&pair.comparison) {
+            fresh_pair_group.pairs.push(pair.clone());
+            continue;
         }
+        let rate = converter.get_rate(&pair.base, &pair.comparison)?;
+        fresh_pair_group.pairs.push(Pair {
+            id: pair.id.clone(),
+            base: pair.base.clone(),
+            value: rate.value,
+            comparison: pair.comparison.clone(),
+            position: pair.position,
+            created_at: pair.created_at.clone(),
+            updated_at: Utc::now().to_rfc3339(),
+        });
     }
-    return Ok(fresh_usd_pair_group);
+    return Ok(fresh_pair_group);
 }
 
-fn refresh_non_usd_pair_group(
-    fresh_usd_pairs: &Vec<Pair>,
-    non_usd_pair_group: &PairGroup,
-) -> Result<PairGroup, Error> {
-    let mut fresh_non_usd_pair_group = PairGroup {
-        id: non_usd_pair_group.id.clone(),
-        pairs: vec![],
-        is_pinned: non_usd_pair_group.is_pinned,
-        multiplier: non_usd_pair_group.multiplier,
-        created_at: non_usd_pair_group.created_at.clone(),
-        updated_at: Utc::now().to_rfc3339(),
-    };
-    let equivalent_usd_value =
-        get_equivalent_usd_value(fresh_usd_pairs, &non_usd_pair_group.pairs[0].base)?;
-    for non_usd_pair in &non_usd_pair_group.pairs {
-        for fresh_usd_pair in fresh_usd_pairs {
-            if non_usd_pair.comparison == fresh_usd_pair.comparison {
-                fresh_non_usd_pair_group.pairs.push(Pair {
-                    id: non_usd_pair.id.clone(),
-                    base: non_usd_pair.base.clone(),
-                    value: equivalent_usd_value / fresh_usd_pair.value,
-                    comparison: non_usd_pair.comparison.clone(),
-                    created_at: non_usd_pair.created_at.clone(),
-                    updated_at: Utc::now().to_rfc3339(),
-                });
-                break;
-            }
+fn matches_query(
+    request: &ViewPairGroupsRequest,
+    currencies: &Vec<Currency>,
+    pair_group: &PairGroup,
+) -> bool {
+    let codes: Vec<&str> = pair_group
+        .pairs
+        .iter()
+        .flat_map(|p| [p.base.as_str(), p.comparison.as_str()])
+        .collect();
+    if let Some(is_pinned) = request.is_pinned {
+        if pair_group.is_pinned != is_pinned {
+            return false;
         }
     }
-    return Ok(fresh_non_usd_pair_group);
-}
-
-// TODO: maybe extract it to a utility function, since it seems to be used in several code parts
-fn get_equivalent_usd_value(usd_pairs: &Vec<Pair>, target_base: &str) -> Result<f64, Error> {
-    for usd_pair in usd_pairs {
-        if usd_pair.comparison == target_base {
-            return Ok(1.0 / usd_pair.value);
+    if let Some(coin) = &request.coin {
+        if !codes.contains(&coin.as_str()) {
+            return false;
+        }
+    }
+    if let Some(search) = &request.search {
+        if !matches_search(search, &codes, currencies) {
+            return false;
         }
     }
-    return Err(Error {
-        message: String::from("Could not find the equivalent USD value for the target base!"),
-    });
+    return true;
+}
+
+fn get_change(
+    converter: &CurrencyConverter,
+    rate_history: &RateHistory,
+    now: &DateTime<Utc>,
+    pair_group: &PairGroup,
+) -> Option<Decimal> {
+    let pair = pair_group.pairs.first()?;
+    let get_value = |c: &CurrencyConverter| -> Option<Decimal> {
+        return c
+            .get_rate(&pair.base, &pair.comparison)
+            .ok()
+            .map(|r| r.value);
+    };
+    let value = get_value(converter)?;
+    return get_fluctuation_windows(rate_history, now, value, get_value).day;
+}
+
+// NOTE: a pair group is named after the base of its first pair, and valued by its multiplier
+fn create_sort_fields(changes: &HashMap<String, Decimal>, pair_group: &PairGroup) -> SortFields {
+    return SortFields {
+        value: pair_group.multiplier,
+        change: changes.get(&pair_group.id).cloned().unwrap_or_default(),
+        name: pair_group
+            .pairs
+            .first()
+            .map(|p| p.base.clone())
+            .unwrap_or_default(),
+        created_at: pair_group.created_at.clone(),
+    };
+}
+
+fn create_response_currency(currency: &Currency) -> ResponseCurrency {
+    return ResponseCurrency {
+        id: currency.id.clone(),
+        code: currency.code.clone(),
+        name: currency.name.clone(),
+        symbol: currency.symbol.clone(),
+        kind: get_currency_kind_name(&currency.kind),
+        decimals: currency.decimals,
+        icon_id: currency.icon_id.clone(),
+        provider_id: currency.provider_id.clone(),
+        ambiguous_with: currency.ambiguous_with.clone(),
+    };
 }
 
 async fn update_pair_group(
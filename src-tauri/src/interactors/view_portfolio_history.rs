@@ -0,0 +1,219 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    entities::{portfolio_snapshot::PortfolioSnapshot, rate_snapshot::RateSnapshot},
+    utilities::{
+        rate_history::{RateHistory, DAILY_RATE_TOLERANCE_DAYS},
+        rounding_policy::round_amount,
+    },
+    Error,
+};
+
+use super::interactor::Interactor;
+
+pub trait ViewPortfolioHistoryDataAccess {
+    async fn fetch_portfolio_snapshots(&mut self) -> Result<Vec<PortfolioSnapshot>, Error>;
+    async fn fetch_daily_rate_snapshots(&mut self) -> Result<Vec<RateSnapshot>, Error>;
+}
+
+// NOTE: without a `tag_id` the series is the whole portfolio, `from` is an RFC 3339 date
+#[derive(Clone, Debug, Deserialize)]
+pub struct ViewPortfolioHistoryRequest {
+    pub tag_id: Option<String>,
+    pub from: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponsePoint {
+    pub timestamp: String,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub value: Decimal,
+}
+
+// NOTE: a return is missing when there is no value old enough to compare with
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseReturns {
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub day: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub week: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub month: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub year_to_date: Option<Decimal>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ViewPortfolioHistoryResponse {
+    pub reference_currency: String,
+    pub tag_id: Option<String>,
+    pub points: Vec<ResponsePoint>,
+    pub returns: ResponseReturns,
+}
+
+/*
+    NOTE: snapshots are stored in `USD` and converted with the daily rates closest
+    to each of them, so a change of reference currency applies to the whole series
+*/
+pub struct ViewPortfolioHistory<DA> {
+    pub data_access: DA,
+    pub reference_currency: String,
+}
+
+impl<DA> Interactor<ViewPortfolioHistoryRequest, ViewPortfolioHistoryResponse>
+    for ViewPortfolioHistory<DA>
+where
+    DA: ViewPortfolioHistoryDataAccess,
+{
+    async fn perform(
+        &mut self,
+        request: ViewPortfolioHistoryRequest,
+    ) -> Result<ViewPortfolioHistoryResponse, Error> {
+        let mut maybe_from: Option<DateTime<Utc>> = None;
+        if let Some(from) = &request.from {
+            let parsed_from = DateTime::parse_from_rfc3339(from).map_err(|_| Error {
+                message: String::from("The history start is not a valid RFC 3339 date!"),
+                ..Default::default()
+            })?;
+            maybe_from = Some(parsed_from.with_timezone(&Utc));
+        }
+        let portfolio_snapshots = self.data_access.fetch_portfolio_snapshots().await?;
+        let rate_history = RateHistory::new(&self.data_access.fetch_daily_rate_snapshots().await?);
+        let points = create_points(
+            &portfolio_snapshots,
+            &rate_history,
+            &self.reference_currency,
+            &request.tag_id,
+        );
+        let returns = create_returns(&points, &Utc::now());
+        return Ok(ViewPortfolioHistoryResponse {
+            reference_currency: self.reference_currency.clone(),
+            tag_id: request.tag_id.clone(),
+            points: points
+                .iter()
+                .filter(|(timestamp, _)| maybe_from.is_none() || *timestamp >= maybe_from.unwrap())
+                .map(|(timestamp, value)| ResponsePoint {
+                    timestamp: timestamp.to_rfc3339(),
+                    value: *value,
+                })
+                .collect(),
+            returns,
+        });
+    }
+}
+
+/*
+    NOTE: snapshots taken before the tag existed, or without daily rates recorded
+    around them, are left out
+*/
+fn create_points(
+    portfolio_snapshots: &Vec<PortfolioSnapshot>,
+    rate_history: &RateHistory,
+    reference_currency: &str,
+    tag_id: &Option<String>,
+) -> Vec<(DateTime<Utc>, Decimal)> {
+    let mut points: Vec<(DateTime<Utc>, Decimal)> = vec![];
+    for portfolio_snapshot in portfolio_snapshots {
+        let maybe_created_at = DateTime::parse_from_rfc3339(&portfolio_snapshot.created_at);
+        if maybe_created_at.is_err() {
+            continue;
+        }
+        let mut usd_value = portfolio_snapshot.total_usd_value;
+        if let Some(tag_id) = tag_id {
+            let maybe_tag_value = portfolio_snapshot
+                .tag_values
+                .iter()
+                .find(|tv| &tv.tag_id == tag_id);
+            if maybe_tag_value.is_none() {
+                continue;
+            }
+            usd_value = maybe_tag_value.unwrap().usd_value;
+        }
+        let mut rate = Decimal::ONE;
+        if reference_currency != "USD" {
+            let maybe_rate = rate_history
+                .find_converter_within(
+                    &portfolio_snapshot.created_at,
+                    Duration::days(DAILY_RATE_TOLERANCE_DAYS),
+                )
+                .and_then(|c| c.get_rate("USD", reference_currency).ok());
+            if maybe_rate.is_none() {
+                continue;
+            }
+            rate = maybe_rate.unwrap().value;
+        }
+        points.push((
+            maybe_created_at.unwrap().with_timezone(&Utc),
+            round_amount(usd_value * rate, reference_currency),
+        ));
+    }
+    points.sort_by_key(|(timestamp, _)| *timestamp);
+    return points;
+}
+
+fn create_returns(points: &Vec<(DateTime<Utc>, Decimal)>, now: &DateTime<Utc>) -> ResponseReturns {
+    let start_of_year = NaiveDate::from_ymd_opt(now.year(), 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+        .and_utc();
+    return ResponseReturns {
+        day: get_return(points, &(*now - Duration::days(1))),
+        week: get_return(points, &(*now - Duration::days(7))),
+        month: get_return(points, &(*now - Duration::days(30))),
+        year_to_date: get_return(points, &start_of_year),
+    };
+}
+
+// NOTE: the return since the last value recorded at or before `since`
+fn get_return(points: &Vec<(DateTime<Utc>, Decimal)>, since: &DateTime<Utc>) -> Option<Decimal> {
+    let (_, last_value) = points.last()?;
+    let (_, since_value) = points
+        .iter()
+        .filter(|(timestamp, _)| timestamp <= since)
+        .last()?;
+    return (last_value - since_value).checked_div(*since_value);
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn create_point(timestamp: &str, value: i64) -> (DateTime<Utc>, Decimal) {
+        return (
+            DateTime::parse_from_rfc3339(timestamp)
+                .unwrap()
+                .with_timezone(&Utc),
+            Decimal::from(value),
+        );
+    }
+
+    #[test]
+    fn test_create_returns() {
+        /*
+            Unit test expectations:
+
+            - Each return compares the last value with the last one at or before the period start.
+            - Returns without an old enough value are missing.
+        */
+        let points = vec![
+            create_point("2024-01-20T00:00:00+00:00", 100),
+            create_point("2024-02-25T00:00:00+00:00", 200),
+            create_point("2024-03-03T00:00:00+00:00", 250),
+            create_point("2024-03-08T12:00:00+00:00", 240),
+            create_point("2024-03-09T12:00:00+00:00", 300),
+        ];
+        let now = DateTime::parse_from_rfc3339("2024-03-10T00:00:00+00:00")
+            .unwrap()
+            .with_timezone(&Utc);
+        let returns = create_returns(&points, &now);
+        assert_eq!(returns.day, Some(Decimal::from_str("0.25").unwrap()));
+        assert_eq!(returns.week, Some(Decimal::from_str("0.2").unwrap()));
+        assert_eq!(returns.month, Some(Decimal::from(2)));
+        assert_eq!(returns.year_to_date, None);
+    }
+}
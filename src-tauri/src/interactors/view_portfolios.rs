@@ -1,23 +1,76 @@
-use serde::Serialize;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    entities::{asset::Asset, pair::Pair, tag::Tag},
-    utilities::coin_market::CoinMarket,
+    entities::{
+        account::Account,
+        asset::Asset,
+        currency::Currency,
+        portfolio_snapshot::{PortfolioSnapshot, TagValue},
+        rate_snapshot::RateSnapshot,
+        settings::CostBasisMethod,
+        tag::Tag,
+        transaction::Transaction,
+    },
+    utilities::{
+        coin_market::CoinMarket,
+        cost_basis::{
+            convert_unit_price, create_cost_basis_report, get_cost_basis_method_name,
+            get_unit_price_rate_dates, CostBasisReport,
+        },
+        currency_catalogue::get_currency_kind_name,
+        currency_converter::{CurrencyConverter, DEFAULT_PIVOT},
+        fluctuation_windows::{
+            get_fluctuation_history_start, get_fluctuation_windows, FluctuationWindows,
+        },
+        ledger::create_missing_opening_transactions,
+        rate_history::RateHistory,
+        rounding_policy::round_amount,
+        tag_hierarchy::{get_descendant_ids, get_tag_path},
+        view_query::{matches_search, parse_sort_key, sort_items, SortFields, SortKey},
+    },
     Error,
 };
 
 use super::interactor::Interactor;
 
 pub trait ViewPortfoliosDataAccess {
+    async fn fetch_accounts(&mut self) -> Result<Vec<Account>, Error>;
     async fn fetch_tags(&mut self) -> Result<Vec<Tag>, Error>;
     async fn fetch_assets(&mut self) -> Result<Vec<Asset>, Error>;
     async fn update_asset(&mut self, asset: &Asset) -> Result<(), Error>;
+    async fn find_rate_snapshot(&mut self) -> Result<Option<RateSnapshot>, Error>;
+    async fn update_rate_snapshot(&mut self, rate_snapshot: &RateSnapshot) -> Result<(), Error>;
+    async fn update_daily_rate_snapshot(
+        &mut self,
+        rate_snapshot: &RateSnapshot,
+    ) -> Result<(), Error>;
+    async fn update_hourly_rate_snapshot(
+        &mut self,
+        rate_snapshot: &RateSnapshot,
+    ) -> Result<(), Error>;
+    async fn fetch_daily_rate_snapshots_since(
+        &mut self,
+        date_time: &DateTime<Utc>,
+    ) -> Result<Vec<RateSnapshot>, Error>;
+    async fn fetch_daily_rate_snapshots_on(
+        &mut self,
+        dates: &Vec<NaiveDate>,
+    ) -> Result<Vec<RateSnapshot>, Error>;
+    async fn fetch_hourly_rate_snapshots(&mut self) -> Result<Vec<RateSnapshot>, Error>;
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error>;
+    async fn update_portfolio_snapshot(
+        &mut self,
+        portfolio_snapshot: &PortfolioSnapshot,
+    ) -> Result<(), Error>;
 }
 
 #[derive(Clone, Debug, Serialize)]
 pub struct ResponsePair {
     pub id: String,
-    pub value: f64,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub value: Decimal,
     pub base: String,
     pub comparison: String,
     pub created_at: String,
@@ -35,10 +88,39 @@ impl PartialEq for ResponsePair {
     }
 }
 
+/*
+    NOTE: without an `account_id` the assets of every account are viewed. The other
+    fields only narrow down and order the listed portfolios, the totals are over
+    every viewed asset. A `tag_id` also keeps the assets of the tags nested below it.
+*/
+#[derive(Clone, Debug, Deserialize)]
+pub struct ViewPortfoliosRequest {
+    pub account_id: Option<String>,
+    pub sort_by: Option<String>,
+    #[serde(default)]
+    pub sort_descending: bool,
+    pub tag_id: Option<String>,
+    pub coin: Option<String>,
+    pub search: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseTargetWeight {
+    pub coin: String,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub weight: Decimal,
+}
+
+// NOTE: `path` holds the names from the root tag down to the tag itself
 #[derive(Clone, Debug, Serialize)]
 pub struct ResponseTag {
     pub id: String,
     pub name: String,
+    pub color: Option<String>,
+    pub description: String,
+    pub parent_id: Option<String>,
+    pub path: Vec<String>,
+    pub targets: Vec<ResponseTargetWeight>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -47,6 +129,9 @@ impl PartialEq for ResponseTag {
     fn eq(&self, other: &Self) -> bool {
         return self.id == other.id
             && self.name == other.name
+            && self.color == other.color
+            && self.description == other.description
+            && self.parent_id == other.parent_id
             && self.created_at == other.created_at
             && self.updated_at == other.updated_at;
     }
@@ -56,8 +141,24 @@ impl PartialEq for ResponseTag {
 pub struct ResponseAsset {
     pub id: String,
     pub coin: String,
-    pub quantity: f64,
-    pub usd_value: f64,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub quantity: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub usd_value: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub value: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub total_value: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub previous_total_value: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub cost_basis: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub unrealized_pnl: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub realized_pnl: Decimal,
+    pub has_unknown_cost: bool,
+    pub account_id: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -72,9 +173,26 @@ impl PartialEq for ResponseAsset {
     }
 }
 
+// NOTE: the changes over the last hour, day, week and 30 days, `null` when no rates were recorded then
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ResponseFluctuations {
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub hour: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub day: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub week: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub month: Option<Decimal>,
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct ResponsePortfolio {
-    pub fluctuation: f64,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub fluctuation: Decimal,
+    pub fluctuations: ResponseFluctuations,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub allocation: Decimal,
     pub tags: Vec<ResponseTag>,
     pub asset: ResponseAsset,
 }
@@ -85,45 +203,311 @@ impl PartialEq for ResponsePortfolio {
     }
 }
 
+/*
+    NOTE: a tag total rolls up the assets of every tag nested below it, each asset
+    counted once, and a `None` tag holds the subtotal of the untagged assets
+*/
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseTagTotal {
+    pub tag: Option<ResponseTag>,
+    pub asset_count: usize,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub total_value: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub fluctuation: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub allocation: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub cost_basis: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub unrealized_pnl: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub realized_pnl: Decimal,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseAccount {
+    pub id: String,
+    pub name: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl PartialEq for ResponseAccount {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.name == other.name
+            && self.created_at == other.created_at
+            && self.updated_at == other.updated_at;
+    }
+}
+
+/*
+    NOTE: account totals are always over every asset, whatever account is viewed,
+    and a `None` account holds the subtotal of the assets without an account
+*/
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseAccountTotal {
+    pub account: Option<ResponseAccount>,
+    pub asset_count: usize,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub total_value: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub fluctuation: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub allocation: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub cost_basis: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub unrealized_pnl: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub realized_pnl: Decimal,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseCoinTotal {
+    pub coin: String,
+    pub asset_count: usize,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub quantity: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub total_value: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub fluctuation: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub allocation: Decimal,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseCurrency {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub symbol: String,
+    pub kind: String,
+    pub decimals: u32,
+    pub icon_id: Option<String>,
+    pub provider_id: Option<String>,
+    pub ambiguous_with: Vec<String>,
+}
+
+impl PartialEq for ResponseCurrency {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.code == other.code
+            && self.name == other.name
+            && self.symbol == other.symbol
+            && self.kind == other.kind
+            && self.decimals == other.decimals
+            && self.icon_id == other.icon_id
+            && self.provider_id == other.provider_id
+            && self.ambiguous_with == other.ambiguous_with;
+    }
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct ViewPortfoliosResponse {
+    pub reference_currency: String,
+    pub account_id: Option<String>,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub total_value: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub total_fluctuation: Decimal,
+    pub total_fluctuations: ResponseFluctuations,
+    pub cost_basis_method: String,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub total_cost_basis: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub total_unrealized_pnl: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub total_realized_pnl: Decimal,
+    pub tag_totals: Vec<ResponseTagTotal>,
+    pub coin_totals: Vec<ResponseCoinTotal>,
+    pub account_totals: Vec<ResponseAccountTotal>,
+    pub currencies: Vec<ResponseCurrency>,
     pub tags: Vec<ResponseTag>,
+    pub accounts: Vec<ResponseAccount>,
     pub usd_pairs: Vec<ResponsePair>,
     pub portfolios: Vec<ResponsePortfolio>,
 }
 
+/*
+    NOTE: assets keep storing their `USD` value, which is what the coin market
+    provides, while every value and fluctuation in the response is expressed in
+    the reference currency. Transaction prices are converted at the daily rates
+    of their date, like in the tax lot export, so both show the same cost basis.
+*/
 pub struct ViewPortfolios<DA, CM> {
     pub data_access: DA,
     pub coin_market: CM,
+    pub reference_currency: String,
+    pub cost_basis_method: CostBasisMethod,
 }
 
-impl<DA, CM> Interactor<(), ViewPortfoliosResponse> for ViewPortfolios<DA, CM>
+impl<DA, CM> Interactor<ViewPortfoliosRequest, ViewPortfoliosResponse> for ViewPortfolios<DA, CM>
 where
     DA: ViewPortfoliosDataAccess,
     CM: CoinMarket,
 {
-    async fn perform(&mut self, _request: ()) -> Result<ViewPortfoliosResponse, Error> {
+    async fn perform(
+        &mut self,
+        request: ViewPortfoliosRequest,
+    ) -> Result<ViewPortfoliosResponse, Error> {
+        let accounts = self.data_access.fetch_accounts().await?;
+        if let Some(account_id) = &request.account_id {
+            if !accounts.iter().any(|a| &a.id == account_id) {
+                return Err(Error {
+                    message: String::from("Account to view does not exist!"),
+                    ..Default::default()
+                });
+            }
+        }
         let tags = self.data_access.fetch_tags().await?;
+        if let Some(tag_id) = &request.tag_id {
+            if !tags.iter().any(|t| &t.id == tag_id) {
+                return Err(Error {
+                    message: String::from("Tag to filter by does not exist!"),
+                    ..Default::default()
+                });
+            }
+        }
+        let mut maybe_sort_key: Option<SortKey> = None;
+        if let Some(sort_by) = &request.sort_by {
+            maybe_sort_key = Some(parse_sort_key(sort_by)?);
+        }
         let assets = self.data_access.fetch_assets().await?;
         let usd_pairs = self.coin_market.fetch_usd_pairs().await?;
+        let currencies = self.coin_market.fetch_currencies().await?;
+
+        let converter = CurrencyConverter::new(&usd_pairs, DEFAULT_PIVOT)?;
+        if !converter.has_currency(&self.reference_currency) {
+            return Err(Error {
+                message: format!(
+                    "Could not find a rate for the reference currency '{}'!",
+                    self.reference_currency
+                ),
+                ..Default::default()
+            });
+        }
+        let maybe_rate_snapshot = self.data_access.find_rate_snapshot().await?;
+        let previous_converter = maybe_rate_snapshot
+            .and_then(|rs| CurrencyConverter::new(&rs.pairs, DEFAULT_PIVOT).ok())
+            .filter(|c| c.has_currency(&self.reference_currency))
+            .unwrap_or(converter.clone());
 
-        let fresh_assets = refresh_assets(&usd_pairs, &assets)?;
+        let now = Utc::now();
+        let mut rate_snapshots = self
+            .data_access
+            .fetch_daily_rate_snapshots_since(&get_fluctuation_history_start(&now))
+            .await?;
+        rate_snapshots.extend(self.data_access.fetch_hourly_rate_snapshots().await?);
+        let rate_history = RateHistory::new(&rate_snapshots);
+
+        let fresh_assets = refresh_assets(&converter, &assets)?;
         for fresh_assets in &fresh_assets {
             self.data_access.update_asset(&fresh_assets).await?;
         }
+        let rate_snapshot = RateSnapshot {
+            pairs: usd_pairs.clone(),
+            created_at: now.to_rfc3339(),
+        };
+        self.data_access
+            .update_rate_snapshot(&rate_snapshot)
+            .await?;
+        self.data_access
+            .update_daily_rate_snapshot(&rate_snapshot)
+            .await?;
+        self.data_access
+            .update_hourly_rate_snapshot(&rate_snapshot)
+            .await?;
+        self.data_access
+            .update_portfolio_snapshot(&create_portfolio_snapshot(&now, &tags, &fresh_assets))
+            .await?;
 
-        let portfolios = create_portfolios(&tags, &assets, &fresh_assets)?;
+        let mut transactions = self.data_access.fetch_transactions().await?;
+        transactions.extend(create_missing_opening_transactions(&assets, &transactions));
+        let price_rate_history = RateHistory::new(
+            &self
+                .data_access
+                .fetch_daily_rate_snapshots_on(&get_unit_price_rate_dates(&transactions))
+                .await?,
+        );
+        let cost_basis_report =
+            create_cost_basis_report(&transactions, &self.cost_basis_method, |t| {
+                convert_unit_price(t, &price_rate_history, &converter, &self.reference_currency)
+            })?;
+
+        let mut portfolios = create_portfolios(
+            &converter,
+            &previous_converter,
+            &self.reference_currency,
+            &tags,
+            &assets,
+            &fresh_assets,
+        )?;
+        apply_cost_basis(
+            &mut portfolios,
+            &cost_basis_report,
+            &self.reference_currency,
+        );
+        let account_totals = create_account_totals(&accounts, &portfolios);
+        if let Some(account_id) = &request.account_id {
+            portfolios.retain(|p| p.asset.account_id.as_ref() == Some(account_id));
+        }
+        let total_value = portfolios
+            .iter()
+            .map(|p| p.asset.total_value)
+            .sum::<Decimal>();
+        let previous_total_value = portfolios
+            .iter()
+            .map(|p| p.asset.previous_total_value)
+            .sum::<Decimal>();
+        for portfolio in &mut portfolios {
+            portfolio.allocation = get_allocation(portfolio.asset.total_value, total_value);
+        }
+        apply_fluctuation_windows(
+            &mut portfolios,
+            &rate_history,
+            &now,
+            &self.reference_currency,
+        );
+        let total_fluctuation_windows =
+            get_fluctuation_windows(&rate_history, &now, total_value, |c| {
+                return portfolios
+                    .iter()
+                    .map(|p| get_previous_total_value(c, &p.asset, &self.reference_currency))
+                    .sum();
+            });
+        let mut listed_portfolios: Vec<ResponsePortfolio> = portfolios
+            .iter()
+            .filter(|p| matches_query(&request, &tags, &currencies, p))
+            .cloned()
+            .collect();
+        if let Some(sort_key) = &maybe_sort_key {
+            sort_items(
+                &mut listed_portfolios,
+                sort_key,
+                request.sort_descending,
+                create_sort_fields,
+            );
+        }
         return Ok(ViewPortfoliosResponse {
-            portfolios,
-            tags: tags
-                .iter()
-                .map(|t| ResponseTag {
-                    id: t.id.clone(),
-                    name: t.name.clone(),
-                    created_at: t.created_at.clone(),
-                    updated_at: t.updated_at.clone(),
-                })
-                .collect(),
+            reference_currency: self.reference_currency.clone(),
+            account_id: request.account_id.clone(),
+            total_value,
+            total_fluctuation: get_fluctuation(total_value, previous_total_value),
+            total_fluctuations: create_response_fluctuations(&total_fluctuation_windows),
+            cost_basis_method: get_cost_basis_method_name(&self.cost_basis_method),
+            total_cost_basis: portfolios.iter().map(|p| p.asset.cost_basis).sum(),
+            total_unrealized_pnl: portfolios.iter().map(|p| p.asset.unrealized_pnl).sum(),
+            total_realized_pnl: portfolios.iter().map(|p| p.asset.realized_pnl).sum(),
+            tag_totals: create_tag_totals(&tags, &portfolios, total_value),
+            coin_totals: create_coin_totals(&portfolios, total_value),
+            account_totals,
+            portfolios: listed_portfolios,
+            currencies: currencies.iter().map(create_response_currency).collect(),
+            tags: tags.iter().map(|t| create_response_tag(&tags, t)).collect(),
+            accounts: accounts.iter().map(create_response_account).collect(),
             usd_pairs: usd_pairs
                 .iter()
                 .map(|p| ResponsePair {
@@ -139,15 +523,16 @@ where
     }
 }
 
-fn refresh_assets(usd_pairs: &Vec<Pair>, assets: &Vec<Asset>) -> Result<Vec<Asset>, Error> {
+fn refresh_assets(converter: &CurrencyConverter, assets: &Vec<Asset>) -> Result<Vec<Asset>, Error> {
     let mut fresh_assets: Vec<Asset> = vec![];
     for asset in assets {
-        let usd_value = get_equivalent_usd_value(usd_pairs, &asset.coin)?;
+        let usd_value = converter.get_rate(&asset.coin, "USD")?.value;
         fresh_assets.push(Asset {
             id: asset.id.clone(),
             usd_value,
             coin: asset.coin.clone(),
             quantity: asset.quantity.clone(),
+            account_id: asset.account_id.clone(),
             created_at: asset.created_at.clone(),
             updated_at: asset.updated_at.clone(),
         });
@@ -155,55 +540,147 @@ fn refresh_assets(usd_pairs: &Vec<Pair>, assets: &Vec<Asset>) -> Result<Vec<Asse
     return Ok(fresh_assets);
 }
 
-// TODO: maybe extract it to a utility function, since it seems to be used in several code parts
-fn get_equivalent_usd_value(usd_pairs: &Vec<Pair>, target_base: &str) -> Result<f64, Error> {
-    for usd_pair in usd_pairs {
-        if usd_pair.comparison == target_base {
-            return Ok(1.0 / usd_pair.value);
-        }
-    }
-    return Err(Error {
-        message: String::from("Could not find the equivalent USD value for the target base!"),
-    });
+/*
+    NOTE: snapshots are bucketed per hour, which keeps a year of history small
+    enough while still showing the moves within a day
+*/
+fn create_portfolio_snapshot(
+    now: &DateTime<Utc>,
+    tags: &Vec<Tag>,
+    fresh_assets: &Vec<Asset>,
+) -> PortfolioSnapshot {
+    return PortfolioSnapshot {
+        bucket: now.format("%Y-%m-%dT%H").to_string(),
+        total_usd_value: get_usd_value(&fresh_assets.iter().collect()),
+        tag_values: tags
+            .iter()
+            .map(|t| {
+                let descendant_ids = get_descendant_ids(tags, &t.id);
+                let descendant_tags: Vec<&Tag> = tags
+                    .iter()
+                    .filter(|dt| descendant_ids.contains(&dt.id))
+                    .collect();
+                return TagValue {
+                    tag_id: t.id.clone(),
+                    usd_value: get_usd_value(
+                        &fresh_assets
+                            .iter()
+                            .filter(|fa| {
+                                descendant_tags
+                                    .iter()
+                                    .any(|dt| dt.assets.iter().any(|a| a.id == fa.id))
+                            })
+                            .collect(),
+                    ),
+                };
+            })
+            .collect(),
+        created_at: now.to_rfc3339(),
+    };
+}
+
+fn get_usd_value(assets: &Vec<&Asset>) -> Decimal {
+    return assets
+        .iter()
+        .map(|a| a.usd_value * a.quantity)
+        .sum::<Decimal>();
 }
 
+fn create_response_tag(tags: &Vec<Tag>, tag: &Tag) -> ResponseTag {
+    return ResponseTag {
+        id: tag.id.clone(),
+        name: tag.name.clone(),
+        color: tag.color.clone(),
+        description: tag.description.clone(),
+        parent_id: tag.parent_id.clone(),
+        path: get_tag_path(tags, &tag.id),
+        targets: tag
+            .targets
+            .iter()
+            .map(|t| ResponseTargetWeight {
+                coin: t.coin.clone(),
+                weight: t.weight,
+            })
+            .collect(),
+        created_at: tag.created_at.clone(),
+        updated_at: tag.updated_at.clone(),
+    };
+}
+
+fn create_response_account(account: &Account) -> ResponseAccount {
+    return ResponseAccount {
+        id: account.id.clone(),
+        name: account.name.clone(),
+        created_at: account.created_at.clone(),
+        updated_at: account.updated_at.clone(),
+    };
+}
+
+fn create_response_currency(currency: &Currency) -> ResponseCurrency {
+    return ResponseCurrency {
+        id: currency.id.clone(),
+        code: currency.code.clone(),
+        name: currency.name.clone(),
+        symbol: currency.symbol.clone(),
+        kind: get_currency_kind_name(&currency.kind),
+        decimals: currency.decimals,
+        icon_id: currency.icon_id.clone(),
+        provider_id: currency.provider_id.clone(),
+        ambiguous_with: currency.ambiguous_with.clone(),
+    };
+}
+
+/*
+    NOTE: the previous value in the reference currency is recomputed from the
+    previously stored `USD` value and the previous rate of the reference currency,
+    falling back to the current rate when no previous rates were recorded
+*/
 fn create_portfolios(
+    converter: &CurrencyConverter,
+    previous_converter: &CurrencyConverter,
+    reference_currency: &str,
     tags: &Vec<Tag>,
     assets: &Vec<Asset>,
     fresh_assets: &Vec<Asset>,
 ) -> Result<Vec<ResponsePortfolio>, Error> {
-    let mut portfolios: Vec<ResponsePortfolio> = fresh_assets
-        .iter()
-        .map(|fa| {
-            let mut fluctuation = 0.0;
-            if let Some(asset) = assets.iter().find(|a| a.id == fa.id) {
-                let usd_value = asset.usd_value;
-                let fresh_usd_value = fa.usd_value;
-                let usd_difference = fresh_usd_value - usd_value;
-                fluctuation = usd_difference / usd_value;
-            }
-            return ResponsePortfolio {
-                fluctuation,
-                tags: vec![],
-                asset: ResponseAsset {
-                    id: fa.id.clone(),
-                    coin: fa.coin.clone(),
-                    quantity: fa.quantity.clone(),
-                    usd_value: fa.usd_value.clone(),
-                    created_at: fa.created_at.clone(),
-                    updated_at: fa.updated_at.clone(),
-                },
-            };
-        })
-        .collect();
+    let previous_reference_rate = previous_converter.get_rate("USD", reference_currency)?;
+    let mut portfolios: Vec<ResponsePortfolio> = vec![];
+    for fa in fresh_assets {
+        let value = converter.get_rate(&fa.coin, reference_currency)?.value;
+        let total_value = round_amount(value * fa.quantity, reference_currency);
+        let mut previous_total_value = total_value;
+        if let Some(asset) = assets.iter().find(|a| a.id == fa.id) {
+            previous_total_value = round_amount(
+                asset.usd_value * previous_reference_rate.value * fa.quantity,
+                reference_currency,
+            );
+        }
+        portfolios.push(ResponsePortfolio {
+            fluctuation: get_fluctuation(total_value, previous_total_value),
+            fluctuations: ResponseFluctuations::default(),
+            allocation: Decimal::ZERO,
+            tags: vec![],
+            asset: ResponseAsset {
+                id: fa.id.clone(),
+                coin: fa.coin.clone(),
+                quantity: fa.quantity.clone(),
+                usd_value: fa.usd_value.clone(),
+                value,
+                total_value,
+                previous_total_value,
+                cost_basis: Decimal::ZERO,
+                unrealized_pnl: Decimal::ZERO,
+                realized_pnl: Decimal::ZERO,
+                has_unknown_cost: false,
+                account_id: fa.account_id.clone(),
+                created_at: fa.created_at.clone(),
+                updated_at: fa.updated_at.clone(),
+            },
+        });
+    }
 
     for tag in tags {
-        let response_tag = ResponseTag {
-            id: tag.id.clone(),
-            name: tag.name.clone(),
-            created_at: tag.created_at.clone(),
-            updated_at: tag.updated_at.clone(),
-        };
+        let response_tag = create_response_tag(tags, tag);
         for portfolio in &mut portfolios {
             for tag_asset in &tag.assets {
                 if tag_asset.id == portfolio.asset.id {
@@ -216,7 +693,438 @@ fn create_portfolios(
     return Ok(portfolios);
 }
 
+// NOTE: the windows are computed at the current quantities, so they only follow the rates
+fn apply_fluctuation_windows(
+    portfolios: &mut Vec<ResponsePortfolio>,
+    rate_history: &RateHistory,
+    now: &DateTime<Utc>,
+    reference_currency: &str,
+) {
+    for portfolio in portfolios {
+        let fluctuation_windows =
+            get_fluctuation_windows(rate_history, now, portfolio.asset.total_value, |c| {
+                return get_previous_total_value(c, &portfolio.asset, reference_currency);
+            });
+        portfolio.fluctuations = create_response_fluctuations(&fluctuation_windows);
+    }
+}
+
+fn get_previous_total_value(
+    previous_converter: &CurrencyConverter,
+    asset: &ResponseAsset,
+    reference_currency: &str,
+) -> Option<Decimal> {
+    let previous_rate = previous_converter
+        .get_rate(&asset.coin, reference_currency)
+        .ok()?;
+    return Some(round_amount(
+        previous_rate.value * asset.quantity,
+        reference_currency,
+    ));
+}
+
+fn create_response_fluctuations(fluctuation_windows: &FluctuationWindows) -> ResponseFluctuations {
+    return ResponseFluctuations {
+        hour: fluctuation_windows.hour,
+        day: fluctuation_windows.day,
+        week: fluctuation_windows.week,
+        month: fluctuation_windows.month,
+    };
+}
+
+fn matches_query(
+    request: &ViewPortfoliosRequest,
+    tags: &Vec<Tag>,
+    currencies: &Vec<Currency>,
+    portfolio: &ResponsePortfolio,
+) -> bool {
+    if let Some(tag_id) = &request.tag_id {
+        let tag_ids = get_descendant_ids(tags, tag_id);
+        if !portfolio.tags.iter().any(|t| tag_ids.contains(&t.id)) {
+            return false;
+        }
+    }
+    if let Some(coin) = &request.coin {
+        if &portfolio.asset.coin != coin {
+            return false;
+        }
+    }
+    if let Some(search) = &request.search {
+        if !matches_search(search, &vec![portfolio.asset.coin.as_str()], currencies) {
+            return false;
+        }
+    }
+    return true;
+}
+
+fn create_sort_fields(portfolio: &ResponsePortfolio) -> SortFields {
+    return SortFields {
+        value: portfolio.asset.total_value,
+        change: portfolio.fluctuation,
+        name: portfolio.asset.coin.clone(),
+        created_at: portfolio.asset.created_at.clone(),
+    };
+}
+
+fn apply_cost_basis(
+    portfolios: &mut Vec<ResponsePortfolio>,
+    cost_basis_report: &CostBasisReport,
+    reference_currency: &str,
+) {
+    for portfolio in portfolios {
+        let asset = &mut portfolio.asset;
+        asset.cost_basis = round_amount(
+            cost_basis_report.get_cost_basis(&asset.id),
+            reference_currency,
+        );
+        asset.unrealized_pnl = asset.total_value - asset.cost_basis;
+        asset.realized_pnl = round_amount(
+            cost_basis_report.get_realized_pnl(&asset.id),
+            reference_currency,
+        );
+        asset.has_unknown_cost = cost_basis_report.has_unknown_cost(&asset.id);
+    }
+}
+
+fn create_tag_totals(
+    tags: &Vec<Tag>,
+    portfolios: &Vec<ResponsePortfolio>,
+    total_value: Decimal,
+) -> Vec<ResponseTagTotal> {
+    let mut tag_totals: Vec<ResponseTagTotal> = vec![];
+    for tag in tags {
+        let descendant_ids = get_descendant_ids(tags, &tag.id);
+        let tagged_portfolios: Vec<&ResponsePortfolio> = portfolios
+            .iter()
+            .filter(|p| p.tags.iter().any(|t| descendant_ids.contains(&t.id)))
+            .collect();
+        tag_totals.push(create_tag_total(
+            Some(create_response_tag(tags, tag)),
+            &tagged_portfolios,
+            total_value,
+        ));
+    }
+    let untagged_portfolios: Vec<&ResponsePortfolio> =
+        portfolios.iter().filter(|p| p.tags.len() == 0).collect();
+    if untagged_portfolios.len() > 0 {
+        tag_totals.push(create_tag_total(None, &untagged_portfolios, total_value));
+    }
+    return tag_totals;
+}
+
+fn create_tag_total(
+    tag: Option<ResponseTag>,
+    portfolios: &Vec<&ResponsePortfolio>,
+    total_value: Decimal,
+) -> ResponseTagTotal {
+    let tag_total_value = portfolios
+        .iter()
+        .map(|p| p.asset.total_value)
+        .sum::<Decimal>();
+    let tag_previous_total_value = portfolios
+        .iter()
+        .map(|p| p.asset.previous_total_value)
+        .sum::<Decimal>();
+    return ResponseTagTotal {
+        tag,
+        asset_count: portfolios.len(),
+        total_value: tag_total_value,
+        fluctuation: get_fluctuation(tag_total_value, tag_previous_total_value),
+        allocation: get_allocation(tag_total_value, total_value),
+        cost_basis: portfolios.iter().map(|p| p.asset.cost_basis).sum(),
+        unrealized_pnl: portfolios.iter().map(|p| p.asset.unrealized_pnl).sum(),
+        realized_pnl: portfolios.iter().map(|p| p.asset.realized_pnl).sum(),
+    };
+}
+
+fn create_account_totals(
+    accounts: &Vec<Account>,
+    portfolios: &Vec<ResponsePortfolio>,
+) -> Vec<ResponseAccountTotal> {
+    let total_value = portfolios
+        .iter()
+        .map(|p| p.asset.total_value)
+        .sum::<Decimal>();
+    let mut account_totals: Vec<ResponseAccountTotal> = vec![];
+    for account in accounts {
+        let account_portfolios: Vec<&ResponsePortfolio> = portfolios
+            .iter()
+            .filter(|p| p.asset.account_id.as_ref() == Some(&account.id))
+            .collect();
+        account_totals.push(create_account_total(
+            Some(create_response_account(account)),
+            &account_portfolios,
+            total_value,
+        ));
+    }
+    let unassigned_portfolios: Vec<&ResponsePortfolio> = portfolios
+        .iter()
+        .filter(|p| p.asset.account_id.is_none())
+        .collect();
+    if unassigned_portfolios.len() > 0 {
+        account_totals.push(create_account_total(
+            None,
+            &unassigned_portfolios,
+            total_value,
+        ));
+    }
+    return account_totals;
+}
+
+fn create_account_total(
+    account: Option<ResponseAccount>,
+    portfolios: &Vec<&ResponsePortfolio>,
+    total_value: Decimal,
+) -> ResponseAccountTotal {
+    let account_total_value = portfolios
+        .iter()
+        .map(|p| p.asset.total_value)
+        .sum::<Decimal>();
+    let account_previous_total_value = portfolios
+        .iter()
+        .map(|p| p.asset.previous_total_value)
+        .sum::<Decimal>();
+    return ResponseAccountTotal {
+        account,
+        asset_count: portfolios.len(),
+        total_value: account_total_value,
+        fluctuation: get_fluctuation(account_total_value, account_previous_total_value),
+        allocation: get_allocation(account_total_value, total_value),
+        cost_basis: portfolios.iter().map(|p| p.asset.cost_basis).sum(),
+        unrealized_pnl: portfolios.iter().map(|p| p.asset.unrealized_pnl).sum(),
+        realized_pnl: portfolios.iter().map(|p| p.asset.realized_pnl).sum(),
+    };
+}
+
+// NOTE: the same coin can be held in several assets, e.g. one per wallet
+fn create_coin_totals(
+    portfolios: &Vec<ResponsePortfolio>,
+    total_value: Decimal,
+) -> Vec<ResponseCoinTotal> {
+    let mut coin_totals: Vec<ResponseCoinTotal> = vec![];
+    let mut previous_total_values: Vec<Decimal> = vec![];
+    for portfolio in portfolios {
+        let maybe_idx = coin_totals
+            .iter()
+            .position(|ct| ct.coin == portfolio.asset.coin);
+        if let Some(idx) = maybe_idx {
+            coin_totals[idx].asset_count += 1;
+            coin_totals[idx].quantity += portfolio.asset.quantity;
+            coin_totals[idx].total_value += portfolio.asset.total_value;
+            previous_total_values[idx] += portfolio.asset.previous_total_value;
+        } else {
+            coin_totals.push(ResponseCoinTotal {
+                coin: portfolio.asset.coin.clone(),
+                asset_count: 1,
+                quantity: portfolio.asset.quantity,
+                total_value: portfolio.asset.total_value,
+                fluctuation: Decimal::ZERO,
+                allocation: Decimal::ZERO,
+            });
+            previous_total_values.push(portfolio.asset.previous_total_value);
+        }
+    }
+    for (idx, coin_total) in coin_totals.iter_mut().enumerate() {
+        coin_total.quantity = round_amount(coin_total.quantity, &coin_total.coin);
+        coin_total.fluctuation =
+            get_fluctuation(coin_total.total_value, previous_total_values[idx]);
+        coin_total.allocation = get_allocation(coin_total.total_value, total_value);
+    }
+    coin_totals.sort_by(|a, b| b.total_value.cmp(&a.total_value));
+    return coin_totals;
+}
+
+fn get_fluctuation(total_value: Decimal, previous_total_value: Decimal) -> Decimal {
+    let difference = total_value - previous_total_value;
+    return difference
+        .checked_div(previous_total_value)
+        .unwrap_or_default();
+}
+
+fn get_allocation(value: Decimal, total_value: Decimal) -> Decimal {
+    return value.checked_div(total_value).unwrap_or_default();
+}
+
 #[cfg(test)]
 mod test {
-    // TODO: create unit tests for this impl
+    use std::str::FromStr;
+
+    use crate::entities::pair::Pair;
+
+    use super::*;
+
+    fn create_usd_pair(comparison: &str, value: &str) -> Pair {
+        return Pair {
+            id: comparison.to_lowercase(),
+            value: Decimal::from_str(value).unwrap(),
+            base: String::from("USD"),
+            comparison: comparison.to_string(),
+            position: 0,
+            created_at: String::from("2024-01-01T00:00:00+00:00"),
+            updated_at: String::from("2024-01-01T00:00:00+00:00"),
+        };
+    }
+
+    fn create_asset(id: &str, coin: &str, quantity: i64, usd_value: &str) -> Asset {
+        return Asset {
+            id: id.to_string(),
+            coin: coin.to_string(),
+            quantity: Decimal::from(quantity),
+            usd_value: Decimal::from_str(usd_value).unwrap(),
+            account_id: None,
+            created_at: String::from("2024-01-01T00:00:00+00:00"),
+            updated_at: String::from("2024-01-01T00:00:00+00:00"),
+        };
+    }
+
+    #[test]
+    fn test_create_portfolios_in_reference_currency() {
+        /*
+            Unit test expectations:
+
+            - Asset values are expressed in the reference currency.
+            - The fluctuation accounts for the movement of the reference currency itself.
+        */
+        let previous_converter = CurrencyConverter::new(
+            &vec![
+                create_usd_pair("EUR", "0.5"),
+                create_usd_pair("BTC", "0.01"),
+            ],
+            DEFAULT_PIVOT,
+        )
+        .unwrap();
+        let converter = CurrencyConverter::new(
+            &vec![
+                create_usd_pair("EUR", "0.25"),
+                create_usd_pair("BTC", "0.01"),
+            ],
+            DEFAULT_PIVOT,
+        )
+        .unwrap();
+        let assets = vec![create_asset("a1", "BTC", 2, "100")];
+        let fresh_assets = refresh_assets(&converter, &assets).unwrap();
+
+        let portfolios = create_portfolios(
+            &converter,
+            &previous_converter,
+            "EUR",
+            &vec![],
+            &assets,
+            &fresh_assets,
+        )
+        .unwrap();
+        assert_eq!(portfolios[0].asset.usd_value, Decimal::from(100));
+        assert_eq!(portfolios[0].asset.value, Decimal::from(25));
+        assert_eq!(portfolios[0].asset.total_value, Decimal::from(50));
+        assert_eq!(
+            portfolios[0].fluctuation,
+            Decimal::from_str("-0.5").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_create_totals() {
+        /*
+            Unit test expectations:
+
+            - Coins held in several assets are aggregated, sorted by value.
+            - Tag subtotals include an untagged bucket, and allocations are fractions of the total.
+            - Tag subtotals roll up the assets of nested tags, counting each asset once.
+            - Account subtotals include a bucket for the assets without an account.
+        */
+        let converter = CurrencyConverter::new(
+            &vec![
+                create_usd_pair("BTC", "0.01"),
+                create_usd_pair("ETH", "0.1"),
+            ],
+            DEFAULT_PIVOT,
+        )
+        .unwrap();
+        let assets = vec![
+            create_asset("a1", "BTC", 1, "50"),
+            create_asset("a2", "BTC", 2, "100"),
+            create_asset("a3", "ETH", 10, "10"),
+        ];
+        let tags = vec![
+            Tag {
+                id: String::from("t1"),
+                name: String::from("Cold wallet"),
+                color: None,
+                description: String::new(),
+                parent_id: None,
+                assets: vec![assets[0].clone()],
+                targets: vec![],
+                created_at: String::from("2024-01-01T00:00:00+00:00"),
+                updated_at: String::from("2024-01-01T00:00:00+00:00"),
+            },
+            Tag {
+                id: String::from("t2"),
+                name: String::from("Ledger"),
+                color: None,
+                description: String::new(),
+                parent_id: Some(String::from("t1")),
+                assets: vec![assets[0].clone(), assets[2].clone()],
+                targets: vec![],
+                created_at: String::from("2024-01-01T00:00:00+00:00"),
+                updated_at: String::from("2024-01-01T00:00:00+00:00"),
+            },
+        ];
+        let fresh_assets = refresh_assets(&converter, &assets).unwrap();
+        let portfolios =
+            create_portfolios(&converter, &converter, "USD", &tags, &assets, &fresh_assets)
+                .unwrap();
+        let total_value = portfolios
+            .iter()
+            .map(|p| p.asset.total_value)
+            .sum::<Decimal>();
+        assert_eq!(total_value, Decimal::from(400));
+
+        let coin_totals = create_coin_totals(&portfolios, total_value);
+        assert_eq!(coin_totals.len(), 2);
+        assert_eq!(coin_totals[0].coin, "BTC");
+        assert_eq!(coin_totals[0].asset_count, 2);
+        assert_eq!(coin_totals[0].quantity, Decimal::from(3));
+        assert_eq!(coin_totals[0].total_value, Decimal::from(300));
+        assert_eq!(
+            coin_totals[0].fluctuation,
+            Decimal::from_str("0.2").unwrap()
+        );
+        assert_eq!(
+            coin_totals[0].allocation,
+            Decimal::from_str("0.75").unwrap()
+        );
+
+        let tag_totals = create_tag_totals(&tags, &portfolios, total_value);
+        assert_eq!(tag_totals.len(), 3);
+        assert_eq!(tag_totals[0].tag.as_ref().unwrap().id, "t1");
+        assert_eq!(tag_totals[0].asset_count, 2);
+        assert_eq!(tag_totals[0].total_value, Decimal::from(200));
+        assert_eq!(
+            tag_totals[1].tag.as_ref().unwrap().path,
+            vec!["Cold wallet", "Ledger"]
+        );
+        assert_eq!(tag_totals[1].total_value, Decimal::from(200));
+        assert!(tag_totals[2].tag.is_none());
+        assert_eq!(tag_totals[2].asset_count, 1);
+        assert_eq!(tag_totals[2].allocation, Decimal::from_str("0.5").unwrap());
+
+        let accounts = vec![Account {
+            id: String::from("ac1"),
+            name: String::from("Exchange"),
+            created_at: String::from("2024-01-01T00:00:00+00:00"),
+            updated_at: String::from("2024-01-01T00:00:00+00:00"),
+        }];
+        let mut portfolios = portfolios.clone();
+        portfolios[1].asset.account_id = Some(String::from("ac1"));
+        let account_totals = create_account_totals(&accounts, &portfolios);
+        assert_eq!(account_totals.len(), 2);
+        assert_eq!(account_totals[0].account.as_ref().unwrap().id, "ac1");
+        assert_eq!(account_totals[0].total_value, Decimal::from(200));
+        assert_eq!(
+            account_totals[0].allocation,
+            Decimal::from_str("0.5").unwrap()
+        );
+        assert!(account_totals[1].account.is_none());
+        assert_eq!(account_totals[1].asset_count, 2);
+    }
 }
@@ -0,0 +1,93 @@
+use serde::Serialize;
+
+use crate::{
+    entities::settings::Settings, utilities::cost_basis::get_cost_basis_method_name, Error,
+};
+
+use super::interactor::Interactor;
+
+pub trait ViewSettingsDataAccess {
+    async fn fetch_settings(&mut self) -> Result<Settings, Error>;
+}
+
+// NOTE: the proxy password is never sent back, only whether one is stored
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseProxySettings {
+    pub url: String,
+    pub username: Option<String>,
+    pub has_password: bool,
+    pub no_proxy: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseNetworkSettings {
+    pub proxy: Option<ResponseProxySettings>,
+    pub extra_ca_certificates: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ViewSettingsResponse {
+    pub fiat_rates_url: String,
+    pub crypto_rates_url: String,
+    pub reference_currency: String,
+    pub refresh_interval: u64,
+    pub data_location: Option<String>,
+    pub network: ResponseNetworkSettings,
+    pub cost_basis_method: String,
+    pub default_watchlist_id: Option<String>,
+    pub updated_at: String,
+}
+
+impl PartialEq for ViewSettingsResponse {
+    fn eq(&self, other: &Self) -> bool {
+        return self.fiat_rates_url == other.fiat_rates_url
+            && self.crypto_rates_url == other.crypto_rates_url
+            && self.reference_currency == other.reference_currency
+            && self.refresh_interval == other.refresh_interval
+            && self.data_location == other.data_location
+            && self.cost_basis_method == other.cost_basis_method
+            && self.default_watchlist_id == other.default_watchlist_id
+            && self.updated_at == other.updated_at;
+    }
+}
+
+pub struct ViewSettings<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<(), ViewSettingsResponse> for ViewSettings<DA>
+where
+    DA: ViewSettingsDataAccess,
+{
+    async fn perform(&mut self, _request: ()) -> Result<ViewSettingsResponse, Error> {
+        let settings = self.data_access.fetch_settings().await?;
+        return Ok(ViewSettingsResponse {
+            fiat_rates_url: settings.fiat_rates_url.clone(),
+            crypto_rates_url: settings.crypto_rates_url.clone(),
+            reference_currency: settings.reference_currency.clone(),
+            refresh_interval: settings.refresh_interval,
+            data_location: settings.data_location.clone(),
+            network: ResponseNetworkSettings {
+                proxy: settings
+                    .network
+                    .proxy
+                    .as_ref()
+                    .map(|p| ResponseProxySettings {
+                        url: p.url.clone(),
+                        username: p.username.clone(),
+                        has_password: p.password.is_some(),
+                        no_proxy: p.no_proxy.clone(),
+                    }),
+                extra_ca_certificates: settings.network.extra_ca_certificates.clone(),
+            },
+            cost_basis_method: get_cost_basis_method_name(&settings.cost_basis_method),
+            default_watchlist_id: settings.default_watchlist_id.clone(),
+            updated_at: settings.updated_at.clone(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
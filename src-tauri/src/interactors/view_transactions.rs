@@ -0,0 +1,122 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    entities::{asset::Asset, transaction::Transaction},
+    utilities::ledger::{
+        create_missing_opening_transactions, get_transaction_kind_name, is_transaction_of,
+        sort_transactions,
+    },
+    Error,
+};
+
+use super::interactor::Interactor;
+
+pub trait ViewTransactionsDataAccess {
+    async fn fetch_assets(&mut self) -> Result<Vec<Asset>, Error>;
+    async fn fetch_transactions(&mut self) -> Result<Vec<Transaction>, Error>;
+    async fn save_transaction(&mut self, transaction: &Transaction) -> Result<(), Error>;
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ViewTransactionsRequest {
+    pub asset_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseTransaction {
+    pub id: String,
+    pub asset_id: String,
+    pub coin: String,
+    pub kind: String,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub quantity: Decimal,
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub price: Option<Decimal>,
+    pub price_currency: Option<String>,
+    pub counterpart_asset_id: Option<String>,
+    pub executed_at: String,
+    pub notes: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ViewTransactionsResponse {
+    pub transactions: Vec<ResponseTransaction>,
+}
+
+pub struct ViewTransactions<DA> {
+    pub data_access: DA,
+}
+
+impl<DA> Interactor<ViewTransactionsRequest, ViewTransactionsResponse> for ViewTransactions<DA>
+where
+    DA: ViewTransactionsDataAccess,
+{
+    async fn perform(
+        &mut self,
+        request: ViewTransactionsRequest,
+    ) -> Result<ViewTransactionsResponse, Error> {
+        let assets = self.data_access.fetch_assets().await?;
+        let mut transactions = self.data_access.fetch_transactions().await?;
+
+        // NOTE: the ledgers of assets stored before transactions existed are opened here
+        let opening_transactions = create_missing_opening_transactions(&assets, &transactions);
+        for opening_transaction in &opening_transactions {
+            self.data_access
+                .save_transaction(opening_transaction)
+                .await?;
+        }
+        transactions.extend(opening_transactions);
+
+        if let Some(asset_id) = &request.asset_id {
+            if !assets.iter().any(|a| &a.id == asset_id) {
+                return Err(Error {
+                    message: String::from("Asset does not exist!"),
+                    ..Default::default()
+                });
+            }
+            transactions.retain(|t| is_transaction_of(t, asset_id));
+        }
+        sort_transactions(&mut transactions);
+        transactions.reverse();
+
+        return Ok(ViewTransactionsResponse {
+            transactions: transactions
+                .iter()
+                .map(|t| create_response_transaction(t, &assets))
+                .collect(),
+        });
+    }
+}
+
+fn create_response_transaction(
+    transaction: &Transaction,
+    assets: &Vec<Asset>,
+) -> ResponseTransaction {
+    let coin = assets
+        .iter()
+        .find(|a| a.id == transaction.asset_id)
+        .map(|a| a.coin.clone())
+        .unwrap_or_default();
+    return ResponseTransaction {
+        id: transaction.id.clone(),
+        asset_id: transaction.asset_id.clone(),
+        coin,
+        kind: get_transaction_kind_name(&transaction.kind),
+        quantity: transaction.quantity,
+        price: transaction.price,
+        price_currency: transaction.price_currency.clone(),
+        counterpart_asset_id: transaction.counterpart_asset_id.clone(),
+        executed_at: transaction.executed_at.clone(),
+        notes: transaction.notes.clone(),
+        created_at: transaction.created_at.clone(),
+        updated_at: transaction.updated_at.clone(),
+    };
+}
+
+#[cfg(test)]
+mod test {
+    // TODO: write tests for this `impl``
+}
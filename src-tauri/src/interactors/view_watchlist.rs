@@ -1,10 +1,20 @@
-use chrono::Utc;
-use serde::Serialize;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
-    entities::{pair::Pair, watchlist::Watchlist},
-    utilities::coin_market::CoinMarket,
+    entities::{currency::Currency, pair::Pair, rate_snapshot::RateSnapshot, watchlist::Watchlist},
+    utilities::{
+        coin_market::CoinMarket,
+        currency_catalogue::get_currency_kind_name,
+        currency_converter::{CurrencyConverter, DEFAULT_PIVOT},
+        fluctuation_windows::{get_fluctuation_history_start, get_fluctuation_windows},
+        manual_order::sort_pairs,
+        rate_history::RateHistory,
+        view_query::{matches_search, parse_sort_key, sort_items, SortFields, SortKey},
+        watchlist_selection::{select_watchlist, DEFAULT_WATCHLIST_NAME},
+    },
     Error,
 };
 
@@ -12,107 +22,290 @@ use super::interactor::Interactor;
 
 pub trait ViewWatchlistDataAccess {
     async fn update_pair(&mut self, pair: &Pair) -> Result<(), Error>;
-    async fn find_watchlist(&mut self) -> Result<Option<Watchlist>, Error>;
+    async fn fetch_watchlists(&mut self) -> Result<Vec<Watchlist>, Error>;
     async fn save_watchlist(&mut self, watchlist: &Watchlist) -> Result<(), Error>;
     async fn update_watchlist(&mut self, watchlist: &Watchlist) -> Result<(), Error>;
+    async fn update_daily_rate_snapshot(
+        &mut self,
+        rate_snapshot: &RateSnapshot,
+    ) -> Result<(), Error>;
+    async fn update_hourly_rate_snapshot(
+        &mut self,
+        rate_snapshot: &RateSnapshot,
+    ) -> Result<(), Error>;
+    async fn fetch_daily_rate_snapshots_since(
+        &mut self,
+        date_time: &DateTime<Utc>,
+    ) -> Result<Vec<RateSnapshot>, Error>;
+    async fn fetch_hourly_rate_snapshots(&mut self) -> Result<Vec<RateSnapshot>, Error>;
+}
+
+/*
+    NOTE: without a `watchlist_id` the default watchlist from the settings is shown.
+    The other fields narrow down and order its pairs and cross pairs alike, a pair
+    matching `coin` or `search` through its base or its comparison. Without
+    `sort_by` the pairs keep the order the user arranged.
+*/
+#[derive(Clone, Debug, Deserialize)]
+pub struct ViewWatchlistRequest {
+    pub watchlist_id: Option<String>,
+    pub sort_by: Option<String>,
+    #[serde(default)]
+    pub sort_descending: bool,
+    pub coin: Option<String>,
+    pub search: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseWatchlist {
+    pub id: String,
+    pub name: String,
+    pub position: u32,
+}
+
+// NOTE: the changes over the last hour, day, week and 30 days, `null` when no rates were recorded then
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseFluctuations {
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub hour: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub day: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub week: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub month: Option<Decimal>,
 }
 
 #[derive(Clone, Debug, Serialize)]
 pub struct ResponsePair {
     pub id: String,
-    pub fluctuation: f64,
-    pub value: f64,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub fluctuation: Decimal,
+    pub fluctuations: ResponseFluctuations,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub value: Decimal,
     pub base: String,
     pub comparison: String,
     pub created_at: String,
     pub updated_at: String,
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub struct ResponseCurrency {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub symbol: String,
+    pub kind: String,
+    pub decimals: u32,
+    pub icon_id: Option<String>,
+    pub provider_id: Option<String>,
+    pub ambiguous_with: Vec<String>,
+}
+
+impl PartialEq for ResponseCurrency {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.code == other.code
+            && self.name == other.name
+            && self.symbol == other.symbol
+            && self.kind == other.kind
+            && self.decimals == other.decimals
+            && self.icon_id == other.icon_id
+            && self.provider_id == other.provider_id
+            && self.ambiguous_with == other.ambiguous_with;
+    }
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct ViewWatchlistResponse {
+    pub watchlist_id: String,
+    pub watchlists: Vec<ResponseWatchlist>,
+    pub reference_currency: String,
     pub coins: Vec<String>,
+    pub currencies: Vec<ResponseCurrency>,
     pub pairs: Vec<ResponsePair>,
+    pub cross_pairs: Vec<ResponsePair>,
 }
 
 pub struct ViewWatchlist<DA, CM> {
     pub data_access: DA,
     pub coin_market: CM,
+    pub reference_currency: String,
+    pub default_watchlist_id: Option<String>,
 }
 
-impl<DA, CM> Interactor<(), ViewWatchlistResponse> for ViewWatchlist<DA, CM>
+impl<DA, CM> Interactor<ViewWatchlistRequest, ViewWatchlistResponse> for ViewWatchlist<DA, CM>
 where
     DA: ViewWatchlistDataAccess,
     CM: CoinMarket,
 {
-    async fn perform(&mut self, _request: ()) -> Result<ViewWatchlistResponse, Error> {
+    async fn perform(
+        &mut self,
+        request: ViewWatchlistRequest,
+    ) -> Result<ViewWatchlistResponse, Error> {
+        let mut maybe_sort_key: Option<SortKey> = None;
+        if let Some(sort_by) = &request.sort_by {
+            maybe_sort_key = Some(parse_sort_key(sort_by)?);
+        }
         let usd_pairs = self.coin_market.fetch_usd_pairs().await?;
-        if let Some(watchlist) = self.data_access.find_watchlist().await? {
-            let fresh_watchlist = refresh_watchlist(&usd_pairs, &watchlist);
-            for pair in &fresh_watchlist.pairs {
+        let currencies = self.coin_market.fetch_currencies().await?;
+        let watchlists = self.data_access.fetch_watchlists().await?;
+        let maybe_watchlist = select_watchlist(
+            &watchlists,
+            &request.watchlist_id,
+            &self.default_watchlist_id,
+        )?;
+        let now = Utc::now();
+        let mut rate_snapshots = self
+            .data_access
+            .fetch_daily_rate_snapshots_since(&get_fluctuation_history_start(&now))
+            .await?;
+        rate_snapshots.extend(self.data_access.fetch_hourly_rate_snapshots().await?);
+        let rate_history = RateHistory::new(&rate_snapshots);
+        let rate_snapshot = RateSnapshot {
+            pairs: usd_pairs.clone(),
+            created_at: now.to_rfc3339(),
+        };
+        self.data_access
+            .update_daily_rate_snapshot(&rate_snapshot)
+            .await?;
+        self.data_access
+            .update_hourly_rate_snapshot(&rate_snapshot)
+            .await?;
+        if let Some(watchlist) = maybe_watchlist {
+            let converter = CurrencyConverter::new(&usd_pairs, DEFAULT_PIVOT)?;
+            let fresh_watchlist =
+                refresh_watchlist(&converter, &watchlist, &self.reference_currency);
+            for pair in fresh_watchlist
+                .pairs
+                .iter()
+                .chain(fresh_watchlist.cross_pairs.iter())
+            {
                 self.data_access.update_pair(pair).await?;
             }
             self.data_access.update_watchlist(&fresh_watchlist).await?;
             return Ok(ViewWatchlistResponse {
-                pairs: create_response_pairs(&watchlist, &fresh_watchlist),
+                watchlist_id: watchlist.id.clone(),
+                watchlists: watchlists.iter().map(create_response_watchlist).collect(),
+                reference_currency: self.reference_currency.clone(),
+                pairs: list_response_pairs(
+                    &request,
+                    &maybe_sort_key,
+                    &currencies,
+                    create_response_pairs(
+                        &rate_history,
+                        &now,
+                        &watchlist.pairs,
+                        &fresh_watchlist.pairs,
+                    ),
+                ),
+                cross_pairs: list_response_pairs(
+                    &request,
+                    &maybe_sort_key,
+                    &currencies,
+                    create_response_pairs(
+                        &rate_history,
+                        &now,
+                        &watchlist.cross_pairs,
+                        &fresh_watchlist.cross_pairs,
+                    ),
+                ),
                 coins: usd_pairs.iter().map(|p| p.comparison.clone()).collect(),
+                currencies: currencies.iter().map(create_response_currency).collect(),
             });
         } else {
             let watchlist = Watchlist {
                 id: Uuid::new_v4().to_string(),
+                name: DEFAULT_WATCHLIST_NAME.to_string(),
+                position: 0,
                 pairs: vec![],
+                cross_pairs: vec![],
                 created_at: Utc::now().to_rfc3339(),
                 updated_at: Utc::now().to_rfc3339(),
             };
             self.data_access.save_watchlist(&watchlist).await?;
             return Ok(ViewWatchlistResponse {
+                watchlist_id: watchlist.id.clone(),
+                watchlists: vec![create_response_watchlist(&watchlist)],
+                reference_currency: self.reference_currency.clone(),
                 pairs: vec![],
+                cross_pairs: vec![],
                 coins: usd_pairs.iter().map(|p| p.comparison.clone()).collect(),
+                currencies: currencies.iter().map(create_response_currency).collect(),
             });
         };
     }
 }
 
-fn refresh_watchlist(usd_pairs: &Vec<Pair>, watchlist: &Watchlist) -> Watchlist {
+/*
+    NOTE: watched coins are quoted against the reference currency, so pairs stored
+    with another base (e.g. before the reference currency changed) are rebased,
+    while cross pairs keep their own base
+*/
+fn refresh_watchlist(
+    converter: &CurrencyConverter,
+    watchlist: &Watchlist,
+    reference_currency: &str,
+) -> Watchlist {
     let mut fresh_watchlist = Watchlist {
         id: watchlist.id.clone(),
-        pairs: vec![],
+        name: watchlist.name.clone(),
+        position: watchlist.position,
+        pairs: watchlist
+            .pairs
+            .iter()
+            .map(|p| refresh_pair(converter, p, reference_currency))
+            .collect(),
+        cross_pairs: watchlist
+            .cross_pairs
+            .iter()
+            .map(|p| refresh_pair(converter, p, &p.base))
+            .collect(),
         created_at: watchlist.created_at.clone(),
         updated_at: Utc::now().to_rfc3339(),
     };
-    /* TODO:
-        - check if pair is indeed an USD pair
-        - improve handling of cases where the watchlist pair has not been found in USD pairs
-    */
-    for pair in &watchlist.pairs {
-        let mut has_usd_pair = false;
-        for usd_pair in usd_pairs {
-            if usd_pair.comparison == pair.comparison {
-                has_usd_pair = true;
-                fresh_watchlist.pairs.push(Pair {
-                    id: pair.id.clone(),
-                    base: pair.base.clone(),
-                    value: usd_pair.value.clone(),
-                    comparison: pair.comparison.clone(),
-                    created_at: pair.created_at.clone(),
-                    updated_at: Utc::now().to_rfc3339(),
-                });
-                break;
-            }
-        }
-        if !has_usd_pair {
-            fresh_watchlist.pairs.push(pair.clone());
-        }
-    }
+    sort_pairs(&mut fresh_watchlist.pairs);
+    sort_pairs(&mut fresh_watchlist.cross_pairs);
     return fresh_watchlist;
 }
 
-fn create_response_pairs(watchlist: &Watchlist, fresh_watchlist: &Watchlist) -> Vec<ResponsePair> {
-    return fresh_watchlist
-        .pairs
+// NOTE: a pair whose currencies are no longer quoted keeps its last known value
+fn refresh_pair(converter: &CurrencyConverter, pair: &Pair, base: &str) -> Pair {
+    if let Ok(rate) = converter.get_rate(base, &pair.comparison) {
+        return Pair {
+            id: pair.id.clone(),
+            base: base.to_string(),
+            value: rate.value,
+            comparison: pair.comparison.clone(),
+            position: pair.position,
+            created_at: pair.created_at.clone(),
+            updated_at: Utc::now().to_rfc3339(),
+        };
+    }
+    return pair.clone();
+}
+
+fn create_response_watchlist(watchlist: &Watchlist) -> ResponseWatchlist {
+    return ResponseWatchlist {
+        id: watchlist.id.clone(),
+        name: watchlist.name.clone(),
+        position: watchlist.position,
+    };
+}
+
+fn create_response_pairs(
+    rate_history: &RateHistory,
+    now: &DateTime<Utc>,
+    pairs: &Vec<Pair>,
+    fresh_pairs: &Vec<Pair>,
+) -> Vec<ResponsePair> {
+    return fresh_pairs
         .iter()
         .map(|p| ResponsePair {
             id: p.id.clone(),
-            fluctuation: get_fluctuation(p, watchlist),
+            fluctuation: get_fluctuation(p, pairs),
+            fluctuations: create_response_fluctuations(rate_history, now, p),
             base: p.base.clone(),
             value: p.value.clone(),
             comparison: p.comparison.clone(),
@@ -122,15 +315,97 @@ fn create_response_pairs(watchlist: &Watchlist, fresh_watchlist: &Watchlist) ->
         .collect();
 }
 
-fn get_fluctuation(fresh_pair: &Pair, watchlist: &Watchlist) -> f64 {
-    let precision: f64 = 10_f64.powi(18);
-    for p in &watchlist.pairs {
-        if p.id == fresh_pair.id {
+fn list_response_pairs(
+    request: &ViewWatchlistRequest,
+    maybe_sort_key: &Option<SortKey>,
+    currencies: &Vec<Currency>,
+    response_pairs: Vec<ResponsePair>,
+) -> Vec<ResponsePair> {
+    let mut listed_pairs: Vec<ResponsePair> = response_pairs
+        .into_iter()
+        .filter(|p| matches_query(request, currencies, p))
+        .collect();
+    if let Some(sort_key) = maybe_sort_key {
+        sort_items(
+            &mut listed_pairs,
+            sort_key,
+            request.sort_descending,
+            create_sort_fields,
+        );
+    }
+    return listed_pairs;
+}
+
+fn matches_query(
+    request: &ViewWatchlistRequest,
+    currencies: &Vec<Currency>,
+    pair: &ResponsePair,
+) -> bool {
+    let codes = vec![pair.base.as_str(), pair.comparison.as_str()];
+    if let Some(coin) = &request.coin {
+        if !codes.contains(&coin.as_str()) {
+            return false;
+        }
+    }
+    if let Some(search) = &request.search {
+        if !matches_search(search, &codes, currencies) {
+            return false;
+        }
+    }
+    return true;
+}
+
+// NOTE: a pair is named after its comparison, since the base is shared by most of them
+fn create_sort_fields(pair: &ResponsePair) -> SortFields {
+    return SortFields {
+        value: pair.value,
+        change: pair.fluctuation,
+        name: pair.comparison.clone(),
+        created_at: pair.created_at.clone(),
+    };
+}
+
+fn create_response_fluctuations(
+    rate_history: &RateHistory,
+    now: &DateTime<Utc>,
+    pair: &Pair,
+) -> ResponseFluctuations {
+    let fluctuation_windows = get_fluctuation_windows(rate_history, now, pair.value, |c| {
+        return c
+            .get_rate(&pair.base, &pair.comparison)
+            .ok()
+            .map(|r| r.value);
+    });
+    return ResponseFluctuations {
+        hour: fluctuation_windows.hour,
+        day: fluctuation_windows.day,
+        week: fluctuation_windows.week,
+        month: fluctuation_windows.month,
+    };
+}
+
+fn create_response_currency(currency: &Currency) -> ResponseCurrency {
+    return ResponseCurrency {
+        id: currency.id.clone(),
+        code: currency.code.clone(),
+        name: currency.name.clone(),
+        symbol: currency.symbol.clone(),
+        kind: get_currency_kind_name(&currency.kind),
+        decimals: currency.decimals,
+        icon_id: currency.icon_id.clone(),
+        provider_id: currency.provider_id.clone(),
+        ambiguous_with: currency.ambiguous_with.clone(),
+    };
+}
+
+fn get_fluctuation(fresh_pair: &Pair, pairs: &Vec<Pair>) -> Decimal {
+    for p in pairs {
+        if p.id == fresh_pair.id && p.base == fresh_pair.base {
             let difference = fresh_pair.value - p.value;
-            return (difference * precision).round() / (p.value * precision);
+            return difference.checked_div(p.value).unwrap_or_default();
         }
     }
-    return 0.0;
+    return Decimal::ZERO;
 }
 
 #[cfg(test)]
@@ -1,25 +1,61 @@
+use std::sync::{Arc, Mutex, OnceLock};
+
 use directories::ProjectDirs;
+use entities::{network_settings::NetworkSettings, settings::Settings};
 use error::Error;
 use implementations::{
     data_access::file_system::file_system_data_access::FileSystemDataAccess,
-    utilities::coin_market::github_coin_market::GithubCoinMarket,
+    utilities::{
+        coin_market::github_coin_market::{GithubCoinMarket, MarketCache},
+        http_client::{HttpClient, HttpClientOptions},
+        settings_channel::SettingsChannel,
+    },
 };
 use interactors::{
+    add_transaction::{AddTransaction, AddTransactionRequest},
+    adjust_asset_quantities::{AdjustAssetQuantities, AdjustAssetQuantitiesRequest},
+    convert_amount::{ConvertAmount, ConvertAmountRequest},
+    delete_account::{DeleteAccount, DeleteAccountRequest},
     delete_asset::{DeleteAsset, DeleteAssetRequest},
+    delete_assets::{DeleteAssets, DeleteAssetsRequest},
     delete_pair_group::{DeletePairGroup, DeletePairGroupRequest},
     delete_tag::{DeleteTag, DeleteTagRequest},
+    delete_transaction::{DeleteTransaction, DeleteTransactionRequest},
+    delete_watchlist::{DeleteWatchlist, DeleteWatchlistRequest},
     delete_watchlist_pair::{DeleteWatchlistPair, DeleteWatchlistPairRequest},
+    export_tax_lots::{ExportTaxLots, ExportTaxLotsRequest},
     interactor::Interactor,
+    merge_assets::{MergeAssets, MergeAssetsRequest},
+    move_assets::{MoveAssets, MoveAssetsRequest},
+    reorder_pair_group_pairs::{ReorderPairGroupPairs, ReorderPairGroupPairsRequest},
+    reorder_pair_groups::{ReorderPairGroups, ReorderPairGroupsRequest},
+    reorder_watchlist_pairs::{ReorderWatchlistPairs, ReorderWatchlistPairsRequest},
+    reorder_watchlists::{ReorderWatchlists, ReorderWatchlistsRequest},
+    retag_assets::{RetagAssets, RetagAssetsRequest},
+    save_account::{SaveAccount, SaveAccountRequest},
     save_pair_group::{SavePairGroup, SavePairGroupRequest},
     save_tag::{SaveTag, SaveTagRequest},
+    save_watchlist::{SaveWatchlist, SaveWatchlistRequest},
     store_portfolios::{StorePortfolios, StorePortfoliosRequest},
     store_watchlist_coins::{StoreWatchlistCoins, StoreWatchlistCoinsRequest},
+    suggest_rebalance::{SuggestRebalance, SuggestRebalanceRequest},
     update_pair_group::{UpdatePairGroup, UpdatePairGroupRequest},
     update_portfolio::{UpdatePortfolio, UpdatePortfolioRequest},
-    view_pair_groups::ViewPairGroups,
-    view_portfolios::ViewPortfolios,
-    view_watchlist::ViewWatchlist,
+    update_settings::{UpdateSettings, UpdateSettingsRequest},
+    update_tag::{UpdateTag, UpdateTagRequest},
+    update_tag_targets::{UpdateTagTargets, UpdateTagTargetsRequest},
+    update_transaction::{UpdateTransaction, UpdateTransactionRequest},
+    update_watchlist::{UpdateWatchlist, UpdateWatchlistRequest},
+    view_currencies::ViewCurrencies,
+    view_pair_groups::{ViewPairGroups, ViewPairGroupsRequest},
+    view_portfolio_history::{ViewPortfolioHistory, ViewPortfolioHistoryRequest},
+    view_portfolios::{ViewPortfolios, ViewPortfoliosRequest},
+    view_settings::{ViewSettings, ViewSettingsDataAccess},
+    view_transactions::{ViewTransactions, ViewTransactionsRequest},
+    view_watchlist::{ViewWatchlist, ViewWatchlistRequest},
 };
+use tokio::sync::watch::Receiver;
+use utilities::default_settings::create_default_settings;
 
 mod entities;
 mod error;
@@ -29,29 +65,99 @@ mod utilities;
 
 /*
     TODO (NOT SURE):
-        - Try to reuse the 'coin_market' instance for all commands
         - Try to reuse the 'data_access' instance for all commands
 */
 
+static SETTINGS_CHANNEL: OnceLock<SettingsChannel> = OnceLock::new();
+static HTTP_CLIENT: Mutex<Option<HttpClient>> = Mutex::new(None);
+static MARKET_CACHE: OnceLock<MarketCache> = OnceLock::new();
+
 #[tauri::command]
-async fn view_pair_groups() -> Result<String, String> {
-    let coin_market = GithubCoinMarket {
-        fiat_rates_url: String::from("https://raw.githubusercontent.com/ARK-Builders/ark-exchange-rates/main/fiat-rates.json"),
-        crypto_rates_url: String::from("https://raw.githubusercontent.com/ARK-Builders/ark-exchange-rates/main/crypto-rates.json")
-    };
+async fn view_pair_groups(request: String) -> Result<String, String> {
+    let maybe_coin_market = create_github_coin_market();
+    if maybe_coin_market.is_err() {
+        return Err(serde_json::to_string(&maybe_coin_market.err().unwrap()).unwrap());
+    }
+    let coin_market = maybe_coin_market.unwrap();
     let data_access = create_fs_data_access();
     let mut interactor = ViewPairGroups {
         coin_market,
         data_access,
     };
-    let result = interactor.perform(()).await;
+    let parsed_request = serde_json::from_str::<ViewPairGroupsRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
     if result.is_err() {
         return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
     }
     return Ok(serde_json::to_string(&result.unwrap()).unwrap());
 }
 
-fn create_fs_data_access() -> FileSystemDataAccess {
+fn create_github_coin_market() -> Result<GithubCoinMarket, Error> {
+    let settings = get_settings_channel().get_settings();
+    let http_client = get_http_client(&settings.network)?;
+    return Ok(GithubCoinMarket {
+        fiat_rates_url: settings.fiat_rates_url.clone(),
+        crypto_rates_url: settings.crypto_rates_url.clone(),
+        refresh_interval: settings.refresh_interval,
+        http_client,
+        market_cache: get_market_cache(),
+    });
+}
+
+fn get_http_client(network_settings: &NetworkSettings) -> Result<HttpClient, Error> {
+    let mut http_client = HTTP_CLIENT.lock().unwrap();
+    if let Some(current_http_client) = http_client.as_ref() {
+        return Ok(current_http_client.clone());
+    }
+    let new_http_client = HttpClient::new(HttpClientOptions::default(), network_settings)?;
+    *http_client = Some(new_http_client.clone());
+    return Ok(new_http_client);
+}
+
+fn get_market_cache() -> MarketCache {
+    return MARKET_CACHE
+        .get_or_init(|| Arc::new(Mutex::new(None)))
+        .clone();
+}
+
+fn get_settings_channel() -> SettingsChannel {
+    return SETTINGS_CHANNEL
+        .get()
+        .expect("Settings were not loaded!")
+        .clone();
+}
+
+// NOTE: a broken settings file should not prevent the application from starting
+async fn load_settings() -> Settings {
+    let mut data_access = create_settings_data_access();
+    return data_access
+        .fetch_settings()
+        .await
+        .unwrap_or_else(|_| create_default_settings());
+}
+
+/*
+    NOTE: the services built from the settings are dropped when the values they
+    depend on change, so the next command rebuilds them from the new settings
+*/
+async fn watch_settings(mut receiver: Receiver<Settings>) {
+    let mut current_settings = receiver.borrow_and_update().clone();
+    while receiver.changed().await.is_ok() {
+        let settings = receiver.borrow_and_update().clone();
+        if settings.network != current_settings.network {
+            *HTTP_CLIENT.lock().unwrap() = None;
+        }
+        if settings.fiat_rates_url != current_settings.fiat_rates_url
+            || settings.crypto_rates_url != current_settings.crypto_rates_url
+        {
+            *get_market_cache().lock().unwrap() = None;
+        }
+        current_settings = settings;
+    }
+}
+
+// NOTE: the settings always live in the default location, since they tell where the rest is
+fn create_settings_data_access() -> FileSystemDataAccess {
     let dirs = get_project_dirs().unwrap();
     let data_access = FileSystemDataAccess {
         root: dirs.cache_dir().to_path_buf(),
@@ -59,11 +165,22 @@ fn create_fs_data_access() -> FileSystemDataAccess {
     return data_access;
 }
 
+fn create_fs_data_access() -> FileSystemDataAccess {
+    let settings = get_settings_channel().get_settings();
+    if let Some(data_location) = settings.data_location {
+        return FileSystemDataAccess {
+            root: data_location.into(),
+        };
+    }
+    return create_settings_data_access();
+}
+
 fn get_project_dirs() -> Result<ProjectDirs, Error> {
     let maybe_dirs = ProjectDirs::from("com", "ark-builders", "ark-rate-desktop");
     if maybe_dirs.is_none() {
         return Err(Error {
             message: String::from("Project directory was not found!"),
+            ..Default::default()
         });
     }
     return Ok(maybe_dirs.unwrap());
@@ -106,17 +223,22 @@ async fn delete_pair_group(request: String) -> Result<String, String> {
 }
 
 #[tauri::command]
-async fn view_portfolios() -> Result<String, String> {
-    let coin_market = GithubCoinMarket {
-        fiat_rates_url: String::from("https://raw.githubusercontent.com/ARK-Builders/ark-exchange-rates/main/fiat-rates.json"),
-        crypto_rates_url: String::from("https://raw.githubusercontent.com/ARK-Builders/ark-exchange-rates/main/crypto-rates.json")
-    };
+async fn view_portfolios(request: String) -> Result<String, String> {
+    let maybe_coin_market = create_github_coin_market();
+    if maybe_coin_market.is_err() {
+        return Err(serde_json::to_string(&maybe_coin_market.err().unwrap()).unwrap());
+    }
+    let coin_market = maybe_coin_market.unwrap();
     let data_access = create_fs_data_access();
+    let settings = get_settings_channel().get_settings();
     let mut interactor = ViewPortfolios {
         coin_market,
         data_access,
+        reference_currency: settings.reference_currency.clone(),
+        cost_basis_method: settings.cost_basis_method.clone(),
     };
-    let result = interactor.perform(()).await;
+    let parsed_request = serde_json::from_str::<ViewPortfoliosRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
     if result.is_err() {
         return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
     }
@@ -125,10 +247,11 @@ async fn view_portfolios() -> Result<String, String> {
 
 #[tauri::command]
 async fn store_portfolios(request: String) -> Result<String, String> {
-    let coin_market = GithubCoinMarket {
-        fiat_rates_url: String::from("https://raw.githubusercontent.com/ARK-Builders/ark-exchange-rates/main/fiat-rates.json"),
-        crypto_rates_url: String::from("https://raw.githubusercontent.com/ARK-Builders/ark-exchange-rates/main/crypto-rates.json")
-    };
+    let maybe_coin_market = create_github_coin_market();
+    if maybe_coin_market.is_err() {
+        return Err(serde_json::to_string(&maybe_coin_market.err().unwrap()).unwrap());
+    }
+    let coin_market = maybe_coin_market.unwrap();
     let data_access = create_fs_data_access();
     let mut interactor = StorePortfolios {
         coin_market,
@@ -168,8 +291,16 @@ async fn delete_tag(request: String) -> Result<String, String> {
 
 #[tauri::command]
 async fn update_portfolio(request: String) -> Result<String, String> {
+    let maybe_coin_market = create_github_coin_market();
+    if maybe_coin_market.is_err() {
+        return Err(serde_json::to_string(&maybe_coin_market.err().unwrap()).unwrap());
+    }
+    let coin_market = maybe_coin_market.unwrap();
     let data_access = create_fs_data_access();
-    let mut interactor = UpdatePortfolio { data_access };
+    let mut interactor = UpdatePortfolio {
+        coin_market,
+        data_access,
+    };
     let parsed_request = serde_json::from_str::<UpdatePortfolioRequest>(&request).unwrap();
     let result = interactor.perform(parsed_request).await;
     if result.is_err() {
@@ -191,17 +322,22 @@ async fn delete_asset(request: String) -> Result<String, String> {
 }
 
 #[tauri::command]
-async fn view_watchlist() -> Result<String, String> {
-    let coin_market = GithubCoinMarket {
-        fiat_rates_url: String::from("https://raw.githubusercontent.com/ARK-Builders/ark-exchange-rates/main/fiat-rates.json"),
-        crypto_rates_url: String::from("https://raw.githubusercontent.com/ARK-Builders/ark-exchange-rates/main/crypto-rates.json")
-    };
+async fn view_watchlist(request: String) -> Result<String, String> {
+    let maybe_coin_market = create_github_coin_market();
+    if maybe_coin_market.is_err() {
+        return Err(serde_json::to_string(&maybe_coin_market.err().unwrap()).unwrap());
+    }
+    let coin_market = maybe_coin_market.unwrap();
     let data_access = create_fs_data_access();
+    let settings = get_settings_channel().get_settings();
     let mut interactor = ViewWatchlist {
         coin_market,
         data_access,
+        reference_currency: settings.reference_currency.clone(),
+        default_watchlist_id: settings.default_watchlist_id.clone(),
     };
-    let result = interactor.perform(()).await;
+    let parsed_request = serde_json::from_str::<ViewWatchlistRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
     if result.is_err() {
         return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
     }
@@ -210,14 +346,18 @@ async fn view_watchlist() -> Result<String, String> {
 
 #[tauri::command]
 async fn store_watchlist_coins(request: String) -> Result<String, String> {
-    let coin_market = GithubCoinMarket {
-        fiat_rates_url: String::from("https://raw.githubusercontent.com/ARK-Builders/ark-exchange-rates/main/fiat-rates.json"),
-        crypto_rates_url: String::from("https://raw.githubusercontent.com/ARK-Builders/ark-exchange-rates/main/crypto-rates.json")
-    };
+    let maybe_coin_market = create_github_coin_market();
+    if maybe_coin_market.is_err() {
+        return Err(serde_json::to_string(&maybe_coin_market.err().unwrap()).unwrap());
+    }
+    let coin_market = maybe_coin_market.unwrap();
     let data_access = create_fs_data_access();
+    let settings = get_settings_channel().get_settings();
     let mut interactor = StoreWatchlistCoins {
         coin_market,
         data_access,
+        reference_currency: settings.reference_currency.clone(),
+        default_watchlist_id: settings.default_watchlist_id.clone(),
     };
     let parsed_request = serde_json::from_str::<StoreWatchlistCoinsRequest>(&request).unwrap();
     let result = interactor.perform(parsed_request).await;
@@ -230,7 +370,11 @@ async fn store_watchlist_coins(request: String) -> Result<String, String> {
 #[tauri::command]
 async fn delete_watchlist_pair(request: String) -> Result<String, String> {
     let data_access = create_fs_data_access();
-    let mut interactor = DeleteWatchlistPair { data_access };
+    let settings = get_settings_channel().get_settings();
+    let mut interactor = DeleteWatchlistPair {
+        data_access,
+        default_watchlist_id: settings.default_watchlist_id.clone(),
+    };
     let parsed_request = serde_json::from_str::<DeleteWatchlistPairRequest>(&request).unwrap();
     let result = interactor.perform(parsed_request).await;
     if result.is_err() {
@@ -239,8 +383,374 @@ async fn delete_watchlist_pair(request: String) -> Result<String, String> {
     return Ok(serde_json::to_string(&result.unwrap()).unwrap());
 }
 
+#[tauri::command]
+async fn convert_amount(request: String) -> Result<String, String> {
+    let maybe_coin_market = create_github_coin_market();
+    if maybe_coin_market.is_err() {
+        return Err(serde_json::to_string(&maybe_coin_market.err().unwrap()).unwrap());
+    }
+    let coin_market = maybe_coin_market.unwrap();
+    let mut interactor = ConvertAmount { coin_market };
+    let parsed_request = serde_json::from_str::<ConvertAmountRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn view_currencies() -> Result<String, String> {
+    let maybe_coin_market = create_github_coin_market();
+    if maybe_coin_market.is_err() {
+        return Err(serde_json::to_string(&maybe_coin_market.err().unwrap()).unwrap());
+    }
+    let coin_market = maybe_coin_market.unwrap();
+    let mut interactor = ViewCurrencies { coin_market };
+    let result = interactor.perform(()).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn view_settings() -> Result<String, String> {
+    let data_access = create_settings_data_access();
+    let mut interactor = ViewSettings { data_access };
+    let result = interactor.perform(()).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn update_settings(request: String) -> Result<String, String> {
+    let maybe_coin_market = create_github_coin_market();
+    if maybe_coin_market.is_err() {
+        return Err(serde_json::to_string(&maybe_coin_market.err().unwrap()).unwrap());
+    }
+    let coin_market = maybe_coin_market.unwrap();
+    let data_access = create_settings_data_access();
+    let settings_notifier = get_settings_channel();
+    let mut interactor = UpdateSettings {
+        data_access,
+        coin_market,
+        settings_notifier,
+    };
+    let parsed_request = serde_json::from_str::<UpdateSettingsRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn view_transactions(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = ViewTransactions { data_access };
+    let parsed_request = serde_json::from_str::<ViewTransactionsRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn add_transaction(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = AddTransaction { data_access };
+    let parsed_request = serde_json::from_str::<AddTransactionRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn update_transaction(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = UpdateTransaction { data_access };
+    let parsed_request = serde_json::from_str::<UpdateTransactionRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn delete_transaction(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = DeleteTransaction { data_access };
+    let parsed_request = serde_json::from_str::<DeleteTransactionRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn export_tax_lots(request: String) -> Result<String, String> {
+    let maybe_coin_market = create_github_coin_market();
+    if maybe_coin_market.is_err() {
+        return Err(serde_json::to_string(&maybe_coin_market.err().unwrap()).unwrap());
+    }
+    let coin_market = maybe_coin_market.unwrap();
+    let data_access = create_fs_data_access();
+    let settings = get_settings_channel().get_settings();
+    let mut interactor = ExportTaxLots {
+        coin_market,
+        data_access,
+        cost_basis_method: settings.cost_basis_method.clone(),
+    };
+    let parsed_request = serde_json::from_str::<ExportTaxLotsRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn view_portfolio_history(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let settings = get_settings_channel().get_settings();
+    let mut interactor = ViewPortfolioHistory {
+        data_access,
+        reference_currency: settings.reference_currency.clone(),
+    };
+    let parsed_request = serde_json::from_str::<ViewPortfolioHistoryRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn update_tag_targets(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = UpdateTagTargets { data_access };
+    let parsed_request = serde_json::from_str::<UpdateTagTargetsRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn suggest_rebalance(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let settings = get_settings_channel().get_settings();
+    let mut interactor = SuggestRebalance {
+        data_access,
+        reference_currency: settings.reference_currency.clone(),
+    };
+    let parsed_request = serde_json::from_str::<SuggestRebalanceRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn save_account(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = SaveAccount { data_access };
+    let parsed_request = serde_json::from_str::<SaveAccountRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn delete_account(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = DeleteAccount { data_access };
+    let parsed_request = serde_json::from_str::<DeleteAccountRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn move_assets(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = MoveAssets { data_access };
+    let parsed_request = serde_json::from_str::<MoveAssetsRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn update_tag(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = UpdateTag { data_access };
+    let parsed_request = serde_json::from_str::<UpdateTagRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn retag_assets(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = RetagAssets { data_access };
+    let parsed_request = serde_json::from_str::<RetagAssetsRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn delete_assets(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = DeleteAssets { data_access };
+    let parsed_request = serde_json::from_str::<DeleteAssetsRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn adjust_asset_quantities(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = AdjustAssetQuantities { data_access };
+    let parsed_request = serde_json::from_str::<AdjustAssetQuantitiesRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn merge_assets(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = MergeAssets { data_access };
+    let parsed_request = serde_json::from_str::<MergeAssetsRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn save_watchlist(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = SaveWatchlist { data_access };
+    let parsed_request = serde_json::from_str::<SaveWatchlistRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn update_watchlist(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = UpdateWatchlist { data_access };
+    let parsed_request = serde_json::from_str::<UpdateWatchlistRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn reorder_watchlists(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = ReorderWatchlists { data_access };
+    let parsed_request = serde_json::from_str::<ReorderWatchlistsRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn delete_watchlist(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = DeleteWatchlist { data_access };
+    let parsed_request = serde_json::from_str::<DeleteWatchlistRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn reorder_pair_groups(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = ReorderPairGroups { data_access };
+    let parsed_request = serde_json::from_str::<ReorderPairGroupsRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn reorder_pair_group_pairs(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let mut interactor = ReorderPairGroupPairs { data_access };
+    let parsed_request = serde_json::from_str::<ReorderPairGroupPairsRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
+#[tauri::command]
+async fn reorder_watchlist_pairs(request: String) -> Result<String, String> {
+    let data_access = create_fs_data_access();
+    let settings = get_settings_channel().get_settings();
+    let mut interactor = ReorderWatchlistPairs {
+        data_access,
+        default_watchlist_id: settings.default_watchlist_id.clone(),
+    };
+    let parsed_request = serde_json::from_str::<ReorderWatchlistPairsRequest>(&request).unwrap();
+    let result = interactor.perform(parsed_request).await;
+    if result.is_err() {
+        return Err(serde_json::to_string(&result.unwrap_err()).unwrap());
+    }
+    return Ok(serde_json::to_string(&result.unwrap()).unwrap());
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    let settings = tauri::async_runtime::block_on(load_settings());
+    let settings_channel = SETTINGS_CHANNEL.get_or_init(|| SettingsChannel::new(settings));
+    tauri::async_runtime::spawn(watch_settings(settings_channel.subscribe()));
+
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .invoke_handler(tauri::generate_handler![
@@ -257,6 +767,33 @@ pub fn run() {
             view_watchlist,
             store_watchlist_coins,
             delete_watchlist_pair,
+            convert_amount,
+            view_currencies,
+            view_settings,
+            update_settings,
+            view_transactions,
+            add_transaction,
+            update_transaction,
+            delete_transaction,
+            export_tax_lots,
+            view_portfolio_history,
+            update_tag_targets,
+            suggest_rebalance,
+            save_account,
+            delete_account,
+            move_assets,
+            update_tag,
+            retag_assets,
+            delete_assets,
+            adjust_asset_quantities,
+            merge_assets,
+            save_watchlist,
+            update_watchlist,
+            reorder_watchlists,
+            delete_watchlist,
+            reorder_pair_groups,
+            reorder_pair_group_pairs,
+            reorder_watchlist_pairs,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
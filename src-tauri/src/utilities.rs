@@ -1 +1,15 @@
 pub mod coin_market;
+pub mod cost_basis;
+pub mod currency_catalogue;
+pub mod currency_converter;
+pub mod default_settings;
+pub mod fluctuation_windows;
+pub mod ledger;
+pub mod manual_order;
+pub mod portfolio_validation;
+pub mod rate_history;
+pub mod rounding_policy;
+pub mod settings_notifier;
+pub mod tag_hierarchy;
+pub mod view_query;
+pub mod watchlist_selection;
@@ -1,5 +1,39 @@
-use crate::{entities::pair::Pair, Error};
+use crate::{
+    entities::{currency::Currency, pair::Pair},
+    Error,
+};
+
+#[derive(Clone, Debug)]
+pub struct SkippedRecord {
+    pub key: String,
+    pub reason: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchReport {
+    pub source: String,
+    pub url: String,
+    pub status: u16,
+    pub fetched_at: String,
+    pub accepted_records: usize,
+    pub skipped_records: Vec<SkippedRecord>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MarketFetch {
+    pub pairs: Vec<Pair>,
+    pub currencies: Vec<Currency>,
+    pub reports: Vec<FetchReport>,
+}
 
 pub trait CoinMarket {
-    async fn fetch_usd_pairs(&mut self) -> Result<Vec<Pair>, Error>;
+    async fn fetch_market(&mut self) -> Result<MarketFetch, Error>;
+
+    async fn fetch_usd_pairs(&mut self) -> Result<Vec<Pair>, Error> {
+        return Ok(self.fetch_market().await?.pairs);
+    }
+
+    async fn fetch_currencies(&mut self) -> Result<Vec<Currency>, Error> {
+        return Ok(self.fetch_market().await?.currencies);
+    }
 }
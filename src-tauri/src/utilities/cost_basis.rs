@@ -0,0 +1,481 @@
+use chrono::{DateTime, Duration, NaiveDate};
+use rust_decimal::Decimal;
+
+use crate::{
+    entities::{
+        settings::CostBasisMethod,
+        transaction::{Transaction, TransactionKind},
+    },
+    Error,
+};
+
+use super::{
+    currency_converter::CurrencyConverter,
+    ledger::sort_transactions,
+    rate_history::{RateHistory, DAILY_RATE_TOLERANCE_DAYS},
+};
+
+// NOTE: a unit price, and whether it was converted at the rates of its transaction date
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnitPrice {
+    pub value: Decimal,
+    pub is_rate_historical: bool,
+}
+
+// NOTE: `unit_cost` is expressed in the currency the report was created for
+#[derive(Clone, Debug)]
+pub struct Lot {
+    pub transaction_id: String,
+    pub asset_id: String,
+    pub acquired_at: String,
+    pub quantity: Decimal,
+    pub unit_cost: Decimal,
+    pub is_cost_known: bool,
+    pub is_rate_historical: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct Disposal {
+    pub transaction_id: String,
+    pub asset_id: String,
+    pub quantity: Decimal,
+    pub acquired_at: String,
+    pub disposed_at: String,
+    pub proceeds: Decimal,
+    pub cost_basis: Decimal,
+    pub is_cost_known: bool,
+    pub is_rate_historical: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct CostBasisReport {
+    pub lots: Vec<Lot>,
+    pub disposals: Vec<Disposal>,
+}
+
+impl CostBasisReport {
+    pub fn get_cost_basis(&self, asset_id: &str) -> Decimal {
+        return self
+            .lots
+            .iter()
+            .filter(|l| l.asset_id == asset_id)
+            .map(|l| l.quantity * l.unit_cost)
+            .sum::<Decimal>();
+    }
+
+    pub fn get_realized_pnl(&self, asset_id: &str) -> Decimal {
+        return self
+            .disposals
+            .iter()
+            .filter(|d| d.asset_id == asset_id)
+            .map(|d| d.proceeds - d.cost_basis)
+            .sum::<Decimal>();
+    }
+
+    pub fn has_unknown_cost(&self, asset_id: &str) -> bool {
+        return self
+            .lots
+            .iter()
+            .any(|l| l.asset_id == asset_id && !l.is_cost_known)
+            || self
+                .disposals
+                .iter()
+                .any(|d| d.asset_id == asset_id && !d.is_cost_known);
+    }
+}
+
+pub fn parse_cost_basis_method(method: &str) -> Result<CostBasisMethod, Error> {
+    match method {
+        "fifo" => return Ok(CostBasisMethod::Fifo),
+        "lifo" => return Ok(CostBasisMethod::Lifo),
+        "average_cost" => return Ok(CostBasisMethod::AverageCost),
+        _ => {
+            return Err(Error {
+                message: format!("Unknown cost basis method '{}'!", method),
+                ..Default::default()
+            })
+        }
+    }
+}
+
+pub fn get_cost_basis_method_name(method: &CostBasisMethod) -> String {
+    let name = match method {
+        CostBasisMethod::Fifo => "fifo",
+        CostBasisMethod::Lifo => "lifo",
+        CostBasisMethod::AverageCost => "average_cost",
+    };
+    return name.to_string();
+}
+
+/*
+    The price of one unit of the transaction in `currency`, at the daily rates
+    recorded around the transaction date. Transactions from before the rates were
+    recorded fall back to the current rates of `converter`, which is flagged so
+    their values are not mistaken for historical ones.
+*/
+pub fn convert_unit_price(
+    transaction: &Transaction,
+    rate_history: &RateHistory,
+    converter: &CurrencyConverter,
+    currency: &str,
+) -> Option<UnitPrice> {
+    let price = transaction.price?;
+    let price_currency = transaction.price_currency.as_ref()?;
+    if price_currency == currency {
+        return Some(UnitPrice {
+            value: price,
+            is_rate_historical: true,
+        });
+    }
+    let maybe_rate = rate_history
+        .find_converter_within(
+            &transaction.executed_at,
+            Duration::days(DAILY_RATE_TOLERANCE_DAYS),
+        )
+        .and_then(|c| c.get_rate(price_currency, currency).ok());
+    if let Some(rate) = maybe_rate {
+        return Some(UnitPrice {
+            value: price * rate.value,
+            is_rate_historical: true,
+        });
+    }
+    let rate = converter.get_rate(price_currency, currency).ok()?;
+    return Some(UnitPrice {
+        value: price * rate.value,
+        is_rate_historical: false,
+    });
+}
+
+// NOTE: the dates of the daily rates `convert_unit_price` may use for the transactions
+pub fn get_unit_price_rate_dates(transactions: &Vec<Transaction>) -> Vec<NaiveDate> {
+    let mut dates: Vec<NaiveDate> = vec![];
+    for transaction in transactions {
+        if transaction.price.is_none() {
+            continue;
+        }
+        if let Ok(executed_at) = DateTime::parse_from_rfc3339(&transaction.executed_at) {
+            for day in -DAILY_RATE_TOLERANCE_DAYS..=DAILY_RATE_TOLERANCE_DAYS {
+                let date = (executed_at + Duration::days(day)).date_naive();
+                if !dates.contains(&date) {
+                    dates.push(date);
+                }
+            }
+        }
+    }
+    return dates;
+}
+
+/*
+    Replays every ledger in chronological order, turning acquisitions into lots
+    and matching sells, withdrawals, fees and transfers against them with the
+    given method. Transfers carry their lots over to the destination asset, so the
+    original acquisition cost and date are kept. Only sells are disposals, since
+    withdrawals and fees do not bring any proceeds.
+
+    Acquisitions without a price, like opening balances, still make lots, but
+    with an unknown cost counted as zero.
+*/
+pub fn create_cost_basis_report(
+    transactions: &Vec<Transaction>,
+    method: &CostBasisMethod,
+    get_unit_price: impl Fn(&Transaction) -> Option<UnitPrice>,
+) -> Result<CostBasisReport, Error> {
+    let mut sorted_transactions = transactions.clone();
+    sort_transactions(&mut sorted_transactions);
+    let mut report = CostBasisReport {
+        lots: vec![],
+        disposals: vec![],
+    };
+    for transaction in &sorted_transactions {
+        match transaction.kind {
+            TransactionKind::Buy | TransactionKind::Deposit => {
+                let maybe_unit_price = get_unit_price(transaction);
+                report.lots.push(Lot {
+                    transaction_id: transaction.id.clone(),
+                    asset_id: transaction.asset_id.clone(),
+                    acquired_at: transaction.executed_at.clone(),
+                    quantity: transaction.quantity,
+                    unit_cost: maybe_unit_price
+                        .as_ref()
+                        .map(|p| p.value)
+                        .unwrap_or_default(),
+                    is_cost_known: maybe_unit_price.is_some(),
+                    is_rate_historical: maybe_unit_price
+                        .as_ref()
+                        .map(|p| p.is_rate_historical)
+                        .unwrap_or(true),
+                });
+            }
+            TransactionKind::Sell => {
+                let maybe_unit_price = get_unit_price(transaction);
+                let unit_proceeds = maybe_unit_price
+                    .as_ref()
+                    .map(|p| p.value)
+                    .unwrap_or_default();
+                let is_proceeds_rate_historical = maybe_unit_price
+                    .as_ref()
+                    .map(|p| p.is_rate_historical)
+                    .unwrap_or(true);
+                let taken_lots = take_lots(&mut report.lots, transaction, method)?;
+                for taken_lot in &taken_lots {
+                    report.disposals.push(Disposal {
+                        transaction_id: transaction.id.clone(),
+                        asset_id: transaction.asset_id.clone(),
+                        quantity: taken_lot.quantity,
+                        acquired_at: taken_lot.acquired_at.clone(),
+                        disposed_at: transaction.executed_at.clone(),
+                        proceeds: taken_lot.quantity * unit_proceeds,
+                        cost_basis: taken_lot.quantity * taken_lot.unit_cost,
+                        is_cost_known: taken_lot.is_cost_known && maybe_unit_price.is_some(),
+                        is_rate_historical: taken_lot.is_rate_historical
+                            && is_proceeds_rate_historical,
+                    });
+                }
+            }
+            TransactionKind::Transfer => {
+                let taken_lots = take_lots(&mut report.lots, transaction, method)?;
+                for mut taken_lot in taken_lots {
+                    taken_lot.asset_id = transaction.counterpart_asset_id.clone().unwrap();
+                    report.lots.push(taken_lot);
+                }
+            }
+            TransactionKind::Withdraw | TransactionKind::Fee => {
+                take_lots(&mut report.lots, transaction, method)?;
+            }
+        }
+    }
+    return Ok(report);
+}
+
+/*
+    NOTE: removes `transaction.quantity` from the lots of the asset, splitting the
+    last lot if needed. With the average cost method the lots are still consumed
+    in acquisition order, which only matters for their dates, while every taken
+    and remaining lot ends up with the average unit cost of the pool.
+*/
+fn take_lots(
+    lots: &mut Vec<Lot>,
+    transaction: &Transaction,
+    method: &CostBasisMethod,
+) -> Result<Vec<Lot>, Error> {
+    let mut lot_idxs: Vec<usize> = (0..lots.len())
+        .filter(|idx| lots[*idx].asset_id == transaction.asset_id)
+        .collect();
+    lot_idxs.sort_by_key(|idx| DateTime::parse_from_rfc3339(&lots[*idx].acquired_at).ok());
+    if *method == CostBasisMethod::Lifo {
+        lot_idxs.reverse();
+    }
+
+    if *method == CostBasisMethod::AverageCost {
+        let pool_quantity = lot_idxs
+            .iter()
+            .map(|idx| lots[*idx].quantity)
+            .sum::<Decimal>();
+        let pool_cost = lot_idxs
+            .iter()
+            .map(|idx| lots[*idx].quantity * lots[*idx].unit_cost)
+            .sum::<Decimal>();
+        let is_pool_cost_known = lot_idxs.iter().all(|idx| lots[*idx].is_cost_known);
+        let is_pool_rate_historical = lot_idxs.iter().all(|idx| lots[*idx].is_rate_historical);
+        let average_unit_cost = pool_cost.checked_div(pool_quantity).unwrap_or_default();
+        for idx in &lot_idxs {
+            lots[*idx].unit_cost = average_unit_cost;
+            lots[*idx].is_cost_known = is_pool_cost_known;
+            lots[*idx].is_rate_historical = is_pool_rate_historical;
+        }
+    }
+
+    let mut taken_lots: Vec<Lot> = vec![];
+    let mut remaining_quantity = transaction.quantity;
+    for idx in &lot_idxs {
+        if remaining_quantity <= Decimal::ZERO {
+            break;
+        }
+        let lot = &mut lots[*idx];
+        let taken_quantity = remaining_quantity.min(lot.quantity);
+        let mut taken_lot = lot.clone();
+        taken_lot.quantity = taken_quantity;
+        taken_lots.push(taken_lot);
+        lot.quantity -= taken_quantity;
+        remaining_quantity -= taken_quantity;
+    }
+    if remaining_quantity > Decimal::ZERO {
+        return Err(Error {
+            message: format!(
+                "The transaction of {} exceeds the holdings of its asset!",
+                transaction.executed_at
+            ),
+            ..Default::default()
+        });
+    }
+    lots.retain(|l| l.quantity > Decimal::ZERO);
+    return Ok(taken_lots);
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use crate::entities::{pair::Pair, rate_snapshot::RateSnapshot};
+
+    use super::*;
+
+    fn create_pair(comparison: &str, value: &str) -> Pair {
+        return Pair {
+            id: comparison.to_lowercase(),
+            value: Decimal::from_str(value).unwrap(),
+            base: String::from("USD"),
+            comparison: comparison.to_string(),
+            position: 0,
+            created_at: String::from("2024-01-01T00:00:00+00:00"),
+            updated_at: String::from("2024-01-01T00:00:00+00:00"),
+        };
+    }
+
+    fn create_transaction(
+        kind: TransactionKind,
+        quantity: i64,
+        price: Option<i64>,
+        executed_at: &str,
+    ) -> Transaction {
+        return Transaction {
+            id: executed_at.to_string(),
+            asset_id: String::from("a1"),
+            kind,
+            quantity: Decimal::from(quantity),
+            price: price.map(Decimal::from),
+            price_currency: price.map(|_| String::from("USD")),
+            counterpart_asset_id: None,
+            executed_at: executed_at.to_string(),
+            notes: String::new(),
+            created_at: executed_at.to_string(),
+            updated_at: executed_at.to_string(),
+        };
+    }
+
+    #[test]
+    fn test_convert_unit_price() {
+        /*
+            Unit test expectations:
+
+            - Prices are converted at the daily rates recorded around their date.
+            - Without daily rates close enough, the current rates are used and flagged.
+            - Prices already in the currency are not converted.
+        */
+        let rate_history = RateHistory::new(&vec![RateSnapshot {
+            pairs: vec![create_pair("EUR", "0.5")],
+            created_at: String::from("2024-01-02T00:00:00+00:00"),
+        }]);
+        let converter = CurrencyConverter::new(&vec![create_pair("EUR", "0.8")], "USD").unwrap();
+        let mut transaction = create_transaction(
+            TransactionKind::Buy,
+            1,
+            Some(100),
+            "2024-01-01T00:00:00+00:00",
+        );
+        assert_eq!(
+            convert_unit_price(&transaction, &rate_history, &converter, "EUR"),
+            Some(UnitPrice {
+                value: Decimal::from(50),
+                is_rate_historical: true,
+            })
+        );
+        transaction.executed_at = String::from("2023-06-01T00:00:00+00:00");
+        assert_eq!(
+            convert_unit_price(&transaction, &rate_history, &converter, "EUR"),
+            Some(UnitPrice {
+                value: Decimal::from(80),
+                is_rate_historical: false,
+            })
+        );
+        assert_eq!(
+            convert_unit_price(&transaction, &rate_history, &converter, "USD"),
+            Some(UnitPrice {
+                value: Decimal::from(100),
+                is_rate_historical: true,
+            })
+        );
+        assert_eq!(get_unit_price_rate_dates(&vec![transaction]).len(), 7);
+    }
+
+    #[test]
+    fn test_create_cost_basis_report() {
+        /*
+            Unit test expectations:
+
+            - FIFO sells the oldest lots first, LIFO the newest ones.
+            - Average cost sells at the average unit cost and re-prices the remaining lots.
+            - Transfers keep the cost of the lots they move.
+            - Lots and disposals priced at current rates are flagged, also once pooled.
+        */
+        let mut transfer = create_transaction(
+            TransactionKind::Transfer,
+            1,
+            None,
+            "2024-01-04T00:00:00+00:00",
+        );
+        transfer.counterpart_asset_id = Some(String::from("a2"));
+        let transactions = vec![
+            create_transaction(
+                TransactionKind::Buy,
+                2,
+                Some(100),
+                "2024-01-01T00:00:00+00:00",
+            ),
+            create_transaction(
+                TransactionKind::Buy,
+                2,
+                Some(200),
+                "2024-01-02T00:00:00+00:00",
+            ),
+            create_transaction(
+                TransactionKind::Sell,
+                3,
+                Some(300),
+                "2024-01-03T00:00:00+00:00",
+            ),
+            transfer,
+        ];
+        let get_unit_price = |t: &Transaction| {
+            return t.price.map(|value| UnitPrice {
+                value,
+                is_rate_historical: t.executed_at != "2024-01-02T00:00:00+00:00",
+            });
+        };
+
+        let report =
+            create_cost_basis_report(&transactions, &CostBasisMethod::Fifo, get_unit_price)
+                .unwrap();
+        assert_eq!(report.get_realized_pnl("a1"), Decimal::from(500));
+        assert_eq!(report.get_cost_basis("a1"), Decimal::ZERO);
+        assert_eq!(report.get_cost_basis("a2"), Decimal::from(200));
+        assert_eq!(report.lots[0].acquired_at, "2024-01-02T00:00:00+00:00");
+
+        let report =
+            create_cost_basis_report(&transactions, &CostBasisMethod::Lifo, get_unit_price)
+                .unwrap();
+        assert_eq!(report.get_realized_pnl("a1"), Decimal::from(400));
+        assert_eq!(report.get_cost_basis("a2"), Decimal::from(100));
+
+        let report =
+            create_cost_basis_report(&transactions, &CostBasisMethod::AverageCost, get_unit_price)
+                .unwrap();
+        assert_eq!(report.get_realized_pnl("a1"), Decimal::from(450));
+        assert_eq!(report.get_cost_basis("a2"), Decimal::from(150));
+        assert!(!report.has_unknown_cost("a2"));
+        assert!(report.disposals.iter().all(|d| !d.is_rate_historical));
+
+        let mut transactions = transactions.clone();
+        transactions.push(create_transaction(
+            TransactionKind::Deposit,
+            1,
+            None,
+            "2024-01-05T00:00:00+00:00",
+        ));
+        let report =
+            create_cost_basis_report(&transactions, &CostBasisMethod::Fifo, get_unit_price)
+                .unwrap();
+        assert!(report.has_unknown_cost("a1"));
+        assert_eq!(report.get_cost_basis("a1"), Decimal::ZERO);
+    }
+}
@@ -0,0 +1,144 @@
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use crate::entities::currency::{Currency, CurrencyKind};
+
+const BUNDLED_CURRENCIES: &str = include_str!("../../resources/currencies.json");
+
+pub const DEFAULT_FIAT_DECIMALS: u32 = 2;
+
+pub const DEFAULT_CRYPTO_DECIMALS: u32 = 8;
+
+#[derive(Deserialize)]
+struct BundledCurrency {
+    pub code: String,
+    pub name: String,
+    pub symbol: String,
+    pub kind: String,
+    pub decimals: u32,
+    pub icon_id: Option<String>,
+    pub provider_id: Option<String>,
+}
+
+static CATALOGUE: OnceLock<Vec<Currency>> = OnceLock::new();
+
+/*
+    NOTE: the bundled table is compiled into the binary, so failing to parse it is a
+    programming error rather than something the user can recover from
+*/
+pub fn get_bundled_currencies() -> &'static Vec<Currency> {
+    return CATALOGUE.get_or_init(|| {
+        let bundled_currencies: Vec<BundledCurrency> = serde_json::from_str(BUNDLED_CURRENCIES)
+            .expect("Could not parse the bundled currency table!");
+        return bundled_currencies
+            .into_iter()
+            .map(|bc| Currency {
+                id: bc.code.clone(),
+                code: bc.code,
+                name: bc.name,
+                symbol: bc.symbol,
+                kind: if bc.kind == "crypto" {
+                    CurrencyKind::Crypto
+                } else {
+                    CurrencyKind::Fiat
+                },
+                decimals: bc.decimals,
+                icon_id: bc.icon_id,
+                provider_id: bc.provider_id,
+                ambiguous_with: vec![],
+            })
+            .collect();
+    });
+}
+
+pub fn find_bundled_currency(code: &str) -> Option<&'static Currency> {
+    return get_bundled_currencies().iter().find(|c| c.code == code);
+}
+
+pub fn get_currency_kind_name(kind: &CurrencyKind) -> String {
+    let name = match kind {
+        CurrencyKind::Fiat => "fiat",
+        CurrencyKind::Crypto => "crypto",
+    };
+    return name.to_string();
+}
+
+pub fn create_fiat_currency(code: &str) -> Currency {
+    if let Some(currency) = find_bundled_currency(code) {
+        if currency.kind == CurrencyKind::Fiat {
+            return currency.clone();
+        }
+    }
+    return Currency {
+        id: code.to_string(),
+        code: code.to_string(),
+        name: code.to_string(),
+        symbol: code.to_string(),
+        kind: CurrencyKind::Fiat,
+        decimals: DEFAULT_FIAT_DECIMALS,
+        icon_id: None,
+        provider_id: None,
+        ambiguous_with: vec![],
+    };
+}
+
+pub fn find_bundled_crypto_currency(
+    code: &str,
+    provider_id: Option<&str>,
+) -> Option<&'static Currency> {
+    return get_bundled_currencies().iter().find(|c| {
+        return c.code == code
+            && c.kind == CurrencyKind::Crypto
+            && (provider_id.is_none() || c.provider_id.as_deref() == provider_id);
+    });
+}
+
+pub fn create_crypto_currency(
+    code: &str,
+    name: Option<&str>,
+    provider_id: Option<&str>,
+) -> Currency {
+    if let Some(currency) = find_bundled_crypto_currency(code, provider_id) {
+        return currency.clone();
+    }
+    return Currency {
+        id: code.to_string(),
+        code: code.to_string(),
+        name: name.unwrap_or(code).to_string(),
+        symbol: code.to_string(),
+        kind: CurrencyKind::Crypto,
+        decimals: DEFAULT_CRYPTO_DECIMALS,
+        icon_id: provider_id.map(|p| p.to_string()),
+        provider_id: provider_id.map(|p| p.to_string()),
+        ambiguous_with: vec![],
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bundled_currencies() {
+        /*
+            Unit test expectations:
+
+            - The bundled table parses and contains both fiat and crypto currencies.
+            - Codes missing from the table fall back to sensible defaults per kind.
+        */
+        let usd = find_bundled_currency("USD").unwrap();
+        assert_eq!(usd.name, "US Dollar");
+        assert_eq!(usd.kind, CurrencyKind::Fiat);
+        assert_eq!(usd.decimals, 2);
+
+        let btc = find_bundled_currency("BTC").unwrap();
+        assert_eq!(btc.kind, CurrencyKind::Crypto);
+        assert_eq!(btc.decimals, 8);
+
+        let unknown = create_crypto_currency("XYZ", Some("Some Token"), Some("some-token"));
+        assert_eq!(unknown.name, "Some Token");
+        assert_eq!(unknown.decimals, DEFAULT_CRYPTO_DECIMALS);
+        assert_eq!(unknown.icon_id, Some(String::from("some-token")));
+    }
+}
@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+
+use crate::{entities::pair::Pair, Error};
+
+use super::rounding_policy::{round_amount, round_rate};
+
+pub const DEFAULT_PIVOT: &str = "USD";
+
+#[derive(Clone, Debug)]
+pub struct Rate {
+    pub from: String,
+    pub to: String,
+    pub value: Decimal,
+    pub path: Vec<String>,
+    pub updated_at: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct Conversion {
+    pub amount: Decimal,
+    pub converted_amount: Decimal,
+    pub rate: Rate,
+}
+
+/*
+    Resolves the rate between any two currencies by triangulating through a pivot
+    currency. The pairs given to the converter must all share the same base, which
+    is usually `USD` since that is what the coin market provides.
+*/
+#[derive(Clone, Debug)]
+pub struct CurrencyConverter {
+    pub pivot: String,
+    pivot_pairs: HashMap<String, Pair>,
+}
+
+impl CurrencyConverter {
+    pub fn new(pairs: &Vec<Pair>, pivot: &str) -> Result<CurrencyConverter, Error> {
+        let mut pivot_pairs: HashMap<String, Pair> = HashMap::new();
+        if pairs.len() == 0 {
+            return Ok(CurrencyConverter {
+                pivot: pivot.to_string(),
+                pivot_pairs,
+            });
+        }
+        let base = pairs[0].base.clone();
+        if pairs.iter().any(|p| p.base != base) {
+            return Err(Error {
+                message: String::from("Cannot convert through pairs with different bases!"),
+                ..Default::default()
+            });
+        }
+        if base == pivot {
+            for pair in pairs {
+                pivot_pairs.insert(pair.comparison.clone(), pair.clone());
+            }
+        } else {
+            let maybe_pivot_pair = pairs.iter().find(|p| p.comparison == pivot);
+            if maybe_pivot_pair.is_none() {
+                return Err(Error {
+                    message: format!("Could not find a rate for the pivot currency '{}'!", pivot),
+                    ..Default::default()
+                });
+            }
+            let pivot_pair = maybe_pivot_pair.unwrap();
+            if pivot_pair.value.is_zero() {
+                return Err(Error {
+                    message: format!("The rate for the pivot currency '{}' is zero!", pivot),
+                    ..Default::default()
+                });
+            }
+            for pair in pairs {
+                if pair.comparison == pivot {
+                    continue;
+                }
+                pivot_pairs.insert(
+                    pair.comparison.clone(),
+                    Pair {
+                        id: pair.id.clone(),
+                        base: pivot.to_string(),
+                        value: round_rate(pair.value / pivot_pair.value),
+                        comparison: pair.comparison.clone(),
+                        position: 0,
+                        created_at: pair.created_at.clone(),
+                        updated_at: oldest_timestamp(&pair.updated_at, &pivot_pair.updated_at),
+                    },
+                );
+            }
+            pivot_pairs.insert(
+                base.clone(),
+                Pair {
+                    id: pivot_pair.id.clone(),
+                    base: pivot.to_string(),
+                    value: round_rate(Decimal::ONE / pivot_pair.value),
+                    comparison: base.clone(),
+                    position: 0,
+                    created_at: pivot_pair.created_at.clone(),
+                    updated_at: pivot_pair.updated_at.clone(),
+                },
+            );
+        }
+        return Ok(CurrencyConverter {
+            pivot: pivot.to_string(),
+            pivot_pairs,
+        });
+    }
+
+    pub fn has_currency(&self, code: &str) -> bool {
+        return code == self.pivot || self.pivot_pairs.contains_key(code);
+    }
+
+    /*
+        NOTE: the returned rate is the amount of `to` that one unit of `from` is worth
+    */
+    pub fn get_rate(&self, from: &str, to: &str) -> Result<Rate, Error> {
+        if from == to {
+            return Ok(Rate {
+                from: from.to_string(),
+                to: to.to_string(),
+                value: Decimal::ONE,
+                path: vec![from.to_string()],
+                updated_at: self.get_pivot_value(from)?.1,
+            });
+        }
+        let (from_value, from_updated_at) = self.get_pivot_value(from)?;
+        let (to_value, to_updated_at) = self.get_pivot_value(to)?;
+        let path = if from == self.pivot || to == self.pivot {
+            vec![from.to_string(), to.to_string()]
+        } else {
+            vec![from.to_string(), self.pivot.clone(), to.to_string()]
+        };
+        let maybe_value = to_value.checked_div(from_value);
+        if maybe_value.is_none() {
+            return Err(Error {
+                message: format!("The rate for the currency '{}' is zero!", from),
+                ..Default::default()
+            });
+        }
+        return Ok(Rate {
+            from: from.to_string(),
+            to: to.to_string(),
+            value: round_rate(maybe_value.unwrap()),
+            path,
+            updated_at: oldest_timestamp(&from_updated_at, &to_updated_at),
+        });
+    }
+
+    pub fn convert(&self, amount: Decimal, from: &str, to: &str) -> Result<Conversion, Error> {
+        let rate = self.get_rate(from, to)?;
+        let maybe_converted_amount = amount.checked_mul(rate.value);
+        if maybe_converted_amount.is_none() {
+            return Err(Error {
+                message: format!("The amount is too large to convert to '{}'!", to),
+                ..Default::default()
+            });
+        }
+        return Ok(Conversion {
+            amount,
+            converted_amount: round_amount(maybe_converted_amount.unwrap(), to),
+            rate,
+        });
+    }
+
+    fn get_pivot_value(&self, code: &str) -> Result<(Decimal, String), Error> {
+        if code == self.pivot {
+            let updated_at = self
+                .pivot_pairs
+                .values()
+                .map(|p| p.updated_at.clone())
+                .min()
+                .unwrap_or_default();
+            return Ok((Decimal::ONE, updated_at));
+        }
+        if let Some(pair) = self.pivot_pairs.get(code) {
+            return Ok((pair.value, pair.updated_at.clone()));
+        }
+        return Err(Error {
+            message: format!("Could not find a rate for the currency '{}'!", code),
+            ..Default::default()
+        });
+    }
+}
+
+fn oldest_timestamp(a: &str, b: &str) -> String {
+    if a <= b {
+        return a.to_string();
+    }
+    return b.to_string();
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn create_usd_pair(comparison: &str, value: &str, updated_at: &str) -> Pair {
+        return Pair {
+            id: comparison.to_lowercase(),
+            value: Decimal::from_str(value).unwrap(),
+            base: String::from("USD"),
+            comparison: comparison.to_string(),
+            position: 0,
+            created_at: updated_at.to_string(),
+            updated_at: updated_at.to_string(),
+        };
+    }
+
+    #[test]
+    fn test_get_rate_through_pivot() {
+        /*
+            Unit test expectations:
+
+            - A rate between two non-pivot currencies is triangulated through the pivot.
+            - The reported timestamp is the oldest among the pairs used.
+        */
+        let pairs = vec![
+            create_usd_pair("EUR", "0.5", "2024-01-02T00:00:00+00:00"),
+            create_usd_pair("BRL", "5", "2024-01-01T00:00:00+00:00"),
+        ];
+        let converter = CurrencyConverter::new(&pairs, "USD").unwrap();
+
+        let rate = converter.get_rate("EUR", "BRL").unwrap();
+        assert_eq!(rate.value, Decimal::from(10));
+        assert_eq!(rate.path, vec!["EUR", "USD", "BRL"]);
+        assert_eq!(rate.updated_at, "2024-01-01T00:00:00+00:00");
+
+        let rate = converter.get_rate("USD", "EUR").unwrap();
+        assert_eq!(rate.value, Decimal::from_str("0.5").unwrap());
+        assert_eq!(rate.path, vec!["USD", "EUR"]);
+    }
+
+    #[test]
+    fn test_convert_with_custom_pivot() {
+        /*
+            Unit test expectations:
+
+            - Pairs are rebased onto the configured pivot, including the original base.
+            - Unknown currencies produce an error instead of a bogus value.
+        */
+        let pairs = vec![
+            create_usd_pair("EUR", "0.5", "2024-01-01T00:00:00+00:00"),
+            create_usd_pair("BRL", "5", "2024-01-01T00:00:00+00:00"),
+        ];
+        let converter = CurrencyConverter::new(&pairs, "EUR").unwrap();
+
+        let conversion = converter.convert(Decimal::from(3), "USD", "BRL").unwrap();
+        assert_eq!(conversion.converted_amount, Decimal::from(15));
+        assert_eq!(conversion.rate.path, vec!["USD", "EUR", "BRL"]);
+
+        let conversion = converter.convert(Decimal::from(2), "EUR", "USD").unwrap();
+        assert_eq!(conversion.converted_amount, Decimal::from(4));
+
+        assert!(converter.convert(Decimal::ONE, "EUR", "XYZ").is_err());
+    }
+}
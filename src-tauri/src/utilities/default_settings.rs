@@ -0,0 +1,26 @@
+use crate::entities::{
+    network_settings::NetworkSettings,
+    settings::{CostBasisMethod, Settings},
+};
+
+pub const DEFAULT_FIAT_RATES_URL: &str =
+    "https://raw.githubusercontent.com/ARK-Builders/ark-exchange-rates/main/fiat-rates.json";
+pub const DEFAULT_CRYPTO_RATES_URL: &str =
+    "https://raw.githubusercontent.com/ARK-Builders/ark-exchange-rates/main/crypto-rates.json";
+pub const DEFAULT_REFERENCE_CURRENCY: &str = "USD";
+pub const DEFAULT_REFRESH_INTERVAL: u64 = 60 * 5;
+pub const DEFAULT_COST_BASIS_METHOD: CostBasisMethod = CostBasisMethod::Fifo;
+
+pub fn create_default_settings() -> Settings {
+    return Settings {
+        fiat_rates_url: DEFAULT_FIAT_RATES_URL.to_string(),
+        crypto_rates_url: DEFAULT_CRYPTO_RATES_URL.to_string(),
+        reference_currency: DEFAULT_REFERENCE_CURRENCY.to_string(),
+        refresh_interval: DEFAULT_REFRESH_INTERVAL,
+        data_location: None,
+        network: NetworkSettings::default(),
+        cost_basis_method: DEFAULT_COST_BASIS_METHOD,
+        default_watchlist_id: None,
+        updated_at: String::new(),
+    };
+}
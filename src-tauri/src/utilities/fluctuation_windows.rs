@@ -0,0 +1,110 @@
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+
+use super::{currency_converter::CurrencyConverter, rate_history::RateHistory};
+
+/*
+    Changes over the last hour, day, week and 30 days, computed from the rates
+    recorded on each refresh rather than from whatever was stored at the previous
+    view. A window is `None` when no rates were recorded within half the window of
+    its start, since comparing with rates from another time would be misleading.
+*/
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FluctuationWindows {
+    pub hour: Option<Decimal>,
+    pub day: Option<Decimal>,
+    pub week: Option<Decimal>,
+    pub month: Option<Decimal>,
+}
+
+const MONTH_WINDOW_DAYS: i64 = 30;
+
+// NOTE: the rates recorded before this date are too old for any window
+pub fn get_fluctuation_history_start(now: &DateTime<Utc>) -> DateTime<Utc> {
+    let month_window = Duration::days(MONTH_WINDOW_DAYS);
+    return *now - month_window - month_window / 2;
+}
+
+// NOTE: `get_previous_value` gives the value at the rates of a converter, if it can be computed
+pub fn get_fluctuation_windows<F>(
+    rate_history: &RateHistory,
+    now: &DateTime<Utc>,
+    value: Decimal,
+    get_previous_value: F,
+) -> FluctuationWindows
+where
+    F: Fn(&CurrencyConverter) -> Option<Decimal>,
+{
+    let get_window_fluctuation = |window: Duration| -> Option<Decimal> {
+        let start = (*now - window).to_rfc3339();
+        let converter = rate_history.find_converter_within(&start, window / 2)?;
+        let previous_value = get_previous_value(converter)?;
+        return (value - previous_value).checked_div(previous_value);
+    };
+    return FluctuationWindows {
+        hour: get_window_fluctuation(Duration::hours(1)),
+        day: get_window_fluctuation(Duration::days(1)),
+        week: get_window_fluctuation(Duration::days(7)),
+        month: get_window_fluctuation(Duration::days(MONTH_WINDOW_DAYS)),
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use crate::entities::{pair::Pair, rate_snapshot::RateSnapshot};
+
+    use super::*;
+
+    fn create_rate_snapshot(btc_value: &str, created_at: &DateTime<Utc>) -> RateSnapshot {
+        return RateSnapshot {
+            pairs: vec![Pair {
+                id: String::from("btc"),
+                value: Decimal::from_str(btc_value).unwrap(),
+                base: String::from("USD"),
+                comparison: String::from("BTC"),
+                position: 0,
+                created_at: created_at.to_rfc3339(),
+                updated_at: created_at.to_rfc3339(),
+            }],
+            created_at: created_at.to_rfc3339(),
+        };
+    }
+
+    #[test]
+    fn test_get_fluctuation_windows() {
+        /*
+            Unit test expectations:
+
+            - Each window compares with the rates recorded closest to its start.
+            - Windows without rates recorded around their start are `None`.
+            - Windows whose previous value cannot be computed are `None`.
+            - Only the rates recorded within half the longest window of its start are needed.
+        */
+        let now = Utc::now();
+        let rate_history = RateHistory::new(&vec![
+            create_rate_snapshot("0.02", &(now - Duration::minutes(50))),
+            create_rate_snapshot("0.04", &(now - Duration::hours(23))),
+            create_rate_snapshot("0.01", &(now - Duration::days(8))),
+        ]);
+        let get_btc_value = |converter: &CurrencyConverter| {
+            return converter.get_rate("BTC", "USD").ok().map(|r| r.value);
+        };
+
+        let windows =
+            get_fluctuation_windows(&rate_history, &now, Decimal::from(100), get_btc_value);
+        assert_eq!(windows.hour, Some(Decimal::ONE));
+        assert_eq!(windows.day, Some(Decimal::from_str("3").unwrap()));
+        assert_eq!(windows.week, Some(Decimal::ZERO));
+        assert_eq!(windows.month, None);
+
+        let windows = get_fluctuation_windows(&rate_history, &now, Decimal::ONE, |_| None);
+        assert_eq!(windows, FluctuationWindows::default());
+
+        assert_eq!(
+            get_fluctuation_history_start(&now),
+            now - Duration::days(45)
+        );
+    }
+}
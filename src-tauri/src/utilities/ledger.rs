@@ -0,0 +1,446 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::{
+    entities::{
+        asset::Asset,
+        transaction::{Transaction, TransactionKind},
+    },
+    Error,
+};
+
+use super::rounding_policy::round_amount;
+
+pub fn parse_transaction_kind(kind: &str) -> Result<TransactionKind, Error> {
+    match kind {
+        "buy" => return Ok(TransactionKind::Buy),
+        "sell" => return Ok(TransactionKind::Sell),
+        "deposit" => return Ok(TransactionKind::Deposit),
+        "withdraw" => return Ok(TransactionKind::Withdraw),
+        "transfer" => return Ok(TransactionKind::Transfer),
+        "fee" => return Ok(TransactionKind::Fee),
+        _ => {
+            return Err(Error {
+                message: format!("Unknown transaction kind '{}'!", kind),
+                ..Default::default()
+            })
+        }
+    }
+}
+
+pub fn get_transaction_kind_name(kind: &TransactionKind) -> String {
+    let name = match kind {
+        TransactionKind::Buy => "buy",
+        TransactionKind::Sell => "sell",
+        TransactionKind::Deposit => "deposit",
+        TransactionKind::Withdraw => "withdraw",
+        TransactionKind::Transfer => "transfer",
+        TransactionKind::Fee => "fee",
+    };
+    return name.to_string();
+}
+
+pub fn validate_transaction(transaction: &Transaction) -> Result<(), Error> {
+    if transaction.quantity <= Decimal::ZERO {
+        return Err(Error {
+            message: String::from("The transaction quantity must be positive!"),
+            ..Default::default()
+        });
+    }
+    if DateTime::parse_from_rfc3339(&transaction.executed_at).is_err() {
+        return Err(Error {
+            message: String::from("The transaction date is not a valid RFC 3339 date!"),
+            ..Default::default()
+        });
+    }
+    if let Some(price) = transaction.price {
+        if price < Decimal::ZERO {
+            return Err(Error {
+                message: String::from("The transaction price cannot be negative!"),
+                ..Default::default()
+            });
+        }
+        if transaction.price_currency.is_none() {
+            return Err(Error {
+                message: String::from("The transaction price requires a currency!"),
+                ..Default::default()
+            });
+        }
+    }
+    match transaction.kind {
+        TransactionKind::Buy | TransactionKind::Sell => {
+            if transaction.price.is_none() {
+                return Err(Error {
+                    message: String::from("Buys and sells require a price!"),
+                    ..Default::default()
+                });
+            }
+        }
+        _ => {}
+    }
+    if transaction.kind == TransactionKind::Transfer {
+        if transaction.counterpart_asset_id.is_none() {
+            return Err(Error {
+                message: String::from("Transfers require a destination asset!"),
+                ..Default::default()
+            });
+        }
+        if transaction.counterpart_asset_id.as_ref() == Some(&transaction.asset_id) {
+            return Err(Error {
+                message: String::from("Transfers require a different destination asset!"),
+                ..Default::default()
+            });
+        }
+    } else if transaction.counterpart_asset_id.is_some() {
+        return Err(Error {
+            message: String::from("Only transfers can have a destination asset!"),
+            ..Default::default()
+        });
+    }
+    return Ok(());
+}
+
+pub fn is_transaction_of(transaction: &Transaction, asset_id: &str) -> bool {
+    return transaction.asset_id == asset_id
+        || transaction.counterpart_asset_id.as_deref() == Some(asset_id);
+}
+
+/*
+    NOTE: the signed change of the asset quantity caused by the transaction, zero
+    when the transaction does not involve the asset
+*/
+pub fn get_quantity_change(transaction: &Transaction, asset_id: &str) -> Decimal {
+    if transaction.counterpart_asset_id.as_deref() == Some(asset_id) {
+        return transaction.quantity;
+    }
+    if transaction.asset_id != asset_id {
+        return Decimal::ZERO;
+    }
+    match transaction.kind {
+        TransactionKind::Buy | TransactionKind::Deposit => return transaction.quantity,
+        TransactionKind::Sell
+        | TransactionKind::Withdraw
+        | TransactionKind::Transfer
+        | TransactionKind::Fee => return -transaction.quantity,
+    }
+}
+
+pub fn sort_transactions(transactions: &mut Vec<Transaction>) {
+    transactions.sort_by(|a, b| {
+        let a_executed_at = DateTime::parse_from_rfc3339(&a.executed_at).ok();
+        let b_executed_at = DateTime::parse_from_rfc3339(&b.executed_at).ok();
+        return a_executed_at
+            .cmp(&b_executed_at)
+            .then(a.created_at.cmp(&b.created_at));
+    });
+}
+
+/*
+    Replays the ledger of an asset in chronological order. The balance can never
+    go below zero, since a holding cannot sell or move more than it has.
+*/
+pub fn get_balance(asset: &Asset, transactions: &Vec<Transaction>) -> Result<Decimal, Error> {
+    let mut asset_transactions: Vec<Transaction> = transactions
+        .iter()
+        .filter(|t| is_transaction_of(t, &asset.id))
+        .cloned()
+        .collect();
+    sort_transactions(&mut asset_transactions);
+    let mut balance = Decimal::ZERO;
+    for transaction in &asset_transactions {
+        balance += get_quantity_change(transaction, &asset.id);
+        if balance < Decimal::ZERO {
+            return Err(Error {
+                message: format!(
+                    "The transaction of {} would make the {} balance negative!",
+                    transaction.executed_at, asset.coin
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    return Ok(round_amount(balance, &asset.coin));
+}
+
+// NOTE: only the assets whose quantity differs from their ledger balance are returned
+pub fn create_balanced_assets(
+    assets: &Vec<Asset>,
+    transactions: &Vec<Transaction>,
+) -> Result<Vec<Asset>, Error> {
+    let mut balanced_assets: Vec<Asset> = vec![];
+    for asset in assets {
+        let balance = get_balance(asset, transactions)?;
+        if balance != asset.quantity {
+            let mut balanced_asset = asset.clone();
+            balanced_asset.quantity = balance;
+            balanced_asset.updated_at = Utc::now().to_rfc3339();
+            balanced_assets.push(balanced_asset);
+        }
+    }
+    return Ok(balanced_assets);
+}
+
+/*
+    NOTE: assets created before the ledger existed have a quantity but no
+    transactions, so their quantity is recorded as an opening deposit the first
+    time a transaction touches them
+*/
+pub fn create_opening_transaction(asset: &Asset) -> Transaction {
+    return Transaction {
+        id: Uuid::new_v4().to_string(),
+        asset_id: asset.id.clone(),
+        kind: TransactionKind::Deposit,
+        quantity: asset.quantity,
+        price: None,
+        price_currency: None,
+        counterpart_asset_id: None,
+        executed_at: asset.created_at.clone(),
+        notes: String::from("Opening balance"),
+        created_at: asset.created_at.clone(),
+        updated_at: asset.created_at.clone(),
+    };
+}
+
+// NOTE: a deposit or a withdrawal of `difference`, for quantities edited directly
+pub fn create_adjustment_transaction(
+    asset_id: &str,
+    difference: Decimal,
+    notes: &str,
+) -> Transaction {
+    let now = Utc::now().to_rfc3339();
+    return Transaction {
+        id: Uuid::new_v4().to_string(),
+        asset_id: asset_id.to_string(),
+        kind: if difference > Decimal::ZERO {
+            TransactionKind::Deposit
+        } else {
+            TransactionKind::Withdraw
+        },
+        quantity: difference.abs(),
+        price: None,
+        price_currency: None,
+        counterpart_asset_id: None,
+        executed_at: now.clone(),
+        notes: notes.to_string(),
+        created_at: now.clone(),
+        updated_at: now.clone(),
+    };
+}
+
+pub fn create_missing_opening_transactions(
+    assets: &Vec<Asset>,
+    transactions: &Vec<Transaction>,
+) -> Vec<Transaction> {
+    let mut opening_transactions: Vec<Transaction> = vec![];
+    for asset in assets {
+        if asset.quantity > Decimal::ZERO
+            && !transactions.iter().any(|t| is_transaction_of(t, &asset.id))
+        {
+            opening_transactions.push(create_opening_transaction(asset));
+        }
+    }
+    return opening_transactions;
+}
+
+// NOTE: what removing assets changes in the ledgers of the assets that remain
+#[derive(Clone, Debug)]
+pub struct LedgerCleanup {
+    pub deleted_transaction_ids: Vec<String>,
+    pub updated_transactions: Vec<Transaction>,
+}
+
+/*
+    Transactions of the removed assets are deleted. Transfers between a removed
+    asset and one that remains still moved the quantity of the remaining asset, so
+    they are kept as withdrawals from the source or deposits into the destination
+    to preserve its balance.
+*/
+pub fn create_ledger_cleanup(
+    transactions: &Vec<Transaction>,
+    asset_ids: &Vec<String>,
+) -> LedgerCleanup {
+    let mut cleanup = LedgerCleanup {
+        deleted_transaction_ids: vec![],
+        updated_transactions: vec![],
+    };
+    for transaction in transactions {
+        let is_source_removed = asset_ids.contains(&transaction.asset_id);
+        let maybe_remaining_counterpart_asset_id = transaction
+            .counterpart_asset_id
+            .as_ref()
+            .filter(|id| !asset_ids.contains(id));
+        if is_source_removed {
+            if let Some(counterpart_asset_id) = maybe_remaining_counterpart_asset_id {
+                let mut updated_transaction = transaction.clone();
+                updated_transaction.asset_id = counterpart_asset_id.clone();
+                updated_transaction.kind = TransactionKind::Deposit;
+                updated_transaction.counterpart_asset_id = None;
+                updated_transaction.updated_at = Utc::now().to_rfc3339();
+                cleanup.updated_transactions.push(updated_transaction);
+            } else {
+                cleanup.deleted_transaction_ids.push(transaction.id.clone());
+            }
+        } else if transaction.counterpart_asset_id.is_some()
+            && maybe_remaining_counterpart_asset_id.is_none()
+        {
+            let mut updated_transaction = transaction.clone();
+            updated_transaction.kind = TransactionKind::Withdraw;
+            updated_transaction.counterpart_asset_id = None;
+            updated_transaction.updated_at = Utc::now().to_rfc3339();
+            cleanup.updated_transactions.push(updated_transaction);
+        }
+    }
+    return cleanup;
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn create_asset(id: &str) -> Asset {
+        return Asset {
+            id: id.to_string(),
+            coin: String::from("BTC"),
+            quantity: Decimal::ZERO,
+            usd_value: Decimal::ZERO,
+            account_id: None,
+            created_at: String::from("2024-01-01T00:00:00+00:00"),
+            updated_at: String::from("2024-01-01T00:00:00+00:00"),
+        };
+    }
+
+    fn create_transaction(
+        kind: TransactionKind,
+        quantity: &str,
+        counterpart_asset_id: Option<&str>,
+        executed_at: &str,
+    ) -> Transaction {
+        return Transaction {
+            id: Uuid::new_v4().to_string(),
+            asset_id: String::from("a1"),
+            kind,
+            quantity: Decimal::from_str(quantity).unwrap(),
+            price: None,
+            price_currency: None,
+            counterpart_asset_id: counterpart_asset_id.map(|id| id.to_string()),
+            executed_at: executed_at.to_string(),
+            notes: String::new(),
+            created_at: executed_at.to_string(),
+            updated_at: executed_at.to_string(),
+        };
+    }
+
+    #[test]
+    fn test_get_balance() {
+        /*
+            Unit test expectations:
+
+            - Transfers move the quantity from the source asset to the destination one.
+            - Transactions are replayed chronologically, regardless of the time zone.
+            - A balance going negative at any point is an error.
+        */
+        let transactions = vec![
+            create_transaction(
+                TransactionKind::Deposit,
+                "2",
+                None,
+                "2024-01-01T10:00:00+02:00",
+            ),
+            create_transaction(
+                TransactionKind::Transfer,
+                "0.5",
+                Some("a2"),
+                "2024-01-02T00:00:00+00:00",
+            ),
+            create_transaction(
+                TransactionKind::Fee,
+                "0.0001",
+                None,
+                "2024-01-02T00:00:00+00:00",
+            ),
+            create_transaction(
+                TransactionKind::Sell,
+                "1",
+                None,
+                "2024-01-03T00:00:00+00:00",
+            ),
+        ];
+        assert_eq!(
+            get_balance(&create_asset("a1"), &transactions).unwrap(),
+            Decimal::from_str("0.4999").unwrap()
+        );
+        assert_eq!(
+            get_balance(&create_asset("a2"), &transactions).unwrap(),
+            Decimal::from_str("0.5").unwrap()
+        );
+
+        let mut transactions = transactions.clone();
+        transactions.push(create_transaction(
+            TransactionKind::Withdraw,
+            "1",
+            None,
+            "2024-01-01T09:00:00+00:00",
+        ));
+        assert!(get_balance(&create_asset("a1"), &transactions).is_err());
+    }
+
+    #[test]
+    fn test_create_ledger_cleanup() {
+        /*
+            Unit test expectations:
+
+            - Transactions of the removed assets are deleted.
+            - Transfers from a removed asset become deposits into the remaining one.
+            - Transfers into a removed asset become withdrawals from the remaining one.
+        */
+        let transactions = vec![
+            create_transaction(
+                TransactionKind::Deposit,
+                "2",
+                None,
+                "2024-01-01T00:00:00+00:00",
+            ),
+            create_transaction(
+                TransactionKind::Transfer,
+                "0.5",
+                Some("a2"),
+                "2024-01-02T00:00:00+00:00",
+            ),
+        ];
+        let mut remaining_transactions = transactions.clone();
+        remaining_transactions.push(create_transaction(
+            TransactionKind::Sell,
+            "0.4",
+            None,
+            "2024-01-03T00:00:00+00:00",
+        ));
+        remaining_transactions[2].asset_id = String::from("a2");
+
+        let cleanup = create_ledger_cleanup(&remaining_transactions, &vec![String::from("a1")]);
+        assert_eq!(
+            cleanup.deleted_transaction_ids,
+            vec![transactions[0].id.clone()]
+        );
+        assert_eq!(cleanup.updated_transactions.len(), 1);
+        let deposit = &cleanup.updated_transactions[0];
+        assert_eq!(deposit.asset_id, "a2");
+        assert_eq!(deposit.kind, TransactionKind::Deposit);
+        assert_eq!(deposit.counterpart_asset_id, None);
+        let remaining_transactions = vec![deposit.clone(), remaining_transactions[2].clone()];
+        assert_eq!(
+            get_balance(&create_asset("a2"), &remaining_transactions).unwrap(),
+            Decimal::from_str("0.1").unwrap()
+        );
+
+        let cleanup = create_ledger_cleanup(&transactions, &vec![String::from("a2")]);
+        assert!(cleanup.deleted_transaction_ids.is_empty());
+        assert_eq!(cleanup.updated_transactions[0].asset_id, "a1");
+        assert_eq!(
+            cleanup.updated_transactions[0].kind,
+            TransactionKind::Withdraw
+        );
+    }
+}
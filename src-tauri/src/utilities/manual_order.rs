@@ -0,0 +1,133 @@
+use std::cmp::Ordering;
+
+use chrono::{DateTime, Utc};
+
+use crate::entities::{pair::Pair, pair_group::PairGroup};
+
+/*
+    Pair groups, and the pairs inside pair groups and watchlists, are listed in
+    the order the user arranged them. Those saved before they could be ordered all
+    have position 0, so they keep their creation order.
+*/
+pub fn sort_pair_groups(pair_groups: &mut Vec<PairGroup>) {
+    pair_groups.sort_by(|a, b| {
+        return compare_positions(a.position, &a.created_at, b.position, &b.created_at);
+    });
+}
+
+pub fn sort_pairs(pairs: &mut Vec<Pair>) {
+    pairs.sort_by(|a, b| {
+        return compare_positions(a.position, &a.created_at, b.position, &b.created_at);
+    });
+}
+
+fn compare_positions(
+    a_position: u32,
+    a_created_at: &str,
+    b_position: u32,
+    b_created_at: &str,
+) -> Ordering {
+    let a_created_at = DateTime::parse_from_rfc3339(a_created_at).ok();
+    let b_created_at = DateTime::parse_from_rfc3339(b_created_at).ok();
+    return a_position
+        .cmp(&b_position)
+        .then(a_created_at.cmp(&b_created_at));
+}
+
+// NOTE: the position right after the last of `positions`, so new items are listed last
+pub fn get_next_position(positions: &Vec<u32>) -> u32 {
+    return positions.iter().max().map(|p| p + 1).unwrap_or(0);
+}
+
+// NOTE: a new order must list every current id exactly once
+pub fn is_complete_order(ids: &Vec<String>, current_ids: &Vec<String>) -> bool {
+    return ids.len() == current_ids.len() && current_ids.iter().all(|id| ids.contains(id));
+}
+
+/*
+    Puts the pairs in the order of `pair_ids`, which must be complete, numbering
+    their positions from 0. Only the pairs whose position changes are touched.
+*/
+pub fn reorder_pairs(pairs: &Vec<Pair>, pair_ids: &Vec<String>) -> Vec<Pair> {
+    let mut reordered_pairs: Vec<Pair> = vec![];
+    for (position, pair_id) in pair_ids.iter().enumerate() {
+        if let Some(pair) = pairs.iter().find(|p| &p.id == pair_id) {
+            let mut reordered_pair = pair.clone();
+            if reordered_pair.position != position as u32 {
+                reordered_pair.position = position as u32;
+                reordered_pair.updated_at = Utc::now().to_rfc3339();
+            }
+            reordered_pairs.push(reordered_pair);
+        }
+    }
+    return reordered_pairs;
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal::Decimal;
+
+    use super::*;
+
+    fn create_pair(id: &str, position: u32, created_at: &str) -> Pair {
+        return Pair {
+            id: id.to_string(),
+            value: Decimal::ONE,
+            base: String::from("USD"),
+            comparison: id.to_uppercase(),
+            position,
+            created_at: created_at.to_string(),
+            updated_at: created_at.to_string(),
+        };
+    }
+
+    #[test]
+    fn test_sort_pairs() {
+        /*
+            Unit test expectations:
+
+            - Pairs are ordered by position, then by creation date.
+            - New items are positioned after the last one.
+            - A new order must list every current id exactly once.
+            - Reordering numbers the positions and only touches the moved pairs.
+        */
+        let mut pairs = vec![
+            create_pair("eth", 1, "2024-01-01T00:00:00+00:00"),
+            create_pair("btc", 0, "2024-01-02T00:00:00+00:00"),
+            create_pair("ada", 0, "2024-01-01T00:00:00+00:00"),
+        ];
+        sort_pairs(&mut pairs);
+        let ids: Vec<&str> = pairs.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, vec!["ada", "btc", "eth"]);
+
+        assert_eq!(get_next_position(&vec![0, 3, 1]), 4);
+        assert_eq!(get_next_position(&vec![]), 0);
+
+        let current_ids = vec![String::from("a"), String::from("b")];
+        assert!(is_complete_order(
+            &vec![String::from("b"), String::from("a")],
+            &current_ids
+        ));
+        assert!(!is_complete_order(&vec![String::from("a")], &current_ids));
+        assert!(!is_complete_order(
+            &vec![String::from("a"), String::from("a")],
+            &current_ids
+        ));
+
+        let reordered_pairs = reorder_pairs(
+            &pairs,
+            &vec![
+                String::from("ada"),
+                String::from("eth"),
+                String::from("btc"),
+            ],
+        );
+        let ids: Vec<&str> = reordered_pairs.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, vec!["ada", "eth", "btc"]);
+        let positions: Vec<u32> = reordered_pairs.iter().map(|p| p.position).collect();
+        assert_eq!(positions, vec![0, 1, 2]);
+        assert_eq!(reordered_pairs[0], pairs[0]);
+        assert_eq!(reordered_pairs[1], pairs[2]);
+        assert_ne!(reordered_pairs[2], pairs[1]);
+    }
+}
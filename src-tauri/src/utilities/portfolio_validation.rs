@@ -0,0 +1,195 @@
+use chrono::DateTime;
+use rust_decimal::Decimal;
+
+use crate::{error::FieldError, Error};
+
+use super::currency_converter::CurrencyConverter;
+
+/*
+    NOTE: the frontend sends quantities it cannot parse as `null`, so a missing
+    quantity is reported like any other invalid one
+*/
+pub fn validate_asset(
+    field: &str,
+    coin: &str,
+    quantity: &Option<Decimal>,
+    converter: &CurrencyConverter,
+) -> Vec<FieldError> {
+    let mut field_errors: Vec<FieldError> = vec![];
+    if coin.trim().is_empty() {
+        field_errors.push(FieldError {
+            field: format!("{}.coin", field),
+            message: String::from("The coin must not be empty!"),
+        });
+    } else if converter.get_rate(coin, "USD").is_err() {
+        field_errors.push(FieldError {
+            field: format!("{}.coin", field),
+            message: format!("'{}' is not a known coin!", coin),
+        });
+    }
+    match quantity {
+        None => field_errors.push(FieldError {
+            field: format!("{}.quantity", field),
+            message: String::from("The quantity must be a number!"),
+        }),
+        Some(quantity) if *quantity < Decimal::ZERO => field_errors.push(FieldError {
+            field: format!("{}.quantity", field),
+            message: String::from("The quantity must not be negative!"),
+        }),
+        _ => {}
+    }
+    return field_errors;
+}
+
+/*
+    NOTE: the acquisition details become the opening transaction of the asset, so
+    they are checked here instead of failing once the assets are being stored
+*/
+pub fn validate_acquisition(
+    field: &str,
+    price: &Option<Decimal>,
+    currency: &Option<String>,
+    acquired_at: &Option<String>,
+    converter: &CurrencyConverter,
+) -> Vec<FieldError> {
+    let mut field_errors: Vec<FieldError> = vec![];
+    if let Some(price) = price {
+        if *price < Decimal::ZERO {
+            field_errors.push(FieldError {
+                field: format!("{}.acquisition_price", field),
+                message: String::from("The acquisition price cannot be negative!"),
+            });
+        }
+    }
+    match currency {
+        None if price.is_some() => field_errors.push(FieldError {
+            field: format!("{}.acquisition_currency", field),
+            message: String::from("The acquisition price requires a currency!"),
+        }),
+        Some(currency) if !converter.has_currency(currency) => field_errors.push(FieldError {
+            field: format!("{}.acquisition_currency", field),
+            message: format!("'{}' is not a known currency!", currency),
+        }),
+        _ => {}
+    }
+    if let Some(acquired_at) = acquired_at {
+        if DateTime::parse_from_rfc3339(acquired_at).is_err() {
+            field_errors.push(FieldError {
+                field: format!("{}.acquired_at", field),
+                message: String::from("The acquisition date is not a valid RFC 3339 date!"),
+            });
+        }
+    }
+    return field_errors;
+}
+
+// NOTE: the message sums up the field errors for clients that only show messages
+pub fn create_validation_error(field_errors: &Vec<FieldError>) -> Result<(), Error> {
+    if field_errors.len() == 0 {
+        return Ok(());
+    }
+    let messages: Vec<String> = field_errors
+        .iter()
+        .map(|fe| format!("{}: {}", fe.field, fe.message))
+        .collect();
+    return Err(Error {
+        message: messages.join("\n"),
+        fields: field_errors.clone(),
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use crate::{entities::pair::Pair, utilities::currency_converter::DEFAULT_PIVOT};
+
+    use super::*;
+
+    fn create_converter() -> CurrencyConverter {
+        let pairs = vec![Pair {
+            id: String::from("btc"),
+            value: Decimal::from_str("0.00002").unwrap(),
+            base: String::from("USD"),
+            comparison: String::from("BTC"),
+            position: 0,
+            created_at: String::from("2024-01-01T00:00:00+00:00"),
+            updated_at: String::from("2024-01-01T00:00:00+00:00"),
+        }];
+        return CurrencyConverter::new(&pairs, DEFAULT_PIVOT).unwrap();
+    }
+
+    #[test]
+    fn test_validate_asset() {
+        /*
+            Unit test expectations:
+
+            - Known coins with a positive or zero quantity are valid.
+            - Empty and unknown coins, negative and missing quantities are reported per field.
+            - The validation error lists every field error, in its message and its fields.
+        */
+        let converter = create_converter();
+        assert!(validate_asset("asset", "BTC", &Some(Decimal::ONE), &converter).is_empty());
+        assert!(validate_asset("asset", "BTC", &Some(Decimal::ZERO), &converter).is_empty());
+
+        let field_errors = validate_asset("assets[0]", " ", &Some(-Decimal::ONE), &converter);
+        assert_eq!(field_errors.len(), 2);
+        assert_eq!(field_errors[0].field, "assets[0].coin");
+        assert_eq!(field_errors[1].field, "assets[0].quantity");
+
+        let field_errors = validate_asset("assets[1]", "XYZ", &None, &converter);
+        assert_eq!(field_errors[0].message, "'XYZ' is not a known coin!");
+        assert_eq!(field_errors[1].message, "The quantity must be a number!");
+
+        assert!(create_validation_error(&vec![]).is_ok());
+        let error = create_validation_error(&field_errors).unwrap_err();
+        assert_eq!(
+            error.message,
+            "assets[1].coin: 'XYZ' is not a known coin!\nassets[1].quantity: The quantity must be a number!"
+        );
+        assert_eq!(error.fields, field_errors);
+    }
+
+    #[test]
+    fn test_validate_acquisition() {
+        /*
+            Unit test expectations:
+
+            - Missing acquisition details are valid.
+            - Negative prices, prices without a currency, unknown currencies and
+              invalid dates are reported per field.
+        */
+        let converter = create_converter();
+        assert!(validate_acquisition("assets[0]", &None, &None, &None, &converter).is_empty());
+        assert!(validate_acquisition(
+            "assets[0]",
+            &Some(Decimal::from(100)),
+            &Some(String::from("BTC")),
+            &Some(String::from("2024-01-01T00:00:00+00:00")),
+            &converter,
+        )
+        .is_empty());
+
+        let field_errors = validate_acquisition(
+            "assets[0]",
+            &Some(Decimal::from(-1)),
+            &None,
+            &Some(String::from("yesterday")),
+            &converter,
+        );
+        assert_eq!(field_errors.len(), 3);
+        assert_eq!(field_errors[0].field, "assets[0].acquisition_price");
+        assert_eq!(field_errors[1].field, "assets[0].acquisition_currency");
+        assert_eq!(field_errors[2].field, "assets[0].acquired_at");
+
+        let field_errors = validate_acquisition(
+            "assets[1]",
+            &None,
+            &Some(String::from("XYZ")),
+            &None,
+            &converter,
+        );
+        assert_eq!(field_errors[0].field, "assets[1].acquisition_currency");
+        assert_eq!(field_errors[0].message, "'XYZ' is not a known currency!");
+    }
+}
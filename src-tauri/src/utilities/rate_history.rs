@@ -0,0 +1,114 @@
+use chrono::{DateTime, Duration, FixedOffset};
+
+use crate::entities::rate_snapshot::RateSnapshot;
+
+use super::currency_converter::{CurrencyConverter, DEFAULT_PIVOT};
+
+// NOTE: how far the daily rates used for a date may be from it
+pub const DAILY_RATE_TOLERANCE_DAYS: i64 = 3;
+
+/*
+    Converters for the recorded rates. Snapshots that cannot be parsed are
+    skipped, since a missing one only makes the closest rates a bit further away.
+*/
+pub struct RateHistory {
+    converters: Vec<(DateTime<FixedOffset>, CurrencyConverter)>,
+}
+
+impl RateHistory {
+    pub fn new(rate_snapshots: &Vec<RateSnapshot>) -> RateHistory {
+        let mut converters: Vec<(DateTime<FixedOffset>, CurrencyConverter)> = vec![];
+        for rate_snapshot in rate_snapshots {
+            let maybe_created_at = DateTime::parse_from_rfc3339(&rate_snapshot.created_at);
+            let maybe_converter = CurrencyConverter::new(&rate_snapshot.pairs, DEFAULT_PIVOT);
+            if maybe_created_at.is_ok() && maybe_converter.is_ok() {
+                converters.push((maybe_created_at.unwrap(), maybe_converter.unwrap()));
+            }
+        }
+        return RateHistory { converters };
+    }
+
+    /*
+        NOTE: the converter of the rates recorded closest to `date_time`, before or
+        after it, as long as they are within `tolerance` of it
+    */
+    pub fn find_converter_within(
+        &self,
+        date_time: &str,
+        tolerance: Duration,
+    ) -> Option<&CurrencyConverter> {
+        let date_time = DateTime::parse_from_rfc3339(date_time).ok()?;
+        return self
+            .converters
+            .iter()
+            .filter(|(created_at, _)| (*created_at - date_time).abs() <= tolerance)
+            .min_by_key(|(created_at, _)| (*created_at - date_time).abs())
+            .map(|(_, converter)| converter);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    use crate::entities::pair::Pair;
+
+    use super::*;
+
+    fn create_rate_snapshot(eur_value: &str, created_at: &str) -> RateSnapshot {
+        return RateSnapshot {
+            pairs: vec![Pair {
+                id: String::from("eur"),
+                value: Decimal::from_str(eur_value).unwrap(),
+                base: String::from("USD"),
+                comparison: String::from("EUR"),
+                position: 0,
+                created_at: created_at.to_string(),
+                updated_at: created_at.to_string(),
+            }],
+            created_at: created_at.to_string(),
+        };
+    }
+
+    #[test]
+    fn test_find_converter() {
+        /*
+            Unit test expectations:
+
+            - The rates recorded closest to the date are used, before or after it.
+            - Nothing is found without recorded rates, or none within the tolerance.
+        */
+        let rate_history = RateHistory::new(&vec![
+            create_rate_snapshot("0.9", "2024-01-01T12:00:00+00:00"),
+            create_rate_snapshot("0.8", "2024-01-10T12:00:00+00:00"),
+        ]);
+        let converter = rate_history
+            .find_converter_within("2024-01-08T00:00:00+00:00", Duration::days(3))
+            .unwrap();
+        assert_eq!(
+            converter.get_rate("USD", "EUR").unwrap().value,
+            Decimal::from_str("0.8").unwrap()
+        );
+        let converter = rate_history
+            .find_converter_within("2023-12-30T00:00:00+00:00", Duration::days(3))
+            .unwrap();
+        assert_eq!(
+            converter.get_rate("USD", "EUR").unwrap().value,
+            Decimal::from_str("0.9").unwrap()
+        );
+        assert!(rate_history
+            .find_converter_within("2023-06-01T00:00:00+00:00", Duration::days(3))
+            .is_none());
+        assert!(RateHistory::new(&vec![])
+            .find_converter_within("2024-01-01T00:00:00+00:00", Duration::days(3))
+            .is_none());
+        assert!(rate_history
+            .find_converter_within("2024-01-08T00:00:00+00:00", Duration::days(1))
+            .is_none());
+        assert!(rate_history
+            .find_converter_within("2024-01-09T00:00:00+00:00", Duration::days(2))
+            .is_some());
+    }
+}
@@ -0,0 +1,82 @@
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use super::currency_catalogue::{find_bundled_currency, DEFAULT_CRYPTO_DECIMALS};
+
+/*
+    NOTE: rates are ratios between currencies and are not rounded to a currency's
+    decimals, they are only capped to keep the stored representation bounded
+*/
+pub const RATE_DECIMALS: u32 = 18;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoundingPolicy {
+    pub decimals: u32,
+    pub strategy: RoundingStrategy,
+}
+
+/*
+    NOTE: unknown codes are most likely crypto tokens missing from the bundled table,
+    so they get the crypto default which is also precise enough for any fiat amount
+*/
+pub fn get_rounding_policy(coin: &str) -> RoundingPolicy {
+    let decimals = find_bundled_currency(coin)
+        .map(|c| c.decimals)
+        .unwrap_or(DEFAULT_CRYPTO_DECIMALS);
+    return RoundingPolicy {
+        decimals,
+        strategy: RoundingStrategy::MidpointNearestEven,
+    };
+}
+
+pub fn round_amount(amount: Decimal, coin: &str) -> Decimal {
+    let policy = get_rounding_policy(coin);
+    return amount
+        .round_dp_with_strategy(policy.decimals, policy.strategy)
+        .normalize();
+}
+
+pub fn round_rate(rate: Decimal) -> Decimal {
+    return rate
+        .round_dp_with_strategy(RATE_DECIMALS, RoundingStrategy::MidpointNearestEven)
+        .normalize();
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_round_amount() {
+        /*
+            Unit test expectations:
+
+            - Amounts are rounded to the decimals of their own currency, using banker's rounding.
+            - Satoshi-level crypto quantities are kept intact.
+        */
+        let amount = Decimal::from_str("1234.565").unwrap();
+        assert_eq!(
+            round_amount(amount, "USD"),
+            Decimal::from_str("1234.56").unwrap()
+        );
+        assert_eq!(
+            round_amount(amount, "JPY"),
+            Decimal::from_str("1235").unwrap()
+        );
+        assert_eq!(
+            round_amount(amount, "KWD"),
+            Decimal::from_str("1234.565").unwrap()
+        );
+
+        let satoshis = Decimal::from_str("0.000000015").unwrap();
+        assert_eq!(
+            round_amount(satoshis, "BTC"),
+            Decimal::from_str("0.00000002").unwrap()
+        );
+        assert_eq!(
+            round_amount(Decimal::from_str("0.00000001").unwrap(), "BTC"),
+            Decimal::from_str("0.00000001").unwrap()
+        );
+    }
+}
@@ -0,0 +1,9 @@
+use crate::entities::settings::Settings;
+
+/*
+    Lets long-lived services (the HTTP client, the coin market cache) react to
+    updated settings without restarting the application.
+*/
+pub trait SettingsNotifier {
+    fn notify(&mut self, settings: &Settings);
+}
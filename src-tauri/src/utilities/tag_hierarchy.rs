@@ -0,0 +1,168 @@
+use crate::{entities::tag::Tag, Error};
+
+/*
+    Tags form a forest through their optional `parent_id`. A parent that no
+    longer exists is treated as missing, so its children behave as root tags.
+*/
+
+// NOTE: the ids of the tag and of every tag nested below it, the tag first
+pub fn get_descendant_ids(tags: &Vec<Tag>, id: &str) -> Vec<String> {
+    let mut descendant_ids: Vec<String> = vec![id.to_string()];
+    let mut idx = 0;
+    while idx < descendant_ids.len() {
+        let parent_id = descendant_ids[idx].clone();
+        for tag in tags {
+            if tag.parent_id.as_ref() == Some(&parent_id) && !descendant_ids.contains(&tag.id) {
+                descendant_ids.push(tag.id.clone());
+            }
+        }
+        idx += 1;
+    }
+    return descendant_ids;
+}
+
+// NOTE: the names from the root tag down to the tag, e.g. `["Crypto", "DeFi"]`
+pub fn get_tag_path(tags: &Vec<Tag>, id: &str) -> Vec<String> {
+    let mut path: Vec<String> = vec![];
+    let mut maybe_tag = tags.iter().find(|t| t.id == id);
+    while let Some(tag) = maybe_tag {
+        if path.len() > tags.len() {
+            break;
+        }
+        path.insert(0, tag.name.clone());
+        maybe_tag = tag
+            .parent_id
+            .as_ref()
+            .and_then(|parent_id| tags.iter().find(|t| &t.id == parent_id));
+    }
+    return path;
+}
+
+pub fn validate_parent(tags: &Vec<Tag>, id: &str, parent_id: &Option<String>) -> Result<(), Error> {
+    if parent_id.is_none() {
+        return Ok(());
+    }
+    let parent_id = parent_id.as_ref().unwrap();
+    if !tags.iter().any(|t| &t.id == parent_id) {
+        return Err(Error {
+            message: String::from("Parent tag does not exist!"),
+            ..Default::default()
+        });
+    }
+    if get_descendant_ids(tags, id).contains(parent_id) {
+        return Err(Error {
+            message: String::from("A tag cannot be nested under itself or its own children!"),
+            ..Default::default()
+        });
+    }
+    return Ok(());
+}
+
+/*
+    NOTE: names only have to be unique among the tags sharing the same parent, so
+    "Crypto > Staking" and "Stocks > Staking" can both exist, and the comparison
+    ignores case and surrounding spaces
+*/
+pub fn validate_tag_name(
+    tags: &Vec<Tag>,
+    id: &str,
+    name: &str,
+    parent_id: &Option<String>,
+) -> Result<(), Error> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(Error {
+            message: String::from("Tag name must not be empty!"),
+            ..Default::default()
+        });
+    }
+    let is_taken = tags.iter().any(|t| {
+        t.id != id
+            && &t.parent_id == parent_id
+            && t.name.trim().to_lowercase() == name.to_lowercase()
+    });
+    if is_taken {
+        return Err(Error {
+            message: format!("A tag named '{}' already exists at this level!", name),
+            ..Default::default()
+        });
+    }
+    return Ok(());
+}
+
+// NOTE: colors are `#RRGGBB` hex codes, which is what the color picker produces
+pub fn validate_color(color: &Option<String>) -> Result<(), Error> {
+    if let Some(color) = color {
+        let is_valid = color.len() == 7
+            && color.starts_with('#')
+            && color.chars().skip(1).all(|c| c.is_ascii_hexdigit());
+        if !is_valid {
+            return Err(Error {
+                message: format!("'{}' is not a valid #RRGGBB color!", color),
+                ..Default::default()
+            });
+        }
+    }
+    return Ok(());
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn create_tag(id: &str, parent_id: Option<&str>) -> Tag {
+        return Tag {
+            id: id.to_string(),
+            name: id.to_uppercase(),
+            color: None,
+            description: String::new(),
+            parent_id: parent_id.map(|p| p.to_string()),
+            assets: vec![],
+            targets: vec![],
+            created_at: String::from("2024-01-01T00:00:00+00:00"),
+            updated_at: String::from("2024-01-01T00:00:00+00:00"),
+        };
+    }
+
+    #[test]
+    fn test_tag_hierarchy() {
+        /*
+            Unit test expectations:
+
+            - Descendants include every nested level, and paths go from the root down.
+            - A tag cannot be nested under one of its descendants, or under a missing tag.
+            - Children of a missing parent behave as root tags.
+            - Names are unique among siblings, ignoring case and surrounding spaces.
+        */
+        let tags = vec![
+            create_tag("crypto", None),
+            create_tag("defi", Some("crypto")),
+            create_tag("lending", Some("defi")),
+            create_tag("cash", None),
+            create_tag("orphan", Some("deleted")),
+        ];
+        assert_eq!(
+            get_descendant_ids(&tags, "crypto"),
+            vec!["crypto", "defi", "lending"]
+        );
+        assert_eq!(
+            get_tag_path(&tags, "lending"),
+            vec!["CRYPTO", "DEFI", "LENDING"]
+        );
+        assert_eq!(get_tag_path(&tags, "orphan"), vec!["ORPHAN"]);
+
+        assert!(validate_parent(&tags, "crypto", &Some(String::from("lending"))).is_err());
+        assert!(validate_parent(&tags, "crypto", &Some(String::from("crypto"))).is_err());
+        assert!(validate_parent(&tags, "cash", &Some(String::from("deleted"))).is_err());
+        assert!(validate_parent(&tags, "cash", &Some(String::from("defi"))).is_ok());
+        assert!(validate_parent(&tags, "defi", &None).is_ok());
+
+        assert!(validate_tag_name(&tags, "new", " defi ", &Some(String::from("crypto"))).is_err());
+        assert!(validate_tag_name(&tags, "defi", "DEFI", &Some(String::from("crypto"))).is_ok());
+        assert!(validate_tag_name(&tags, "new", "DEFI", &None).is_ok());
+        assert!(validate_tag_name(&tags, "new", "  ", &None).is_err());
+
+        assert!(validate_color(&Some(String::from("#1a2B3c"))).is_ok());
+        assert!(validate_color(&Some(String::from("red"))).is_err());
+    }
+}
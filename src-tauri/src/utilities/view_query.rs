@@ -0,0 +1,173 @@
+use std::cmp::Ordering;
+
+use chrono::DateTime;
+use rust_decimal::Decimal;
+
+use crate::{entities::currency::Currency, Error};
+
+/*
+    Sorting and search shared by the view interactors, so every client lists the
+    same items in the same order. Filters depend on the view and stay with it.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub enum SortKey {
+    Value,
+    Change,
+    Name,
+    Date,
+}
+
+pub fn parse_sort_key(sort_by: &str) -> Result<SortKey, Error> {
+    match sort_by {
+        "value" => return Ok(SortKey::Value),
+        "change" => return Ok(SortKey::Change),
+        "name" => return Ok(SortKey::Name),
+        "date" => return Ok(SortKey::Date),
+        _ => {
+            return Err(Error {
+                message: format!("Unknown sort key '{}'!", sort_by),
+                ..Default::default()
+            })
+        }
+    }
+}
+
+// NOTE: what an item of a view is sorted by, `created_at` being its date
+#[derive(Clone, Debug, PartialEq)]
+pub struct SortFields {
+    pub value: Decimal,
+    pub change: Decimal,
+    pub name: String,
+    pub created_at: String,
+}
+
+// NOTE: the sort is stable, so items that compare equal keep their previous order
+pub fn sort_items<T, F>(
+    items: &mut Vec<T>,
+    sort_key: &SortKey,
+    descending: bool,
+    get_sort_fields: F,
+) where
+    F: Fn(&T) -> SortFields,
+{
+    items.sort_by(|a, b| {
+        let ordering = compare_sort_fields(sort_key, &get_sort_fields(a), &get_sort_fields(b));
+        if descending {
+            return ordering.reverse();
+        }
+        return ordering;
+    });
+}
+
+fn compare_sort_fields(sort_key: &SortKey, a: &SortFields, b: &SortFields) -> Ordering {
+    match sort_key {
+        SortKey::Value => return a.value.cmp(&b.value),
+        SortKey::Change => return a.change.cmp(&b.change),
+        SortKey::Name => return a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        SortKey::Date => {
+            let a_created_at = DateTime::parse_from_rfc3339(&a.created_at).ok();
+            let b_created_at = DateTime::parse_from_rfc3339(&b.created_at).ok();
+            return a_created_at.cmp(&b_created_at);
+        }
+    }
+}
+
+/*
+    NOTE: the search ignores case and surrounding spaces, and matches the codes as
+    well as the names of their currencies, e.g. `bit` finds `BTC` through `Bitcoin`
+*/
+pub fn matches_search(search: &str, codes: &Vec<&str>, currencies: &Vec<Currency>) -> bool {
+    let search = search.trim().to_lowercase();
+    if search.is_empty() {
+        return true;
+    }
+    return codes.iter().any(|code| {
+        if code.to_lowercase().contains(&search) {
+            return true;
+        }
+        return currencies
+            .iter()
+            .any(|c| &c.code == code && c.name.to_lowercase().contains(&search));
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use crate::entities::currency::CurrencyKind;
+
+    use super::*;
+
+    fn create_currency(code: &str, name: &str) -> Currency {
+        return Currency {
+            id: code.to_lowercase(),
+            code: code.to_string(),
+            name: name.to_string(),
+            symbol: code.to_string(),
+            kind: CurrencyKind::Crypto,
+            decimals: 8,
+            icon_id: None,
+            provider_id: None,
+            ambiguous_with: vec![],
+        };
+    }
+
+    fn create_sort_fields(name: &str, value: &str, change: &str, created_at: &str) -> SortFields {
+        return SortFields {
+            value: Decimal::from_str(value).unwrap(),
+            change: Decimal::from_str(change).unwrap(),
+            name: name.to_string(),
+            created_at: created_at.to_string(),
+        };
+    }
+
+    #[test]
+    fn test_sort_items() {
+        /*
+            Unit test expectations:
+
+            - Items are sorted by value, change, name ignoring case, or date.
+            - Descending sorts reverse the order, and unknown sort keys are rejected.
+        */
+        let items = vec![
+            create_sort_fields("eth", "10", "0.1", "2024-01-02T00:00:00+00:00"),
+            create_sort_fields("BTC", "30", "-0.2", "2024-01-03T00:00:00+00:00"),
+            create_sort_fields("Ada", "20", "0.3", "2024-01-01T00:00:00+00:00"),
+        ];
+        let get_names = |items: &Vec<SortFields>| -> Vec<String> {
+            return items.iter().map(|i| i.name.clone()).collect();
+        };
+
+        let mut sorted_items = items.clone();
+        sort_items(&mut sorted_items, &SortKey::Value, true, |i| i.clone());
+        assert_eq!(get_names(&sorted_items), vec!["BTC", "Ada", "eth"]);
+        sort_items(&mut sorted_items, &SortKey::Change, false, |i| i.clone());
+        assert_eq!(get_names(&sorted_items), vec!["BTC", "eth", "Ada"]);
+        sort_items(&mut sorted_items, &SortKey::Name, false, |i| i.clone());
+        assert_eq!(get_names(&sorted_items), vec!["Ada", "BTC", "eth"]);
+        sort_items(&mut sorted_items, &SortKey::Date, true, |i| i.clone());
+        assert_eq!(get_names(&sorted_items), vec!["BTC", "eth", "Ada"]);
+
+        assert_eq!(parse_sort_key("change").unwrap(), SortKey::Change);
+        assert!(parse_sort_key("size").is_err());
+    }
+
+    #[test]
+    fn test_matches_search() {
+        /*
+            Unit test expectations:
+
+            - Codes and currency names are searched, ignoring case and surrounding spaces.
+            - An empty search matches everything.
+        */
+        let currencies = vec![
+            create_currency("BTC", "Bitcoin"),
+            create_currency("ETH", "Ethereum"),
+        ];
+        assert!(matches_search(" btc ", &vec!["BTC"], &currencies));
+        assert!(matches_search("bit", &vec!["USD", "BTC"], &currencies));
+        assert!(!matches_search("bit", &vec!["ETH"], &currencies));
+        assert!(matches_search("", &vec!["ETH"], &currencies));
+    }
+}
@@ -0,0 +1,123 @@
+use chrono::DateTime;
+
+use crate::{entities::watchlist::Watchlist, Error};
+
+pub const DEFAULT_WATCHLIST_NAME: &str = "Watchlist";
+
+// NOTE: watchlists saved before they could be ordered all have position 0
+pub fn sort_watchlists(watchlists: &mut Vec<Watchlist>) {
+    watchlists.sort_by(|a, b| {
+        let a_created_at = DateTime::parse_from_rfc3339(&a.created_at).ok();
+        let b_created_at = DateTime::parse_from_rfc3339(&b.created_at).ok();
+        return a
+            .position
+            .cmp(&b.position)
+            .then(a_created_at.cmp(&b_created_at));
+    });
+}
+
+/*
+    Picks the watchlist a request is about: the requested one, which must exist,
+    or else the default one from the settings, or else the first one. A default
+    watchlist that was deleted is ignored rather than reported.
+*/
+pub fn select_watchlist(
+    watchlists: &Vec<Watchlist>,
+    watchlist_id: &Option<String>,
+    default_watchlist_id: &Option<String>,
+) -> Result<Option<Watchlist>, Error> {
+    if let Some(watchlist_id) = watchlist_id {
+        let maybe_watchlist = watchlists.iter().find(|w| &w.id == watchlist_id);
+        if maybe_watchlist.is_none() {
+            return Err(Error {
+                message: String::from("Watchlist not found!"),
+                ..Default::default()
+            });
+        }
+        return Ok(maybe_watchlist.cloned());
+    }
+    if let Some(default_watchlist_id) = default_watchlist_id {
+        if let Some(watchlist) = watchlists.iter().find(|w| &w.id == default_watchlist_id) {
+            return Ok(Some(watchlist.clone()));
+        }
+    }
+    return Ok(watchlists.first().cloned());
+}
+
+// NOTE: names ignore case and surrounding spaces, like tag names
+pub fn validate_watchlist_name(
+    watchlists: &Vec<Watchlist>,
+    id: &str,
+    name: &str,
+) -> Result<(), Error> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(Error {
+            message: String::from("Watchlist name must not be empty!"),
+            ..Default::default()
+        });
+    }
+    let is_taken = watchlists
+        .iter()
+        .any(|w| w.id != id && w.name.trim().to_lowercase() == name.to_lowercase());
+    if is_taken {
+        return Err(Error {
+            message: format!("A watchlist named '{}' already exists!", name),
+            ..Default::default()
+        });
+    }
+    return Ok(());
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn create_watchlist(id: &str, position: u32, created_at: &str) -> Watchlist {
+        return Watchlist {
+            id: id.to_string(),
+            name: id.to_uppercase(),
+            position,
+            pairs: vec![],
+            cross_pairs: vec![],
+            created_at: created_at.to_string(),
+            updated_at: created_at.to_string(),
+        };
+    }
+
+    #[test]
+    fn test_select_watchlist() {
+        /*
+            Unit test expectations:
+
+            - Watchlists are ordered by position, then by creation date.
+            - The requested watchlist wins over the default one, which wins over the first one.
+            - A missing requested watchlist is an error, a missing default one is ignored.
+            - Names are unique, ignoring case and surrounding spaces.
+        */
+        let mut watchlists = vec![
+            create_watchlist("w3", 1, "2024-01-01T00:00:00+00:00"),
+            create_watchlist("w2", 0, "2024-01-02T00:00:00+00:00"),
+            create_watchlist("w1", 0, "2024-01-01T00:00:00+00:00"),
+        ];
+        sort_watchlists(&mut watchlists);
+        let ids: Vec<&str> = watchlists.iter().map(|w| w.id.as_str()).collect();
+        assert_eq!(ids, vec!["w1", "w2", "w3"]);
+
+        let default_id = Some(String::from("w2"));
+        let selected = select_watchlist(&watchlists, &Some(String::from("w3")), &default_id);
+        assert_eq!(selected.unwrap().unwrap().id, "w3");
+        let selected = select_watchlist(&watchlists, &None, &default_id);
+        assert_eq!(selected.unwrap().unwrap().id, "w2");
+        let selected = select_watchlist(&watchlists, &None, &Some(String::from("deleted")));
+        assert_eq!(selected.unwrap().unwrap().id, "w1");
+        assert!(select_watchlist(&watchlists, &Some(String::from("deleted")), &None).is_err());
+        assert!(select_watchlist(&vec![], &None, &default_id)
+            .unwrap()
+            .is_none());
+
+        assert!(validate_watchlist_name(&watchlists, "new", " w1 ").is_err());
+        assert!(validate_watchlist_name(&watchlists, "w1", "W1").is_ok());
+        assert!(validate_watchlist_name(&watchlists, "new", "  ").is_err());
+    }
+}
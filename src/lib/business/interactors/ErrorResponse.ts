@@ -1,3 +1,9 @@
 export interface ErrorResponse {
     message: string
+    fields?: FieldError[]
+}
+
+export interface FieldError {
+    field: string
+    message: string
 }
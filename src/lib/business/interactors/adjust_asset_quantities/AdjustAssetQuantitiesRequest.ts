@@ -0,0 +1,4 @@
+export interface AdjustAssetQuantitiesRequest {
+  asset_ids: string[];
+  factor: number;
+}
@@ -0,0 +1,4 @@
+export interface AdjustAssetQuantitiesResponse {
+  asset_count: number;
+  transaction_count: number;
+}
@@ -0,0 +1,6 @@
+export interface ConvertAmountRequest {
+  amount: number;
+  from: string;
+  to: string;
+  pivot?: string;
+}
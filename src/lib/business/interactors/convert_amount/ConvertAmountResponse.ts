@@ -0,0 +1,9 @@
+export interface ConvertAmountResponse {
+  amount: number;
+  converted_amount: number;
+  from: string;
+  to: string;
+  rate: number;
+  path: string[];
+  rate_updated_at: string;
+}
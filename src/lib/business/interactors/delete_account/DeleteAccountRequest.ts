@@ -0,0 +1,7 @@
+interface RequestAccount {
+  id: string;
+}
+
+export interface DeleteAccountRequest {
+  account: RequestAccount;
+}
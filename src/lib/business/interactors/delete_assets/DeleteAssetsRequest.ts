@@ -0,0 +1,3 @@
+export interface DeleteAssetsRequest {
+  asset_ids: string[];
+}
@@ -0,0 +1,5 @@
+export interface DeleteAssetsResponse {
+  deleted_asset_count: number;
+  updated_tag_count: number;
+  transaction_count: number;
+}
@@ -0,0 +1,9 @@
+interface RequestTag {
+  id: string;
+}
+
+export interface DeleteTagRequest {
+  tag: RequestTag;
+  mode: 'detach' | 'cascade';
+  is_preview: boolean;
+}
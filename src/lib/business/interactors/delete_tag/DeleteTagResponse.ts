@@ -0,0 +1,20 @@
+interface ResponseTag {
+  id: string;
+  name: string;
+}
+
+interface ResponseAsset {
+  id: string;
+  coin: string;
+  quantity: number;
+  other_tag_count: number;
+}
+
+export interface DeleteTagResponse {
+  mode: 'detach' | 'cascade';
+  is_preview: boolean;
+  tag: ResponseTag;
+  child_tags: ResponseTag[];
+  assets: ResponseAsset[];
+  transaction_count: number;
+}
@@ -0,0 +1,7 @@
+interface RequestTransaction {
+  id: string;
+}
+
+export interface DeleteTransactionRequest {
+  transaction: RequestTransaction;
+}
@@ -0,0 +1,7 @@
+interface RequestWatchlist {
+  id: string;
+}
+
+export interface DeleteWatchlistRequest {
+  watchlist: RequestWatchlist;
+}
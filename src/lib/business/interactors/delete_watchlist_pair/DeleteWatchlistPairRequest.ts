@@ -3,5 +3,6 @@ interface RequestPair {
 }
 
 export interface DeleteWatchlistPairRequest {
+  watchlist_id: string | null;
   pair: RequestPair;
 }
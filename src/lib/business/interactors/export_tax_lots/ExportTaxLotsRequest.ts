@@ -0,0 +1,5 @@
+export interface ExportTaxLotsRequest {
+  from: string;
+  to: string;
+  currency: string;
+}
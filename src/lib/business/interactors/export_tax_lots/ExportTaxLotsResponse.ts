@@ -0,0 +1,9 @@
+export interface ExportTaxLotsResponse {
+  file_name: string;
+  csv: string;
+  currency: string;
+  disposal_count: number;
+  total_proceeds: number;
+  total_cost_basis: number;
+  total_gain: number;
+}
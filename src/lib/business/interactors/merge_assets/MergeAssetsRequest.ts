@@ -0,0 +1,3 @@
+export interface MergeAssetsRequest {
+  asset_ids: string[];
+}
@@ -0,0 +1,12 @@
+interface ResponseAsset {
+  id: string;
+  coin: string;
+  quantity: number;
+}
+
+export interface MergeAssetsResponse {
+  asset: ResponseAsset;
+  merged_asset_count: number;
+  updated_tag_count: number;
+  transaction_count: number;
+}
@@ -0,0 +1,4 @@
+export interface MoveAssetsRequest {
+  asset_ids: string[];
+  account_id: string | null;
+}
@@ -0,0 +1,4 @@
+export interface ReorderPairGroupPairsRequest {
+  pair_group_id: string;
+  pair_ids: string[];
+}
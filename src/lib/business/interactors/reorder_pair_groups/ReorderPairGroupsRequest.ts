@@ -0,0 +1,3 @@
+export interface ReorderPairGroupsRequest {
+  pair_group_ids: string[];
+}
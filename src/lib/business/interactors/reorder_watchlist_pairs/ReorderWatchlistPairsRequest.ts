@@ -0,0 +1,5 @@
+export interface ReorderWatchlistPairsRequest {
+  watchlist_id: string | null;
+  pair_ids: string[];
+  cross_pair_ids: string[];
+}
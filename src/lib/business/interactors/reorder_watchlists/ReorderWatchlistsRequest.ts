@@ -0,0 +1,3 @@
+export interface ReorderWatchlistsRequest {
+  watchlist_ids: string[];
+}
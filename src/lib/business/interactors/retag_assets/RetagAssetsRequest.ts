@@ -0,0 +1,5 @@
+export interface RetagAssetsRequest {
+  asset_ids: string[];
+  added_tag_ids: string[];
+  removed_tag_ids: string[];
+}
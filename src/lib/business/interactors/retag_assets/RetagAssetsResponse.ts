@@ -0,0 +1,4 @@
+export interface RetagAssetsResponse {
+  asset_count: number;
+  updated_tag_count: number;
+}
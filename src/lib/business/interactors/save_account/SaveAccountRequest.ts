@@ -0,0 +1,7 @@
+interface RequestAccount {
+  name: string;
+}
+
+export interface SaveAccountRequest {
+  account: RequestAccount;
+}
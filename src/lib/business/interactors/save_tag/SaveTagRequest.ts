@@ -1,5 +1,8 @@
 interface RequestTag {
   name: string;
+  color?: string;
+  description?: string;
+  parent_id?: string;
 }
 
 export interface SaveTagRequest {
@@ -0,0 +1,7 @@
+interface RequestWatchlist {
+  name: string;
+}
+
+export interface SaveWatchlistRequest {
+  watchlist: RequestWatchlist;
+}
@@ -2,12 +2,20 @@ interface RequestTag {
   id: string;
 }
 
+interface RequestAccount {
+  id: string;
+}
+
 interface RequestAsset {
   coin: string;
   quantity: number;
+  acquisition_price?: number;
+  acquisition_currency?: string;
+  acquired_at?: string;
 }
 
 export interface StorePortfoliosRequest {
   tag?: RequestTag;
+  account?: RequestAccount;
   assets: RequestAsset[];
 }
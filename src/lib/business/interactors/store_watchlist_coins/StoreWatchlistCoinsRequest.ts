@@ -1,3 +1,10 @@
+interface RequestPair {
+  base: string;
+  comparison: string;
+}
+
 export interface StoreWatchlistCoinsRequest {
+  watchlist_id: string | null;
   coins: string[];
+  pairs: RequestPair[];
 }
@@ -0,0 +1,5 @@
+export interface SuggestRebalanceRequest {
+  tag_id: string;
+  threshold: number;
+  min_trade_value: number;
+}
@@ -0,0 +1,18 @@
+interface ResponseSuggestion {
+  coin: string;
+  action: 'buy' | 'sell' | 'hold';
+  quantity: number;
+  value: number;
+  current_value: number;
+  current_allocation: number;
+  target_allocation: number;
+  drift: number;
+}
+
+export interface SuggestRebalanceResponse {
+  tag_id: string;
+  reference_currency: string;
+  total_value: number;
+  is_balanced: boolean;
+  suggestions: ResponseSuggestion[];
+}
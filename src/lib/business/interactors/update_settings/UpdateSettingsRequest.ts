@@ -0,0 +1,24 @@
+type CostBasisMethod = 'fifo' | 'lifo' | 'average_cost';
+
+interface RequestProxySettings {
+  url: string;
+  username: string | null;
+  password: string | null;
+  no_proxy: string[];
+}
+
+interface RequestNetworkSettings {
+  proxy: RequestProxySettings | null;
+  extra_ca_certificates: string[];
+}
+
+export interface UpdateSettingsRequest {
+  fiat_rates_url: string;
+  crypto_rates_url: string;
+  reference_currency: string;
+  refresh_interval: number;
+  data_location: string | null;
+  network: RequestNetworkSettings;
+  cost_basis_method: CostBasisMethod;
+  default_watchlist_id: string | null;
+}
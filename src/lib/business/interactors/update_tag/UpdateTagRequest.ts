@@ -0,0 +1,11 @@
+interface RequestTag {
+  id: string;
+  name: string;
+  color: string | null;
+  description: string;
+  parent_id: string | null;
+}
+
+export interface UpdateTagRequest {
+  tag: RequestTag;
+}
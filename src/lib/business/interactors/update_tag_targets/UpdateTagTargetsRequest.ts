@@ -0,0 +1,13 @@
+interface RequestTargetWeight {
+  coin: string;
+  weight: number;
+}
+
+interface RequestTag {
+  id: string;
+  targets: RequestTargetWeight[];
+}
+
+export interface UpdateTagTargetsRequest {
+  tag: RequestTag;
+}
@@ -0,0 +1,16 @@
+type TransactionKind = 'buy' | 'sell' | 'deposit' | 'withdraw' | 'transfer' | 'fee';
+
+interface RequestTransaction {
+  id: string;
+  kind: TransactionKind;
+  quantity: number;
+  price: number | null;
+  price_currency: string | null;
+  counterpart_asset_id: string | null;
+  executed_at: string;
+  notes: string;
+}
+
+export interface UpdateTransactionRequest {
+  transaction: RequestTransaction;
+}
@@ -0,0 +1,8 @@
+interface RequestWatchlist {
+  id: string;
+  name: string;
+}
+
+export interface UpdateWatchlistRequest {
+  watchlist: RequestWatchlist;
+}
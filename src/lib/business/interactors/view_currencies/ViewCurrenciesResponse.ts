@@ -0,0 +1,30 @@
+interface ResponseCurrency {
+  id: string;
+  code: string;
+  name: string;
+  symbol: string;
+  kind: 'fiat' | 'crypto';
+  decimals: number;
+  icon_id: string | null;
+  provider_id: string | null;
+  ambiguous_with: string[];
+}
+
+interface ResponseSkippedRecord {
+  key: string;
+  reason: string;
+}
+
+interface ResponseFetchReport {
+  source: string;
+  url: string;
+  status: number;
+  fetched_at: string;
+  accepted_records: number;
+  skipped_records: ResponseSkippedRecord[];
+}
+
+export interface ViewCurrenciesResponse {
+  currencies: ResponseCurrency[];
+  reports: ResponseFetchReport[];
+}
@@ -0,0 +1,7 @@
+export interface ViewPairGroupsRequest {
+  sort_by?: 'value' | 'change' | 'name' | 'date';
+  sort_descending?: boolean;
+  is_pinned?: boolean;
+  coin?: string;
+  search?: string;
+}
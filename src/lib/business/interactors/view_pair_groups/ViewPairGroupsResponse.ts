@@ -16,7 +16,20 @@ interface ResponsePairGroup {
   updated_at: string;
 }
 
+interface ResponseCurrency {
+  id: string;
+  code: string;
+  name: string;
+  symbol: string;
+  kind: 'fiat' | 'crypto';
+  decimals: number;
+  icon_id: string | null;
+  provider_id: string | null;
+  ambiguous_with: string[];
+}
+
 export interface ViewPairGroupsResponse {
+  currencies: ResponseCurrency[];
   usd_pairs: ResponsePair[];
   pair_groups: ResponsePairGroup[];
 }
@@ -0,0 +1,4 @@
+export interface ViewPortfolioHistoryRequest {
+  tag_id: string | null;
+  from: string | null;
+}
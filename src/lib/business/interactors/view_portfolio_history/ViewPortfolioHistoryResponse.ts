@@ -0,0 +1,18 @@
+interface ResponsePoint {
+  timestamp: string;
+  value: number;
+}
+
+interface ResponseReturns {
+  day: number | null;
+  week: number | null;
+  month: number | null;
+  year_to_date: number | null;
+}
+
+export interface ViewPortfolioHistoryResponse {
+  reference_currency: string;
+  tag_id: string | null;
+  points: ResponsePoint[];
+  returns: ResponseReturns;
+}
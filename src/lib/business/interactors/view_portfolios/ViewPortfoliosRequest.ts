@@ -0,0 +1,8 @@
+export interface ViewPortfoliosRequest {
+  account_id: string | null;
+  sort_by?: 'value' | 'change' | 'name' | 'date';
+  sort_descending?: boolean;
+  tag_id?: string;
+  coin?: string;
+  search?: string;
+}
@@ -1,3 +1,5 @@
+type CostBasisMethod = 'fifo' | 'lifo' | 'average_cost';
+
 interface ResponsePair {
   id: string;
   value: number;
@@ -7,9 +9,19 @@ interface ResponsePair {
   updated_at: string;
 }
 
+interface ResponseTargetWeight {
+  coin: string;
+  weight: number;
+}
+
 interface ResponseTag {
   id: string;
   name: string;
+  color: string | null;
+  description: string;
+  parent_id: string | null;
+  path: string[];
+  targets: ResponseTargetWeight[];
   created_at: string;
   updated_at: string;
 }
@@ -19,18 +31,99 @@ interface ResponseAsset {
   coin: string;
   quantity: number;
   usd_value: number;
+  value: number;
+  total_value: number;
+  previous_total_value: number;
+  cost_basis: number;
+  unrealized_pnl: number;
+  realized_pnl: number;
+  has_unknown_cost: boolean;
+  account_id: string | null;
   created_at: string;
   updated_at: string;
 }
 
+interface ResponseFluctuations {
+  hour: number | null;
+  day: number | null;
+  week: number | null;
+  month: number | null;
+}
+
 interface ResponsePortfolio {
   fluctuation: number;
+  fluctuations: ResponseFluctuations;
+  allocation: number;
   tags: ResponseTag[];
   asset: ResponseAsset;
 }
 
+interface ResponseTagTotal {
+  tag: ResponseTag | null;
+  asset_count: number;
+  total_value: number;
+  fluctuation: number;
+  allocation: number;
+  cost_basis: number;
+  unrealized_pnl: number;
+  realized_pnl: number;
+}
+
+interface ResponseAccount {
+  id: string;
+  name: string;
+  created_at: string;
+  updated_at: string;
+}
+
+interface ResponseAccountTotal {
+  account: ResponseAccount | null;
+  asset_count: number;
+  total_value: number;
+  fluctuation: number;
+  allocation: number;
+  cost_basis: number;
+  unrealized_pnl: number;
+  realized_pnl: number;
+}
+
+interface ResponseCoinTotal {
+  coin: string;
+  asset_count: number;
+  quantity: number;
+  total_value: number;
+  fluctuation: number;
+  allocation: number;
+}
+
+interface ResponseCurrency {
+  id: string;
+  code: string;
+  name: string;
+  symbol: string;
+  kind: 'fiat' | 'crypto';
+  decimals: number;
+  icon_id: string | null;
+  provider_id: string | null;
+  ambiguous_with: string[];
+}
+
 export interface ViewPortfoliosResponse {
+  reference_currency: string;
+  account_id: string | null;
+  total_value: number;
+  total_fluctuation: number;
+  total_fluctuations: ResponseFluctuations;
+  cost_basis_method: CostBasisMethod;
+  total_cost_basis: number;
+  total_unrealized_pnl: number;
+  total_realized_pnl: number;
+  tag_totals: ResponseTagTotal[];
+  coin_totals: ResponseCoinTotal[];
+  account_totals: ResponseAccountTotal[];
+  currencies: ResponseCurrency[];
   tags: ResponseTag[];
+  accounts: ResponseAccount[];
   usd_pairs: ResponsePair[];
   portfolios: ResponsePortfolio[];
 }
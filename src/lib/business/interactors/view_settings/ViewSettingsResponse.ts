@@ -0,0 +1,25 @@
+type CostBasisMethod = 'fifo' | 'lifo' | 'average_cost';
+
+interface ResponseProxySettings {
+  url: string;
+  username: string | null;
+  has_password: boolean;
+  no_proxy: string[];
+}
+
+interface ResponseNetworkSettings {
+  proxy: ResponseProxySettings | null;
+  extra_ca_certificates: string[];
+}
+
+export interface ViewSettingsResponse {
+  fiat_rates_url: string;
+  crypto_rates_url: string;
+  reference_currency: string;
+  refresh_interval: number;
+  data_location: string | null;
+  network: ResponseNetworkSettings;
+  cost_basis_method: CostBasisMethod;
+  default_watchlist_id: string | null;
+  updated_at: string;
+}
@@ -0,0 +1,3 @@
+export interface ViewTransactionsRequest {
+  asset_id: string | null;
+}
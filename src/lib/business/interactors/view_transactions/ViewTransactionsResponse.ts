@@ -0,0 +1,20 @@
+type TransactionKind = 'buy' | 'sell' | 'deposit' | 'withdraw' | 'transfer' | 'fee';
+
+interface ResponseTransaction {
+  id: string;
+  asset_id: string;
+  coin: string;
+  kind: TransactionKind;
+  quantity: number;
+  price: number | null;
+  price_currency: string | null;
+  counterpart_asset_id: string | null;
+  executed_at: string;
+  notes: string;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface ViewTransactionsResponse {
+  transactions: ResponseTransaction[];
+}
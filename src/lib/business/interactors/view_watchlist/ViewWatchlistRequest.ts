@@ -0,0 +1,7 @@
+export interface ViewWatchlistRequest {
+  watchlist_id: string | null;
+  sort_by?: 'value' | 'change' | 'name' | 'date';
+  sort_descending?: boolean;
+  coin?: string;
+  search?: string;
+}
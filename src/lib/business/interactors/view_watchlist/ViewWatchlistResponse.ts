@@ -1,6 +1,20 @@
+interface ResponseWatchlist {
+  id: string;
+  name: string;
+  position: number;
+}
+
+interface ResponseFluctuations {
+  hour: number | null;
+  day: number | null;
+  week: number | null;
+  month: number | null;
+}
+
 interface ResponsePair {
   id: string;
   fluctuation: number;
+  fluctuations: ResponseFluctuations;
   base: string;
   value: number;
   comparison: string;
@@ -8,7 +22,24 @@ interface ResponsePair {
   updated_at: string;
 }
 
+interface ResponseCurrency {
+  id: string;
+  code: string;
+  name: string;
+  symbol: string;
+  kind: 'fiat' | 'crypto';
+  decimals: number;
+  icon_id: string | null;
+  provider_id: string | null;
+  ambiguous_with: string[];
+}
+
 export interface ViewWatchlistResponse {
+  watchlist_id: string;
+  watchlists: ResponseWatchlist[];
+  reference_currency: string;
   coins: string[];
+  currencies: ResponseCurrency[];
   pairs: ResponsePair[];
+  cross_pairs: ResponsePair[];
 }